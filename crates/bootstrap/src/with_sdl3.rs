@@ -48,7 +48,9 @@ pub fn run<T: ExampleBody>(mut yak: Yakui, mut state: ExampleState, title: Strin
             }
         }
 
-        state.time = (Instant::now() - start).as_secs_f32();
+        let time = (Instant::now() - start).as_secs_f32();
+        yak.set_delta_time(time - state.time);
+        state.time = time;
 
         {
             profiling::scope!("Build UI");
@@ -115,7 +115,9 @@ impl<T: ExampleBody> ApplicationHandler for App<T> {
         // Handle window event.
         match event {
             WindowEvent::RedrawRequested => {
-                self.state.time = (Instant::now() - self.start).as_secs_f32();
+                let time = (Instant::now() - self.start).as_secs_f32();
+                self.yak.set_delta_time(time - self.state.time);
+                self.state.time = time;
 
                 {
                     profiling::scope!("Build UI");
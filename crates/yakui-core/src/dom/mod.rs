@@ -7,7 +7,7 @@ mod dynamic_scope;
 mod root;
 
 use std::any::{type_name, TypeId};
-use std::cell::{Ref, RefCell, RefMut};
+use std::cell::{Cell, Ref, RefCell, RefMut};
 use std::collections::VecDeque;
 use std::mem::replace;
 use std::panic::Location;
@@ -24,6 +24,10 @@ use self::dummy::DummyWidget;
 use self::dynamic_scope::DynamicScope;
 use self::root::RootWidget;
 
+/// The frame time assumed if the host never calls
+/// [`Yakui::set_delta_time`][crate::Yakui::set_delta_time].
+const DEFAULT_DELTA_TIME: f32 = 1.0 / 60.0;
+
 /// The DOM that contains the tree of active widgets.
 pub struct Dom {
     inner: Rc<DomInner>,
@@ -36,6 +40,7 @@ struct DomInner {
     root: WidgetId,
     globals: RefCell<Globals>,
     dynamic_scope: DynamicScope,
+    delta_time: Cell<f32>,
 }
 
 /// A node in the [`Dom`].
@@ -62,6 +67,13 @@ pub struct DomNode {
     callsite: &'static Location<'static>,
 }
 
+impl DomNode {
+    /// The location in the source code that created this widget.
+    pub fn callsite(&self) -> &'static Location<'static> {
+        self.callsite
+    }
+}
+
 impl Dom {
     /// Create a new, empty DOM.
     pub fn new() -> Self {
@@ -172,6 +184,19 @@ impl Dom {
         }
     }
 
+    /// How much time passed since the previous frame, in seconds. Widgets that
+    /// animate use this to move at the same speed regardless of frame rate.
+    ///
+    /// Set with [`Yakui::set_delta_time`][crate::Yakui::set_delta_time].
+    /// Defaults to 1/60th of a second if it has never been set.
+    pub fn delta_time(&self) -> f32 {
+        self.inner.delta_time.get()
+    }
+
+    pub(crate) fn set_delta_time(&self, delta_time: f32) {
+        self.inner.delta_time.set(delta_time);
+    }
+
     /// Get a piece of DOM-global state or initialize it with the given
     /// function.
     ///
@@ -287,6 +312,7 @@ impl DomInner {
             stack: RefCell::new(Vec::new()),
             root: WidgetId::new(root),
             dynamic_scope: DynamicScope::new(),
+            delta_time: Cell::new(DEFAULT_DELTA_TIME),
         }
     }
 }
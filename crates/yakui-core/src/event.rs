@@ -117,6 +117,15 @@ pub enum WidgetEvent {
 
     /// The widget was focused or unfocused.
     FocusChanged(bool),
+
+    /// A descendant of the widget was selected, and the widget should scroll
+    /// so that the given rectangle is visible, if it can scroll.
+    ///
+    /// Sent to each ancestor of the newly selected widget, starting with its
+    /// parent. Widgets that scroll should respond with [`EventResponse::Sink`],
+    /// which tells ancestors further up that the rectangle has been moved
+    /// inside of the widget's layout rectangle.
+    ScrollIntoView(Rect),
 }
 
 /// Responses that can be given to an event.
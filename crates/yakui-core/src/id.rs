@@ -34,6 +34,11 @@ pub enum TextureId {
 
     /// A texture that is managed by the user or renderer.
     User(u64),
+
+    /// The texture that the renderer painted a
+    /// [`RenderTarget`][crate::paint::RenderTarget] into, identified by the
+    /// widget that owns it.
+    RenderTarget(WidgetId),
 }
 
 impl From<ManagedTextureId> for TextureId {
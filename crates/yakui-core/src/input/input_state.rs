@@ -256,6 +256,10 @@ impl InputState {
                     &mut node,
                     &WidgetEvent::FocusChanged(true),
                 );
+
+                let parent = node.parent;
+                drop(node);
+                self.scroll_into_view(dom, layout, entered, parent);
             } else {
                 self.selection.set(None);
                 current = None;
@@ -277,6 +281,42 @@ impl InputState {
         self.last_selection.set(current);
     }
 
+    /// Ask every ancestor of a newly selected widget to scroll it into view,
+    /// starting with the innermost one.
+    fn scroll_into_view(
+        &self,
+        dom: &Dom,
+        layout: &LayoutDom,
+        selected: WidgetId,
+        mut parent: Option<WidgetId>,
+    ) {
+        let Some(mut target) = layout.get(selected).map(|node| node.rect) else {
+            return;
+        };
+
+        while let Some(id) = parent {
+            let Some(mut node) = dom.get_mut(id) else {
+                break;
+            };
+
+            let response = self.fire_event(
+                dom,
+                layout,
+                id,
+                &mut node,
+                &WidgetEvent::ScrollIntoView(target),
+            );
+            parent = node.parent;
+
+            // The widget scrolled, so the target is now somewhere within it.
+            if response == EventResponse::Sink {
+                if let Some(layout_node) = layout.get(id) {
+                    target = move_inside(target, layout_node.rect);
+                }
+            }
+        }
+    }
+
     /// Signal that the mouse has moved.
     fn mouse_moved(&self, dom: &Dom, layout: &LayoutDom, pos: Option<Vec2>) {
         {
@@ -421,11 +461,12 @@ impl InputState {
 
         for &id in &intersections.mouse_hit {
             if let Some(mut node) = dom.get_mut(id) {
+                let position = mouse.position.unwrap_or(Vec2::ZERO) / layout.scale_factor();
                 let event = WidgetEvent::MouseButtonChanged {
                     button,
                     down,
                     inside: true,
-                    position: mouse.position.unwrap_or(Vec2::ZERO) / layout.scale_factor(),
+                    position: local_position(layout, id, position),
                     modifiers: self.modifiers.get(),
                 };
                 let response = self.fire_event(dom, layout, id, &mut node, &event);
@@ -442,11 +483,12 @@ impl InputState {
                 && !intersections.mouse_hit.contains(&id)
             {
                 if let Some(mut node) = dom.get_mut(id) {
+                    let position = mouse.position.unwrap_or(Vec2::ZERO) / layout.scale_factor();
                     let event = WidgetEvent::MouseButtonChanged {
                         button,
                         down,
                         inside: false,
-                        position: mouse.position.unwrap_or(Vec2::ZERO) / layout.scale_factor(),
+                        position: local_position(layout, id, position),
                         modifiers: self.modifiers.get(),
                     };
                     self.fire_event(dom, layout, id, &mut node, &event);
@@ -483,11 +525,12 @@ impl InputState {
     fn send_mouse_move(&self, dom: &Dom, layout: &LayoutDom) {
         let mouse = self.mouse.borrow();
         let pos = mouse.position.map(|pos| pos / layout.scale_factor());
-        let event = WidgetEvent::MouseMoved(pos);
 
         for (id, interest) in layout.interest_mouse.iter() {
             if interest.intersects(EventInterest::MOUSE_MOVE) {
                 if let Some(mut node) = dom.get_mut(id) {
+                    let event =
+                        WidgetEvent::MouseMoved(pos.map(|pos| local_position(layout, id, pos)));
                     self.fire_event(dom, layout, id, &mut node, &event);
                 }
             }
@@ -589,6 +632,38 @@ impl InputState {
     }
 }
 
+/// Moves `rect` by the smallest amount needed to fit inside of `bounds`,
+/// keeping its start visible if it's too big to fit, then clips it to `bounds`.
+fn move_inside(rect: Rect, bounds: Rect) -> Rect {
+    let mut offset = Vec2::ZERO;
+
+    for axis in 0..2 {
+        let start = rect.pos()[axis];
+        let end = rect.max()[axis];
+        let bounds_start = bounds.pos()[axis];
+        let bounds_end = bounds.max()[axis];
+
+        if start < bounds_start {
+            offset[axis] = bounds_start - start;
+        } else if end > bounds_end {
+            offset[axis] = (bounds_end - end).max(bounds_start - start);
+        }
+    }
+
+    let pos = (rect.pos() + offset).max(bounds.pos());
+    let max = (rect.max() + offset).min(bounds.max());
+    Rect::from_pos_size(pos, (max - pos).max(Vec2::ZERO))
+}
+
+/// Maps a point in logical pixels into the untransformed coordinate space of
+/// the given widget's layout rect, undoing any transforms applied to it.
+fn local_position(layout: &LayoutDom, id: WidgetId, pos: Vec2) -> Vec2 {
+    match layout.get(id).and_then(|node| node.transform) {
+        Some(transform) => transform.inverse().transform_point2(pos),
+        None => pos,
+    }
+}
+
 /// Calculate the set of widgets that are under the given point, sorted by
 /// relative height, highest to lowest.
 #[profiling::function]
@@ -600,7 +675,7 @@ fn hit_test(_dom: &Dom, layout: &LayoutDom, coords: Vec2, output: &mut Vec<Widge
 
         let rect = layout_node.clip.constrain(layout_node.rect);
 
-        if rect.contains_point(coords) {
+        if rect.contains_point(local_position(layout, id, coords)) {
             output.push(id);
         }
     }
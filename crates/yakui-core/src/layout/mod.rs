@@ -1,15 +1,18 @@
 //! Defines yakui's layout protocol and Layout DOM.
 
 mod clipping;
+mod overflow;
 
 pub use self::clipping::*;
+pub use self::overflow::*;
 
 use std::collections::VecDeque;
+use std::mem;
 
-use glam::Vec2;
+use glam::{Affine2, Vec2};
 use thunderdome::Arena;
 
-use crate::dom::Dom;
+use crate::dom::{Dom, DomNode};
 use crate::event::EventInterest;
 use crate::geometry::{Constraints, Rect};
 use crate::id::WidgetId;
@@ -29,6 +32,13 @@ pub struct LayoutDom {
     pub(crate) interest_mouse: MouseInterest,
 
     clip_logic_overrides: Arena<ClipLogic>,
+    baselines: Arena<f32>,
+    transforms: Arena<Affine2>,
+
+    warnings: Arena<LayoutWarnings>,
+    /// The warnings from the previous layout pass, used to only log warnings
+    /// when they first appear.
+    previous_warnings: Arena<LayoutWarnings>,
 }
 
 /// A node in a [`LayoutDom`].
@@ -46,6 +56,16 @@ pub struct LayoutDomNode {
 
     /// What events the widget reported interest in.
     pub event_interest: EventInterest,
+
+    /// The distance from the top of the node to the baseline of its first line
+    /// of text, if it reported one during layout.
+    pub baseline: Option<f32>,
+
+    /// The transform applied to this node and all of its descendants when they
+    /// are painted and hit tested, in logical pixels. This combines the
+    /// transforms of the node and all of its ancestors and is `None` if none
+    /// of them set one.
+    pub transform: Option<Affine2>,
 }
 
 impl LayoutDom {
@@ -59,6 +79,11 @@ impl LayoutDom {
 
             interest_mouse: MouseInterest::new(),
             clip_logic_overrides: Arena::new(),
+            baselines: Arena::new(),
+            transforms: Arena::new(),
+
+            warnings: Arena::new(),
+            previous_warnings: Arena::new(),
         }
     }
 
@@ -123,6 +148,11 @@ impl LayoutDom {
 
         self.interest_mouse.clear();
         self.clip_logic_overrides.clear();
+        self.baselines.clear();
+        self.transforms.clear();
+
+        mem::swap(&mut self.warnings, &mut self.previous_warnings);
+        self.warnings.clear();
 
         let constraints = Constraints::tight(self.viewport().size());
 
@@ -136,6 +166,11 @@ impl LayoutDom {
     /// This function must only be called from
     /// [`Widget::layout`][crate::widget::Widget::layout] and should only be
     /// called once per widget per layout pass.
+    ///
+    /// In debug builds, widgets that return a size that doesn't satisfy their
+    /// constraints or that report an overflow with
+    /// [`LayoutDom::report_overflow`] have their [`LayoutWarnings`] recorded
+    /// and logged.
     pub fn calculate(
         &mut self,
         dom: &Dom,
@@ -147,6 +182,10 @@ impl LayoutDom {
         dom.enter(id);
         let dom_node = dom.get(id).unwrap();
 
+        // Widgets can be laid out more than once per pass, in which case only
+        // the last layout counts.
+        self.warnings.remove(id.index());
+
         let context = LayoutContext {
             dom,
             input,
@@ -156,6 +195,14 @@ impl LayoutDom {
 
         let size = dom_node.widget.layout(context, constraints);
 
+        if cfg!(debug_assertions) {
+            if let Some(violation) = ConstraintViolation::check(constraints, size) {
+                self.warnings_mut(id).constraint_violation = Some(violation);
+            }
+
+            self.log_warnings(id, &dom_node);
+        }
+
         // If the widget called new_layer() during layout, it will be on top of
         // the mouse interest layer stack.
         let new_layer = self.interest_mouse.current_layer_root() == Some(id);
@@ -180,6 +227,8 @@ impl LayoutDom {
                 clip: Rect::ZERO,
                 new_layer,
                 event_interest,
+                baseline: self.baselines.get(id.index()).copied(),
+                transform: None,
             },
         );
 
@@ -206,6 +255,109 @@ impl LayoutDom {
         self.set_clip_logic(dom, ClipLogic::Override(AbstractClipRect::Viewport));
     }
 
+    /// Reports the distance from the top of the currently active widget to the
+    /// baseline of its first line of text. Containers can use this to align
+    /// children by their baselines.
+    pub fn set_baseline(&mut self, dom: &Dom, baseline: f32) {
+        self.baselines.insert_at(dom.current().index(), baseline);
+    }
+
+    /// Returns the baseline that a widget reported during layout, measured
+    /// from its top edge.
+    pub fn baseline(&self, id: WidgetId) -> Option<f32> {
+        self.baselines.get(id.index()).copied()
+    }
+
+    /// Reports the baseline of the first of the given children that has one,
+    /// offset by that child's position, as the baseline of the currently
+    /// active widget. Must be called after the children have been positioned.
+    ///
+    /// Does nothing if none of the children have a baseline.
+    pub fn propagate_baseline(&mut self, dom: &Dom, children: &[WidgetId]) {
+        let first = children
+            .iter()
+            .find_map(|&child| Some((child, self.baseline(child)?)));
+
+        if let Some((child, baseline)) = first {
+            let offset = self.get(child).map(|node| node.rect.pos().y).unwrap_or(0.0);
+            self.set_baseline(dom, baseline + offset);
+        }
+    }
+
+    /// Sets the transform applied to the currently active widget and its
+    /// descendants when they are painted and hit tested.
+    ///
+    /// The transform is expressed relative to the top-left corner of the
+    /// widget. It does not change the layout of any widgets.
+    pub fn set_transform(&mut self, dom: &Dom, transform: Affine2) {
+        self.transforms.insert_at(dom.current().index(), transform);
+    }
+
+    /// Reports that the content of the currently active widget extends past
+    /// its edges, like a list whose children don't fit.
+    ///
+    /// Overflows are only recorded in debug builds. See
+    /// [`LayoutDom::warnings`].
+    pub fn report_overflow(&mut self, dom: &Dom, overflow: Overflow) {
+        if cfg!(debug_assertions) && !overflow.is_none() {
+            let warnings = self.warnings_mut(dom.current());
+            warnings.overflow = warnings.overflow.max(overflow);
+        }
+    }
+
+    /// Returns the layout problems found in a widget during the last layout
+    /// pass, if there were any. Always returns `None` in release builds.
+    pub fn warnings(&self, id: WidgetId) -> Option<&LayoutWarnings> {
+        self.warnings.get(id.index())
+    }
+
+    fn warnings_mut(&mut self, id: WidgetId) -> &mut LayoutWarnings {
+        if !self.warnings.contains(id.index()) {
+            self.warnings
+                .insert_at(id.index(), LayoutWarnings::default());
+        }
+
+        self.warnings.get_mut(id.index()).unwrap()
+    }
+
+    /// Logs the warnings of a widget that it didn't have in the previous
+    /// layout pass, so that they aren't logged every frame.
+    fn log_warnings(&self, id: WidgetId, node: &DomNode) {
+        let Some(warnings) = self.warnings.get(id.index()) else {
+            return;
+        };
+
+        let previous = self
+            .previous_warnings
+            .get(id.index())
+            .copied()
+            .unwrap_or_default();
+        let name = node.widget.type_name();
+        let callsite = node.callsite();
+
+        if let Some(violation) = warnings.constraint_violation {
+            if previous.constraint_violation.is_none() {
+                log::warn!(
+                    "{name} created at {callsite} returned size {} which does not satisfy its constraints (min {}, max {})",
+                    violation.size,
+                    violation.constraints.min,
+                    violation.constraints.max,
+                );
+            }
+        }
+
+        if !warnings.overflow.is_none() && previous.overflow.is_none() {
+            let overflow = warnings.overflow;
+            log::warn!(
+                "{name} created at {callsite} overflowed by {} on the left, {} on the top, {} on the right, and {} on the bottom",
+                overflow.left,
+                overflow.top,
+                overflow.right,
+                overflow.bottom,
+            );
+        }
+    }
+
     /// Put this widget and its children into a new layer.
     pub fn new_layer(&mut self, dom: &Dom) {
         self.interest_mouse.push_layer(dom.current());
@@ -221,16 +373,29 @@ impl LayoutDom {
     fn resolve_positions(&mut self, dom: &Dom) {
         let mut queue = VecDeque::new();
 
-        queue.push_back((dom.root(), self.viewport().pos()));
+        queue.push_back((dom.root(), self.viewport().pos(), None));
 
-        while let Some((id, parent_pos)) = queue.pop_front() {
+        while let Some((id, parent_pos, parent_transform)) = queue.pop_front() {
             if let Some(layout_node) = self.nodes.get_mut(id.index()) {
                 let node = dom.get(id).unwrap();
-                layout_node
-                    .rect
-                    .set_pos((layout_node.rect.pos() + parent_pos).round());
-
-                queue.extend(node.children.iter().map(|&id| (id, layout_node.rect.pos())));
+                let pos = (layout_node.rect.pos() + parent_pos).round();
+                layout_node.rect.set_pos(pos);
+
+                // Transforms are relative to the widget that set them, so we
+                // can only make them absolute once its position is known.
+                let transform = match self.transforms.get(id.index()) {
+                    Some(&local) => {
+                        let local = Affine2::from_translation(pos)
+                            * local
+                            * Affine2::from_translation(-pos);
+
+                        Some(parent_transform.map_or(local, |parent: Affine2| parent * local))
+                    }
+                    None => parent_transform,
+                };
+                layout_node.transform = transform;
+
+                queue.extend(node.children.iter().map(|&id| (id, pos, transform)));
             }
         }
     }
@@ -0,0 +1,131 @@
+use glam::Vec2;
+
+use crate::geometry::{Constraints, Rect};
+
+/// How much a widget's size may differ from its constraints before it's
+/// reported, to allow for rounding errors.
+const TOLERANCE: f32 = 0.01;
+
+/// Layout problems found in a widget by the debug checks in
+/// [`LayoutDom::calculate`][super::LayoutDom::calculate]. These checks only run
+/// in debug builds.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LayoutWarnings {
+    /// Set if the widget returned a size that didn't satisfy the constraints it
+    /// was laid out with.
+    pub constraint_violation: Option<ConstraintViolation>,
+
+    /// How far the widget or its content extends past each of its edges.
+    pub overflow: Overflow,
+}
+
+/// A size returned by a widget that didn't satisfy its constraints.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConstraintViolation {
+    /// The constraints the widget was laid out with.
+    pub constraints: Constraints,
+    /// The size the widget returned.
+    pub size: Vec2,
+}
+
+/// How far content extends past each edge of a widget, in logical pixels.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Overflow {
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+}
+
+impl LayoutWarnings {
+    /// Tells whether any problems were found.
+    pub fn is_empty(&self) -> bool {
+        self.constraint_violation.is_none() && self.overflow.is_none()
+    }
+
+    /// Combines the widget's overflow with how far it extends past the space
+    /// its constraints allowed.
+    pub fn overflow_edges(&self) -> Overflow {
+        match self.constraint_violation {
+            Some(violation) => self.overflow.max(violation.overflow()),
+            None => self.overflow,
+        }
+    }
+}
+
+impl ConstraintViolation {
+    /// Checks whether `size` satisfies `constraints`, returning a violation if
+    /// it doesn't.
+    pub fn check(constraints: Constraints, size: Vec2) -> Option<Self> {
+        // Constraints that can't be satisfied are the fault of the parent, not
+        // the widget being laid out.
+        if constraints.min.cmpgt(constraints.max).any() {
+            return None;
+        }
+
+        let too_small = (size + TOLERANCE).cmplt(constraints.min).any();
+        let too_large = (size - TOLERANCE).cmpgt(constraints.max).any();
+
+        if too_small || too_large {
+            Some(Self { constraints, size })
+        } else {
+            None
+        }
+    }
+
+    /// How far the widget extends past the space its constraints allowed,
+    /// which is always towards its right and bottom edges.
+    pub fn overflow(&self) -> Overflow {
+        let excess = (self.size - self.constraints.max).max(Vec2::ZERO);
+
+        Overflow {
+            right: excess.x,
+            bottom: excess.y,
+            ..Overflow::NONE
+        }
+    }
+}
+
+impl Overflow {
+    /// No overflow on any edge.
+    pub const NONE: Self = Self {
+        left: 0.0,
+        top: 0.0,
+        right: 0.0,
+        bottom: 0.0,
+    };
+
+    /// Tells whether nothing extends past any edge.
+    pub fn is_none(&self) -> bool {
+        self.left <= TOLERANCE
+            && self.top <= TOLERANCE
+            && self.right <= TOLERANCE
+            && self.bottom <= TOLERANCE
+    }
+
+    /// Finds how far a child with the given rectangle extends past the edges
+    /// of a container with the given size. The child's position is relative to
+    /// the container.
+    pub fn of_child(container_size: Vec2, child: Rect) -> Self {
+        // Ignore tiny overflows caused by rounding errors.
+        let excess = |amount: f32| if amount > TOLERANCE { amount } else { 0.0 };
+
+        Self {
+            left: excess(-child.pos().x),
+            top: excess(-child.pos().y),
+            right: excess(child.max().x - container_size.x),
+            bottom: excess(child.max().y - container_size.y),
+        }
+    }
+
+    /// Combines two overflows, taking the largest overflow on each edge.
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            left: self.left.max(other.left),
+            top: self.top.max(other.top),
+            right: self.right.max(other.right),
+            bottom: self.bottom.max(other.bottom),
+        }
+    }
+}
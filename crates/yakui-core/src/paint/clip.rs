@@ -0,0 +1,214 @@
+use std::f32::consts::TAU;
+use std::sync::Arc;
+
+use glam::{Affine2, Vec2};
+
+use crate::geometry::Rect;
+
+/// The furthest that the tessellated edges of curved clip shapes may be from
+/// the true curve, in the units of the shape.
+const CURVE_TOLERANCE: f32 = 0.2;
+
+/// A shape that paint calls can be clipped to in addition to their clip rect.
+///
+/// Shapes passed to [`PaintDom::push_clip_shape`][super::PaintDom::push_clip_shape]
+/// are in logical pixels, like widget rects. Shapes stored in a [`ClipMask`]
+/// have been converted to physical pixels, like the clip rect of each paint
+/// call.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClipShape {
+    /// A rectangle with a separate radius for each of its corners.
+    #[allow(missing_docs)]
+    RoundedRect {
+        rect: Rect,
+        top_left: f32,
+        top_right: f32,
+        bottom_left: f32,
+        bottom_right: f32,
+    },
+
+    /// A circle.
+    #[allow(missing_docs)]
+    Circle { center: Vec2, radius: f32 },
+
+    /// An arbitrary shape made out of triangles, where every three points form
+    /// a triangle.
+    Mesh(Vec<Vec2>),
+}
+
+impl ClipShape {
+    /// A rectangle with the same radius for all of its corners.
+    pub fn rounded_rect(rect: Rect, radius: f32) -> Self {
+        Self::RoundedRect {
+            rect,
+            top_left: radius,
+            top_right: radius,
+            bottom_left: radius,
+            bottom_right: radius,
+        }
+    }
+
+    /// A circle with the given center and radius.
+    pub fn circle(center: Vec2, radius: f32) -> Self {
+        Self::Circle { center, radius }
+    }
+
+    /// A shape made out of a list of triangles, where every three points form a
+    /// triangle.
+    pub fn mesh<I: IntoIterator<Item = Vec2>>(triangles: I) -> Self {
+        Self::Mesh(triangles.into_iter().collect())
+    }
+
+    /// Tessellates the shape into a list of triangles, where every three points
+    /// form a triangle.
+    pub fn triangles(&self) -> Vec<Vec2> {
+        match self {
+            Self::RoundedRect {
+                rect,
+                top_left,
+                top_right,
+                bottom_left,
+                bottom_right,
+            } => {
+                let max_radius = rect.size().min_element().max(0.0) / 2.0;
+                let (min, max) = (rect.pos(), rect.max());
+
+                let corners = [
+                    (*top_right, Vec2::new(max.x, min.y), Vec2::new(-1.0, 1.0)),
+                    (*top_left, min, Vec2::new(1.0, 1.0)),
+                    (*bottom_left, Vec2::new(min.x, max.y), Vec2::new(1.0, -1.0)),
+                    (*bottom_right, max, Vec2::new(-1.0, -1.0)),
+                ];
+
+                let mut outline = Vec::new();
+                for (i, (radius, corner, inward)) in corners.into_iter().enumerate() {
+                    let radius = radius.clamp(0.0, max_radius);
+                    if radius <= 0.0 {
+                        outline.push(corner);
+                        continue;
+                    }
+
+                    let center = corner + inward * radius;
+                    let start_angle = i as f32 * TAU / 4.0;
+                    let segments = arc_segments(radius, TAU / 4.0);
+
+                    for segment in 0..=segments {
+                        let angle = start_angle + segment as f32 / segments as f32 * TAU / 4.0;
+                        outline.push(center + radius * Vec2::new(angle.cos(), -angle.sin()));
+                    }
+                }
+
+                fan(rect.center(), &outline)
+            }
+
+            Self::Circle { center, radius } => {
+                let segments = arc_segments(*radius, TAU).max(3);
+                let outline: Vec<Vec2> = (0..segments)
+                    .map(|segment| {
+                        let angle = segment as f32 / segments as f32 * TAU;
+                        *center + *radius * Vec2::new(angle.cos(), angle.sin())
+                    })
+                    .collect();
+
+                fan(*center, &outline)
+            }
+
+            Self::Mesh(triangles) => triangles.clone(),
+        }
+    }
+
+    /// Applies a transform to the shape. Rounded rectangles and circles keep
+    /// their shape if the transform only translates them and scales them
+    /// uniformly, but are turned into meshes otherwise.
+    pub fn transformed(&self, transform: Affine2) -> Self {
+        let matrix = transform.matrix2;
+        let scale = matrix.x_axis.x;
+        let uniform = matrix.x_axis.y == 0.0
+            && matrix.y_axis.x == 0.0
+            && matrix.y_axis.y == scale
+            && scale > 0.0;
+
+        match *self {
+            Self::RoundedRect {
+                rect,
+                top_left,
+                top_right,
+                bottom_left,
+                bottom_right,
+            } if uniform => Self::RoundedRect {
+                rect: Rect::from_pos_size(
+                    transform.transform_point2(rect.pos()),
+                    rect.size() * scale,
+                ),
+                top_left: top_left * scale,
+                top_right: top_right * scale,
+                bottom_left: bottom_left * scale,
+                bottom_right: bottom_right * scale,
+            },
+
+            Self::Circle { center, radius } if uniform => Self::Circle {
+                center: transform.transform_point2(center),
+                radius: radius * scale,
+            },
+
+            _ => Self::Mesh(
+                self.triangles()
+                    .into_iter()
+                    .map(|point| transform.transform_point2(point))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// The shapes that a paint call is clipped to. Only the parts of the call
+/// that are inside of every shape, as well as inside of the call's clip rect,
+/// should be painted.
+#[derive(Debug, Clone)]
+pub struct ClipMask {
+    shapes: Arc<[ClipShape]>,
+}
+
+impl ClipMask {
+    pub(crate) fn new(shapes: &[ClipShape]) -> Self {
+        Self {
+            shapes: shapes.into(),
+        }
+    }
+
+    /// The shapes in the mask, in physical pixels, from outermost to innermost.
+    pub fn shapes(&self) -> &[ClipShape] {
+        &self.shapes
+    }
+}
+
+impl PartialEq for ClipMask {
+    fn eq(&self, other: &Self) -> bool {
+        // Consecutive paint calls usually share the same mask, which is much
+        // cheaper to check than comparing every shape.
+        Arc::ptr_eq(&self.shapes, &other.shapes) || self.shapes == other.shapes
+    }
+}
+
+/// How many line segments an arc needs to stay within [`CURVE_TOLERANCE`] of
+/// the true curve.
+fn arc_segments(radius: f32, angle: f32) -> u32 {
+    if radius <= CURVE_TOLERANCE {
+        return 1;
+    }
+
+    let max_step = 2.0 * f32::acos(1.0 - CURVE_TOLERANCE / radius);
+    (angle / max_step).ceil().max(1.0) as u32
+}
+
+/// Turns a convex outline into triangles that all share the given center.
+fn fan(center: Vec2, outline: &[Vec2]) -> Vec<Vec2> {
+    let mut triangles = Vec::with_capacity(outline.len() * 3);
+
+    for (i, &point) in outline.iter().enumerate() {
+        let next = outline[(i + 1) % outline.len()];
+        triangles.extend_from_slice(&[center, point, next]);
+    }
+
+    triangles
+}
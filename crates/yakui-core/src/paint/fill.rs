@@ -0,0 +1,353 @@
+use glam::{Vec2, Vec4};
+
+use crate::geometry::{Color, Rect};
+
+use super::Vertex;
+
+/// How many pieces radial gradients split the longest side of the shape they
+/// fill into, at most. Colors are interpolated linearly between the pieces.
+const RADIAL_SUBDIVISIONS: f32 = 32.0;
+
+/// The smallest piece that radial gradients split shapes into, in logical
+/// pixels.
+const MIN_RADIAL_EDGE: f32 = 2.0;
+
+/// How a shape is filled: with a single color or with a gradient.
+///
+/// Gradients are positioned relative to the rect of the shape they fill, where
+/// (0, 0) is the top-left corner of the rect and (1, 1) is the bottom-right
+/// corner. Colors between stops are interpolated in linear space.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fill {
+    /// A single color.
+    Solid(Color),
+    /// Colors that change along a line.
+    Linear(LinearGradient),
+    /// Colors that change with the distance from a point.
+    Radial(RadialGradient),
+}
+
+/// A gradient whose colors change along the line from `start` to `end`. Past
+/// either end of the line, the color of the nearest stop is used.
+#[derive(Debug, Clone, PartialEq)]
+#[allow(missing_docs)]
+pub struct LinearGradient {
+    pub start: Vec2,
+    pub end: Vec2,
+    pub stops: Vec<ColorStop>,
+}
+
+/// A gradient whose colors change with the distance from `center`, reaching
+/// the last stop at `radius`. On shapes that aren't square, the gradient is
+/// stretched into an ellipse along with the shape.
+#[derive(Debug, Clone, PartialEq)]
+#[allow(missing_docs)]
+pub struct RadialGradient {
+    pub center: Vec2,
+    pub radius: f32,
+    pub stops: Vec<ColorStop>,
+}
+
+/// A color at a position along a gradient, from 0.0 at the start of the
+/// gradient to 1.0 at its end.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[allow(missing_docs)]
+pub struct ColorStop {
+    pub offset: f32,
+    pub color: Color,
+}
+
+impl Fill {
+    /// A linear gradient from `start` to `end`, with the given offsets and
+    /// colors as stops.
+    pub fn linear<I>(start: Vec2, end: Vec2, stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f32, Color)>,
+    {
+        Self::Linear(LinearGradient {
+            start,
+            end,
+            stops: ColorStop::sorted(stops),
+        })
+    }
+
+    /// A linear gradient from the top of a shape to its bottom.
+    pub fn vertical(top: Color, bottom: Color) -> Self {
+        Self::linear(Vec2::ZERO, Vec2::Y, [(0.0, top), (1.0, bottom)])
+    }
+
+    /// A linear gradient from the left of a shape to its right.
+    pub fn horizontal(left: Color, right: Color) -> Self {
+        Self::linear(Vec2::ZERO, Vec2::X, [(0.0, left), (1.0, right)])
+    }
+
+    /// A radial gradient around `center`, with the given offsets and colors as
+    /// stops.
+    pub fn radial<I>(center: Vec2, radius: f32, stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f32, Color)>,
+    {
+        Self::Radial(RadialGradient {
+            center,
+            radius,
+            stops: ColorStop::sorted(stops),
+        })
+    }
+
+    /// Returns the color of the fill if it's a single color.
+    pub fn as_solid(&self) -> Option<Color> {
+        match self {
+            Self::Solid(color) => Some(*color),
+            _ => None,
+        }
+    }
+
+    /// The linear color of the fill at `position`, for a shape whose rect is
+    /// `rect`.
+    pub fn color_at(&self, rect: Rect, position: Vec2) -> Vec4 {
+        match self {
+            Self::Solid(color) => color.to_linear(),
+            Self::Linear(gradient) => sample(&gradient.stops, gradient.offset_at(rect, position)),
+            Self::Radial(gradient) => sample(&gradient.stops, gradient.offset_at(rect, position)),
+        }
+    }
+
+    /// Fills a mesh whose rect is `rect`, multiplying the color of each vertex
+    /// by the color of the fill.
+    ///
+    /// Gradients can't be represented by vertex colors alone, so triangles are
+    /// split up where needed. For linear gradients, triangles are split along
+    /// each stop, which is exact. For radial gradients, triangles are split
+    /// into smaller triangles, which approximates the gradient.
+    pub fn apply(&self, rect: Rect, vertices: &mut Vec<Vertex>, indices: &mut Vec<u32>) {
+        let mut triangles = match self {
+            Self::Solid(color) => {
+                let color = color.to_linear();
+                for vertex in vertices.iter_mut() {
+                    vertex.color *= color;
+                }
+                return;
+            }
+
+            Self::Linear(gradient) => {
+                let mut cuts: Vec<f32> = gradient.stops.iter().map(|stop| stop.offset).collect();
+                cuts.dedup();
+
+                let mut triangles = Vec::new();
+                for triangle in indices.chunks_exact(3) {
+                    let triangle = [0, 1, 2].map(|i| vertices[triangle[i] as usize]);
+                    slice_triangle(triangle, &cuts, &mut triangles, |position| {
+                        gradient.offset_at(rect, position)
+                    });
+                }
+                triangles
+            }
+
+            Self::Radial(_) => {
+                let max_edge =
+                    (rect.size().max_element() / RADIAL_SUBDIVISIONS).max(MIN_RADIAL_EDGE);
+
+                let mut triangles = Vec::new();
+                for triangle in indices.chunks_exact(3) {
+                    let triangle = [0, 1, 2].map(|i| vertices[triangle[i] as usize]);
+                    subdivide_triangle(triangle, max_edge, &mut triangles);
+                }
+                triangles
+            }
+        };
+
+        for vertex in &mut triangles {
+            vertex.color *= self.color_at(rect, vertex.position);
+        }
+
+        indices.clear();
+        indices.extend(0..triangles.len() as u32);
+        *vertices = triangles;
+    }
+}
+
+impl From<Color> for Fill {
+    fn from(color: Color) -> Self {
+        Self::Solid(color)
+    }
+}
+
+impl LinearGradient {
+    /// How far `position` is along the gradient, where 0.0 is the start and
+    /// 1.0 is the end.
+    pub fn offset_at(&self, rect: Rect, position: Vec2) -> f32 {
+        let direction = self.end - self.start;
+        let length_squared = direction.length_squared();
+        if length_squared == 0.0 {
+            return 0.0;
+        }
+
+        (relative_position(rect, position) - self.start).dot(direction) / length_squared
+    }
+}
+
+impl RadialGradient {
+    /// How far `position` is from the center of the gradient, where 1.0 is
+    /// the radius of the gradient.
+    pub fn offset_at(&self, rect: Rect, position: Vec2) -> f32 {
+        if self.radius <= 0.0 {
+            return 0.0;
+        }
+
+        relative_position(rect, position).distance(self.center) / self.radius
+    }
+}
+
+impl ColorStop {
+    /// Creates a new color stop.
+    pub fn new(offset: f32, color: Color) -> Self {
+        Self { offset, color }
+    }
+
+    fn sorted<I>(stops: I) -> Vec<Self>
+    where
+        I: IntoIterator<Item = (f32, Color)>,
+    {
+        let mut stops: Vec<Self> = stops
+            .into_iter()
+            .map(|(offset, color)| Self::new(offset, color))
+            .collect();
+
+        stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+        stops
+    }
+}
+
+/// Converts a position into the space of a shape's rect, where (0, 0) is its
+/// top-left corner and (1, 1) is its bottom-right corner.
+fn relative_position(rect: Rect, position: Vec2) -> Vec2 {
+    let size = rect.size();
+    let relative = position - rect.pos();
+
+    Vec2::new(
+        if size.x > 0.0 {
+            relative.x / size.x
+        } else {
+            0.0
+        },
+        if size.y > 0.0 {
+            relative.y / size.y
+        } else {
+            0.0
+        },
+    )
+}
+
+/// Finds the linear color at `offset` along a sorted list of stops.
+fn sample(stops: &[ColorStop], offset: f32) -> Vec4 {
+    let (Some(first), Some(last)) = (stops.first(), stops.last()) else {
+        return Vec4::ZERO;
+    };
+
+    if offset <= first.offset {
+        return first.color.to_linear();
+    }
+
+    if offset >= last.offset {
+        return last.color.to_linear();
+    }
+
+    let next = stops.partition_point(|stop| stop.offset <= offset);
+    let (a, b) = (stops[next - 1], stops[next]);
+    let ratio = (offset - a.offset) / (b.offset - a.offset);
+
+    a.color.to_linear().lerp(b.color.to_linear(), ratio)
+}
+
+fn lerp_vertex(a: Vertex, b: Vertex, ratio: f32) -> Vertex {
+    Vertex {
+        position: a.position.lerp(b.position, ratio),
+        texcoord: a.texcoord.lerp(b.texcoord, ratio),
+        color: a.color.lerp(b.color, ratio),
+    }
+}
+
+/// Splits a triangle along the lines where `offset_at` equals each of `cuts`,
+/// which must be sorted. `offset_at` must change linearly with position.
+fn slice_triangle(
+    triangle: [Vertex; 3],
+    cuts: &[f32],
+    output: &mut Vec<Vertex>,
+    offset_at: impl Fn(Vec2) -> f32,
+) {
+    let mut rest = triangle.to_vec();
+
+    for &cut in cuts {
+        if rest.is_empty() {
+            return;
+        }
+
+        let (below, above) = split_polygon(&rest, |vertex| offset_at(vertex.position) - cut);
+        fan(&below, output);
+        rest = above;
+    }
+
+    fan(&rest, output);
+}
+
+/// Splits a convex polygon into the parts where `distance` is negative and
+/// positive. `distance` must change linearly with position.
+fn split_polygon(
+    polygon: &[Vertex],
+    distance: impl Fn(&Vertex) -> f32,
+) -> (Vec<Vertex>, Vec<Vertex>) {
+    let mut below = Vec::new();
+    let mut above = Vec::new();
+
+    for (i, &vertex) in polygon.iter().enumerate() {
+        let next = polygon[(i + 1) % polygon.len()];
+        let (d, next_d) = (distance(&vertex), distance(&next));
+
+        if d <= 0.0 {
+            below.push(vertex);
+        }
+        if d >= 0.0 {
+            above.push(vertex);
+        }
+
+        if (d < 0.0 && next_d > 0.0) || (d > 0.0 && next_d < 0.0) {
+            let crossing = lerp_vertex(vertex, next, d / (d - next_d));
+            below.push(crossing);
+            above.push(crossing);
+        }
+    }
+
+    (below, above)
+}
+
+/// Turns a convex polygon into triangles.
+fn fan(polygon: &[Vertex], output: &mut Vec<Vertex>) {
+    for i in 1..polygon.len().saturating_sub(1) {
+        output.extend_from_slice(&[polygon[0], polygon[i], polygon[i + 1]]);
+    }
+}
+
+/// Splits a triangle in half along its longest edge until none of its edges
+/// are longer than `max_edge`.
+fn subdivide_triangle(triangle: [Vertex; 3], max_edge: f32, output: &mut Vec<Vertex>) {
+    let edge_length = |i: usize| {
+        triangle[i]
+            .position
+            .distance(triangle[(i + 1) % 3].position)
+    };
+
+    let longest = (0..3)
+        .max_by(|&a, &b| edge_length(a).total_cmp(&edge_length(b)))
+        .unwrap();
+
+    if edge_length(longest) <= max_edge {
+        output.extend_from_slice(&triangle);
+        return;
+    }
+
+    let [a, b, c] = [0, 1, 2].map(|i| triangle[(longest + i) % 3]);
+    let middle = lerp_vertex(a, b, 0.5);
+
+    subdivide_triangle([a, middle, c], max_edge, output);
+    subdivide_triangle([middle, b, c], max_edge, output);
+}
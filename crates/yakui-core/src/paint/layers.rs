@@ -2,13 +2,43 @@ use std::ops::Deref;
 
 use crate::geometry::Rect;
 
-use super::PaintCall;
+use super::{ClipMask, PaintCall};
 
 /// Contains all of the draw calls for a single layer of the UI.
 #[derive(Debug)]
 pub struct PaintLayer {
     /// The draw calls that can be used to paint this layer.
-    pub calls: Vec<(Rect, PaintCall)>,
+    pub calls: Vec<PaintLayerCall>,
+}
+
+/// A draw call in a [`PaintLayer`], along with what it is clipped to.
+#[derive(Debug)]
+pub struct PaintLayerCall {
+    pub(crate) clip: Rect,
+    pub(crate) mask: Option<ClipMask>,
+    pub(crate) call: PaintCall,
+}
+
+impl PaintLayerCall {
+    pub(crate) fn new(clip: Rect, mask: Option<ClipMask>, call: PaintCall) -> Self {
+        Self { clip, mask, call }
+    }
+
+    /// The rect that the call is clipped to, in physical pixels.
+    pub fn clip(&self) -> Rect {
+        self.clip
+    }
+
+    /// The shape that the call is clipped to in addition to its clip rect, if
+    /// there is one.
+    pub fn mask(&self) -> Option<&ClipMask> {
+        self.mask.as_ref()
+    }
+
+    /// The draw call itself.
+    pub fn call(&self) -> &PaintCall {
+        &self.call
+    }
 }
 
 impl PaintLayer {
@@ -63,6 +93,15 @@ impl PaintLayers {
         self.layer_stack.push(index);
     }
 
+    /// Consumes the layers, returning the paint calls of every layer in the
+    /// order they should be drawn.
+    pub(crate) fn into_calls(self) -> Vec<PaintLayerCall> {
+        self.layers
+            .into_iter()
+            .flat_map(|layer| layer.calls)
+            .collect()
+    }
+
     /// Pop the most recently pushed paint layer. This should always be paired
     /// with a call to `push`.
     pub fn pop(&mut self) {
@@ -1,13 +1,21 @@
 //! Defines primitives for painting widgets, including the Paint DOM.
 
+mod clip;
+mod fill;
 mod layers;
 mod paint_dom;
 mod primitives;
 mod rect;
+mod render_target;
+mod stats;
 mod texture;
 
+pub use self::clip::*;
+pub use self::fill::*;
 pub use self::layers::*;
 pub use self::paint_dom::*;
 pub use self::primitives::*;
 pub use self::rect::PaintRect;
+pub use self::render_target::RenderTarget;
+pub use self::stats::*;
 pub use self::texture::*;
@@ -3,23 +3,33 @@ use std::collections::HashMap;
 use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
 
-use glam::Vec2;
-use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use glam::{Affine2, Vec2, Vec4};
+use parking_lot::RwLock;
 use thunderdome::Arena;
 
 use crate::dom::Dom;
-use crate::geometry::Rect;
-use crate::id::{ManagedTextureId, WidgetId};
-use crate::layout::LayoutDom;
-use crate::paint::{PaintCall, Pipeline, YakuiPaintCall};
+use crate::geometry::{Color, Rect};
+use crate::id::{ManagedTextureId, TextureId, WidgetId};
+use crate::layout::{LayoutDom, Overflow};
+use crate::paint::{
+    ClipMask, ClipShape, PaintCall, PaintRect, PaintStats, Pipeline, RenderTarget,
+    WidgetPaintStats, YakuiPaintCall,
+};
 use crate::widget::PaintContext;
 use crate::Globals;
 
-use super::layers::PaintLayers;
+use super::layers::{PaintLayerCall, PaintLayers};
 use super::primitives::{PaintMesh, Vertex};
 use super::texture::{Texture, TextureChange};
 use super::UserPaintCallId;
 
+/// How thick the striped bands painted along the overflowing edges of widgets
+/// are, in logical pixels.
+const OVERFLOW_BAND_THICKNESS: f32 = 8.0;
+
+/// How wide each stripe in an overflow band is, in logical pixels.
+const OVERFLOW_STRIPE_WIDTH: f32 = 6.0;
+
 #[derive(Debug, Clone, Copy, Default)]
 /// Contains all information about the limits of the paint device.
 pub struct PaintLimits {
@@ -29,6 +39,10 @@ pub struct PaintLimits {
     pub max_texture_size_2d: u32,
     /// Maximum texture size of a 3D texture.
     pub max_texture_size_3d: u32,
+    /// Whether the renderer paints [`PaintDom::render_targets`] into offscreen
+    /// textures. Widgets that would use them fall back to painting directly
+    /// otherwise.
+    pub render_targets: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -78,11 +92,57 @@ pub struct PaintDom {
     pub globals: RefCell<Globals>,
 
     current_clip: Rect,
+    current_transform: Option<Affine2>,
+
+    /// The bounding box of the widget that is currently being painted, in
+    /// physical pixels.
+    current_bounds: Rect,
+
+    /// The clip shapes pushed by the widgets currently being painted, in
+    /// physical pixels, and the mask made from them.
+    clip_shapes: Vec<ClipShape>,
+    current_mask: Option<ClipMask>,
+
+    /// The clip rect, in physical pixels, of the parent of the outermost
+    /// transformed widget that is currently being painted.
+    transform_bounds: Option<Rect>,
+
+    /// The opacity that meshes are currently painted with, and the opacities
+    /// that were current before each call to [`PaintDom::push_opacity`].
+    current_opacity: f32,
+    opacity_stack: Vec<f32>,
+
+    /// The render targets painted this frame, and the ones that are currently
+    /// being painted into.
+    render_targets: Vec<RenderTarget>,
+    target_stack: Vec<PendingRenderTarget>,
+
+    /// The rects of the render targets painted in the previous frame, whose
+    /// textures can be reused if the surface is still the same size.
+    previous_targets: HashMap<WidgetId, Rect>,
+    targets_surface_size: Vec2,
+
+    show_overflow: bool,
+
+    /// The vertices added by each widget type this frame, if enabled, and the
+    /// type of the widget that is currently being painted.
+    widget_stats: Option<HashMap<&'static str, WidgetPaintStats>>,
+    current_widget: Option<&'static str>,
 
     #[cfg(debug_assertions)]
     painted_already: bool,
 }
 
+/// A render target that is being painted into, along with the state of the
+/// PaintDom from before it was pushed.
+#[derive(Debug)]
+struct PendingRenderTarget {
+    id: WidgetId,
+    rect: Rect,
+    parent_layers: PaintLayers,
+    parent_opacity: f32,
+}
+
 /// Stores textures for one or more `PaintDom` instances.
 #[derive(Debug, Default)]
 pub struct Textures {
@@ -151,6 +211,24 @@ impl PaintDom {
             globals: RefCell::new(Globals::new()),
 
             current_clip: Rect::ZERO,
+            current_transform: None,
+            current_bounds: Rect::ZERO,
+            clip_shapes: Vec::new(),
+            current_mask: None,
+            transform_bounds: None,
+
+            current_opacity: 1.0,
+            opacity_stack: Vec::new(),
+
+            render_targets: Vec::new(),
+            target_stack: Vec::new(),
+            previous_targets: HashMap::new(),
+            targets_surface_size: Vec2::ZERO,
+
+            show_overflow: false,
+
+            widget_stats: None,
+            current_widget: None,
 
             #[cfg(debug_assertions)]
             painted_already: false,
@@ -184,6 +262,50 @@ impl PaintDom {
         }
     }
 
+    /// Tells whether striped bands are painted along the edges of widgets
+    /// that overflowed during layout.
+    pub fn show_overflow(&self) -> bool {
+        self.show_overflow
+    }
+
+    /// Sets whether striped bands are painted along the edges of widgets that
+    /// overflowed during layout. Overflows are only detected in debug builds;
+    /// see [`LayoutDom::warnings`].
+    pub fn set_show_overflow(&mut self, show: bool) {
+        self.show_overflow = show;
+    }
+
+    /// Tells whether the vertices added by each type of widget are counted
+    /// while painting.
+    pub fn widget_stats(&self) -> bool {
+        self.widget_stats.is_some()
+    }
+
+    /// Sets whether the vertices added by each type of widget are counted
+    /// while painting, which are reported in [`PaintStats::widgets`].
+    pub fn set_widget_stats(&mut self, enabled: bool) {
+        if enabled != self.widget_stats.is_some() {
+            self.widget_stats = enabled.then(HashMap::new);
+        }
+    }
+
+    /// Computes statistics about the paint calls of the last painted frame.
+    pub fn stats(&self) -> PaintStats {
+        let mut widgets: Vec<_> = self
+            .widget_stats
+            .iter()
+            .flat_map(|stats| stats.values().copied())
+            .collect();
+
+        widgets.sort_by(|a, b| {
+            b.vertices
+                .cmp(&a.vertices)
+                .then_with(|| a.type_name.cmp(b.type_name))
+        });
+
+        PaintStats::new(&self.layers, &self.render_targets, widgets)
+    }
+
     /// Get the size of the surface that is being painted onto.
     pub fn surface_size(&self) -> Vec2 {
         self.info.surface_size
@@ -226,32 +348,352 @@ impl PaintDom {
             return;
         }
 
+        let parent_clip = self.current_clip;
+        let parent_transform = self.current_transform;
+        let parent_bounds = self.current_bounds;
+        let parent_transform_bounds = self.transform_bounds;
+
+        // Clip rects can't be rotated, so transformed widgets are clipped to
+        // the bounding box of their transformed clip rect instead, which can
+        // never extend past the clip rect of the untransformed container.
+        let (clip, bounds) = match layout_node.transform {
+            Some(transform) => (
+                transformed_bounds(transform, layout_node.clip),
+                transformed_bounds(transform, layout_node.rect),
+            ),
+            None => (layout_node.clip, layout_node.rect),
+        };
+
+        let scale_factor = self.scale_factor();
+        let min = (bounds.pos() * scale_factor).floor();
+        let max = (bounds.max() * scale_factor).ceil();
+        self.current_bounds = Rect::from_pos_size(min, max - min);
+
         self.current_clip = Rect::from_pos_size(
-            (layout_node.clip.pos() * self.scale_factor()).round(),
-            (layout_node.clip.size() * self.scale_factor()).round(),
+            (clip.pos() * self.scale_factor()).round(),
+            (clip.size() * self.scale_factor()).round(),
         )
         .constrain(layout.unscaled_viewport());
 
+        if layout_node.transform.is_some() {
+            let bounds = *self.transform_bounds.get_or_insert(parent_clip);
+            self.current_clip = self.current_clip.constrain(bounds);
+        }
+
+        self.current_transform = layout_node.transform;
+
         if layout_node.new_layer {
             self.layers.push();
         }
 
         dom.enter(id);
 
+        let node = dom.get(id).unwrap();
+        let parent_widget = self.current_widget;
+
+        if let Some(stats) = &mut self.widget_stats {
+            let type_name = node.widget.type_name();
+            self.current_widget = Some(type_name);
+
+            stats
+                .entry(type_name)
+                .or_insert(WidgetPaintStats {
+                    type_name,
+                    instances: 0,
+                    vertices: 0,
+                })
+                .instances += 1;
+        }
+
         let context = PaintContext {
             dom,
             layout,
             clip: self.current_clip,
             paint: self,
         };
-        let node = dom.get(id).unwrap();
         node.widget.paint(context);
 
+        if self.show_overflow {
+            if let Some(warnings) = layout.warnings(id) {
+                self.paint_overflow(layout_node.rect, warnings.overflow_edges());
+            }
+        }
+
         dom.exit(id);
 
+        self.current_widget = parent_widget;
+
         if layout_node.new_layer {
             self.layers.pop();
         }
+
+        self.current_clip = parent_clip;
+        self.current_transform = parent_transform;
+        self.current_bounds = parent_bounds;
+        self.transform_bounds = parent_transform_bounds;
+    }
+
+    /// Clip everything painted until the matching call to
+    /// [`PaintDom::pop_clip_shape`] to the given shape, in addition to the clip
+    /// rect of the widget being painted. The shape is in logical pixels, like
+    /// the rects of widgets, and is affected by the transform of the widget
+    /// being painted.
+    ///
+    /// Pushing multiple shapes clips painting to the intersection of all of
+    /// them.
+    pub fn push_clip_shape(&mut self, shape: ClipShape) {
+        let transform = Affine2::from_scale(Vec2::splat(self.scale_factor()))
+            * self.current_transform.unwrap_or(Affine2::IDENTITY);
+
+        self.clip_shapes.push(shape.transformed(transform));
+        self.current_mask = Some(ClipMask::new(&self.clip_shapes));
+    }
+
+    /// Stop clipping to the most recently pushed clip shape. This should
+    /// always be paired with a call to [`PaintDom::push_clip_shape`].
+    pub fn pop_clip_shape(&mut self) {
+        let top = self.clip_shapes.pop();
+        debug_assert!(
+            top.is_some(),
+            "cannot call PaintDom::pop_clip_shape without a corresponding push call"
+        );
+
+        self.current_mask = if self.clip_shapes.is_empty() {
+            None
+        } else {
+            Some(ClipMask::new(&self.clip_shapes))
+        };
+    }
+
+    /// Multiply the alpha of everything painted until the matching call to
+    /// [`PaintDom::pop_opacity`] by the given opacity, in addition to any
+    /// opacity that was already pushed.
+    ///
+    /// Overlapping meshes are faded individually, so they show through each
+    /// other. Painting into a render target and drawing it with
+    /// [`PaintDom::pop_render_target`] fades them together instead. User paint
+    /// calls aren't affected.
+    pub fn push_opacity(&mut self, opacity: f32) {
+        self.opacity_stack.push(self.current_opacity);
+        self.current_opacity *= opacity.clamp(0.0, 1.0);
+    }
+
+    /// Stop applying the most recently pushed opacity. This should always be
+    /// paired with a call to [`PaintDom::push_opacity`].
+    pub fn pop_opacity(&mut self) {
+        let top = self.opacity_stack.pop();
+        debug_assert!(
+            top.is_some(),
+            "cannot call PaintDom::pop_opacity without a corresponding push call"
+        );
+
+        self.current_opacity = top.unwrap_or(1.0);
+    }
+
+    /// Tells whether the renderer paints render targets, which is needed to
+    /// call [`PaintDom::push_render_target`]. See
+    /// [`PaintLimits::render_targets`].
+    pub fn render_targets_supported(&self) -> bool {
+        self.limits.is_some_and(|limits| limits.render_targets)
+    }
+
+    /// Returns the render targets painted this frame in the order they need
+    /// to be drawn, which puts targets before any target they're drawn into.
+    pub fn render_targets(&self) -> &[RenderTarget] {
+        &self.render_targets
+    }
+
+    /// Paint everything until the matching call to
+    /// [`PaintDom::pop_render_target`] into an offscreen texture owned by the
+    /// given widget. The target covers the part of the widget being painted
+    /// that is inside of its clip rect, so anything its children paint outside
+    /// of its rect is cut off.
+    ///
+    /// Renderers that don't set [`PaintLimits::render_targets`] never draw the
+    /// target, so widgets should check
+    /// [`PaintDom::render_targets_supported`] first.
+    pub fn push_render_target(&mut self, id: WidgetId) {
+        let mut layers = PaintLayers::new();
+        layers.push();
+
+        self.target_stack.push(PendingRenderTarget {
+            id,
+            rect: self.target_rect(),
+            parent_layers: std::mem::replace(&mut self.layers, layers),
+            parent_opacity: self.current_opacity,
+        });
+
+        self.current_opacity = 1.0;
+    }
+
+    /// Stop painting into the most recently pushed render target and draw its
+    /// texture with the given opacity. This should always be paired with a
+    /// call to [`PaintDom::push_render_target`].
+    pub fn pop_render_target(&mut self, opacity: f32) {
+        let Some(target) = self.target_stack.pop() else {
+            panic!("cannot call PaintDom::pop_render_target without a corresponding push call");
+        };
+
+        let layers = std::mem::replace(&mut self.layers, target.parent_layers);
+        self.current_opacity = target.parent_opacity;
+
+        let surface_size = self.surface_size();
+        let calls = layers
+            .into_calls()
+            .into_iter()
+            .map(|call| into_target_space(call, target.rect, surface_size))
+            .collect();
+
+        self.render_targets.push(RenderTarget {
+            id: target.id,
+            rect: target.rect,
+            calls: Some(calls),
+        });
+
+        self.draw_render_target(target.id, target.rect, opacity);
+    }
+
+    /// Forget the render targets painted in the previous frame that the
+    /// renderer doesn't have a texture for anymore, so that they're painted
+    /// again instead of being reused. Renderers should call this before
+    /// painting if they can drop the textures of render targets.
+    pub fn retain_render_targets<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(WidgetId) -> bool,
+    {
+        self.render_targets.retain(|target| keep(target.id));
+    }
+
+    /// Draw the texture that the given widget painted into a render target in
+    /// the previous frame again with the given opacity, without painting
+    /// anything into it.
+    ///
+    /// Returns false without drawing anything if the texture can't be reused,
+    /// because the widget didn't paint into a render target in the previous
+    /// frame, or because the surface or the part of the surface covered by
+    /// the widget being painted changed since then.
+    pub fn reuse_render_target(&mut self, id: WidgetId, opacity: f32) -> bool {
+        let rect = self.target_rect();
+        let reusable = self.render_targets_supported()
+            && self.previous_targets.get(&id) == Some(&rect)
+            && self.render_targets.iter().all(|target| target.id != id);
+
+        if reusable {
+            self.render_targets.push(RenderTarget {
+                id,
+                rect,
+                calls: None,
+            });
+
+            self.draw_render_target(id, rect, opacity);
+        }
+
+        reusable
+    }
+
+    /// The part of the surface that a render target pushed by the widget
+    /// being painted covers, in physical pixels.
+    fn target_rect(&self) -> Rect {
+        let rect = self.current_bounds.constrain(self.current_clip);
+        Rect::from_pos_size(rect.pos(), rect.size().max(Vec2::ZERO))
+    }
+
+    /// Draws the texture of a render target over the part of the surface that
+    /// it covers.
+    fn draw_render_target(&mut self, id: WidgetId, rect: Rect, opacity: f32) {
+        let color = Vec4::new(1.0, 1.0, 1.0, opacity);
+        let scale_factor = self.scale_factor();
+
+        let (min, max) = (rect.pos(), rect.max());
+        let corners = [
+            (min, Vec2::ZERO),
+            (Vec2::new(min.x, max.y), Vec2::Y),
+            (max, Vec2::ONE),
+            (Vec2::new(max.x, min.y), Vec2::X),
+        ];
+        let vertices =
+            corners.map(|(corner, texcoord)| Vertex::new(corner / scale_factor, texcoord, color));
+
+        let mut mesh = PaintMesh::new(vertices, [0u32, 1, 2, 3, 0, 2]);
+        mesh.texture = Some((TextureId::RenderTarget(id), Rect::ONE));
+
+        // The contents of the target were already transformed, and the rect
+        // is in physical pixels.
+        let transform = self.current_transform.take();
+        self.add_mesh(mesh);
+        self.current_transform = transform;
+    }
+
+    /// Paints yellow and black stripes along the overflowing edges of a
+    /// widget.
+    fn paint_overflow(&mut self, rect: Rect, overflow: Overflow) {
+        let pos = rect.pos();
+        let size = rect.size();
+        let thickness = Vec2::splat(OVERFLOW_BAND_THICKNESS).min(size);
+        let far = (size - thickness).max(Vec2::ZERO);
+
+        let bands = [
+            (overflow.left, pos, Vec2::new(thickness.x, size.y)),
+            (overflow.top, pos, Vec2::new(size.x, thickness.y)),
+            (
+                overflow.right,
+                pos + Vec2::new(far.x, 0.0),
+                Vec2::new(thickness.x, size.y),
+            ),
+            (
+                overflow.bottom,
+                pos + Vec2::new(0.0, far.y),
+                Vec2::new(size.x, thickness.y),
+            ),
+        ];
+
+        let parent_clip = self.current_clip;
+        let scale_factor = self.scale_factor();
+        let yellow = Color::YELLOW.to_linear();
+
+        for (amount, band_pos, band_size) in bands {
+            if amount <= 0.0 {
+                continue;
+            }
+
+            let band = Rect::from_pos_size(band_pos, band_size);
+
+            // Stripes are painted across the band's bounding box and clipped
+            // to the band.
+            let clip = match self.current_transform {
+                Some(transform) => transformed_bounds(transform, band),
+                None => band,
+            };
+            self.current_clip = Rect::from_pos_size(
+                (clip.pos() * scale_factor).round(),
+                (clip.size() * scale_factor).round(),
+            )
+            .constrain(parent_clip);
+
+            let mut background = PaintRect::new(band);
+            background.color = Color::BLACK;
+            background.add(self);
+
+            // Each stripe runs diagonally between the lines where x + y
+            // equals `start` and `start + OVERFLOW_STRIPE_WIDTH`.
+            let (top, bottom) = (band.pos().y, band.max().y);
+            let mut start = band.pos().x + top - OVERFLOW_STRIPE_WIDTH;
+            while start < band.max().x + bottom {
+                let end = start + OVERFLOW_STRIPE_WIDTH;
+                let vertices = [
+                    Vec2::new(start - top, top),
+                    Vec2::new(end - top, top),
+                    Vec2::new(end - bottom, bottom),
+                    Vec2::new(start - bottom, bottom),
+                ]
+                .map(|position| Vertex::new(position, Vec2::ZERO, yellow));
+
+                self.add_mesh(PaintMesh::new(vertices, [0u32, 1, 2, 2, 3, 0]));
+                start += OVERFLOW_STRIPE_WIDTH * 2.0;
+            }
+        }
+
+        self.current_clip = parent_clip;
     }
 
     /// Paint all of the widgets in the given DOM.
@@ -260,6 +702,24 @@ impl PaintDom {
         log::debug!("PaintDom:paint_all()");
 
         self.layers.clear();
+        self.clip_shapes.clear();
+        self.current_mask = None;
+        self.current_opacity = 1.0;
+        self.opacity_stack.clear();
+        self.target_stack.clear();
+
+        self.previous_targets.clear();
+        if self.targets_surface_size == self.surface_size() {
+            let previous = self.render_targets.drain(..);
+            self.previous_targets
+                .extend(previous.map(|target| (target.id, target.rect)));
+        }
+        self.render_targets.clear();
+        self.targets_surface_size = self.surface_size();
+
+        if let Some(stats) = &mut self.widget_stats {
+            stats.clear();
+        }
         self.paint(dom, layout, dom.root());
 
         #[cfg(debug_assertions)]
@@ -303,7 +763,8 @@ impl PaintDom {
     pub fn add_mesh<V, I>(&mut self, mesh: PaintMesh<V, I>)
     where
         V: IntoIterator<Item = Vertex>,
-        I: IntoIterator<Item = u16>,
+        I: IntoIterator,
+        I::Item: Into<u32>,
     {
         profiling::scope!("PaintDom::add_mesh");
 
@@ -315,10 +776,15 @@ impl PaintDom {
             .expect("an active layer is required to call add_mesh");
 
         let call = match layer.calls.last_mut() {
-            Some((clip, PaintCall::Internal(call)))
-                if call.texture == texture_id
-                    && call.pipeline == mesh.pipeline
-                    && *clip == self.current_clip =>
+            Some(PaintLayerCall {
+                clip,
+                mask,
+                call: PaintCall::Internal(call),
+            }) if call.texture == texture_id
+                && call.pipeline == mesh.pipeline
+                && call.uniforms == mesh.uniforms
+                && *clip == self.current_clip
+                && *mask == self.current_mask =>
             {
                 call
             }
@@ -326,12 +792,19 @@ impl PaintDom {
                 let mut call = YakuiPaintCall::new();
                 call.texture = texture_id;
                 call.pipeline = mesh.pipeline;
-
-                layer
-                    .calls
-                    .push((self.current_clip, PaintCall::Internal(call)));
-
-                let Some((_, PaintCall::Internal(inserted))) = layer.calls.last_mut() else {
+                call.uniforms = mesh.uniforms;
+
+                layer.calls.push(PaintLayerCall::new(
+                    self.current_clip,
+                    self.current_mask.clone(),
+                    PaintCall::Internal(call),
+                ));
+
+                let Some(PaintLayerCall {
+                    call: PaintCall::Internal(inserted),
+                    ..
+                }) = layer.calls.last_mut()
+                else {
                     panic!()
                 };
 
@@ -342,7 +815,7 @@ impl PaintDom {
         let indices = mesh
             .indices
             .into_iter()
-            .map(|index| index + call.vertices.len() as u16);
+            .map(|index| index.into() + call.vertices.len() as u32);
         call.indices.extend(indices);
 
         let vertices = mesh.vertices.into_iter().map(|mut vertex| {
@@ -353,11 +826,24 @@ impl PaintDom {
             // See: https://github.com/SecondHalfGames/yakui/issues/153
             let round = mesh.pipeline == Pipeline::Text;
 
+            if let Some(transform) = self.current_transform {
+                vertex.position = transform.transform_point2(vertex.position);
+            }
+
             vertex.position = self.info.transform_vertex(vertex.position, round);
+            vertex.color.w *= self.current_opacity;
 
             vertex
         });
+
+        let start = call.vertices.len();
         call.vertices.extend(vertices);
+
+        if let (Some(stats), Some(type_name)) = (&mut self.widget_stats, self.current_widget) {
+            if let Some(stats) = stats.get_mut(type_name) {
+                stats.vertices += call.vertices.len() - start;
+            }
+        }
     }
 
     /// Adds a user-managed paint call to be painted.
@@ -370,12 +856,62 @@ impl PaintDom {
             .current_mut()
             .expect("an active layer is required to call add_user_call");
 
-        layer
-            .calls
-            .push((self.current_clip, PaintCall::User(call_id)));
+        layer.calls.push(PaintLayerCall::new(
+            self.current_clip,
+            self.current_mask.clone(),
+            PaintCall::User(call_id),
+        ));
     }
 }
 
+/// Returns the smallest axis-aligned rectangle containing `rect` after it has
+/// been transformed by `transform`.
+fn transformed_bounds(transform: Affine2, rect: Rect) -> Rect {
+    let corners = [
+        rect.pos(),
+        Vec2::new(rect.max().x, rect.pos().y),
+        Vec2::new(rect.pos().x, rect.max().y),
+        rect.max(),
+    ]
+    .map(|corner| transform.transform_point2(corner));
+
+    let min = corners.iter().copied().fold(Vec2::INFINITY, Vec2::min);
+    let max = corners.iter().copied().fold(Vec2::NEG_INFINITY, Vec2::max);
+    Rect::from_pos_size(min, max - min)
+}
+
+/// Moves a paint call from the surface into a render target covering the
+/// given part of it, so that the target's texture only needs to be as large as
+/// that part.
+fn into_target_space(call: PaintLayerCall, rect: Rect, surface_size: Vec2) -> PaintLayerCall {
+    let offset = Affine2::from_translation(-rect.pos());
+    let clip = Rect::from_pos_size(call.clip.pos() - rect.pos(), call.clip.size())
+        .constrain(Rect::from_pos_size(Vec2::ZERO, rect.size()));
+    let mask = call.mask.map(|mask| {
+        let shapes: Vec<_> = mask
+            .shapes()
+            .iter()
+            .map(|shape| shape.transformed(offset))
+            .collect();
+
+        ClipMask::new(&shapes)
+    });
+
+    let call = match call.call {
+        PaintCall::Internal(mut internal) => {
+            for vertex in &mut internal.vertices {
+                let position = vertex.position * surface_size - rect.pos();
+                vertex.position = position / rect.size();
+            }
+
+            PaintCall::Internal(internal)
+        }
+        user => user,
+    };
+
+    PaintLayerCall::new(clip, mask, call)
+}
+
 fn transform_vertex(
     mut pos: Vec2,
     scale_factor: f32,
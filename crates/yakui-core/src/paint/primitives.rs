@@ -7,15 +7,24 @@ use crate::id::TextureId;
 pub struct PaintMesh<V, I> {
     /// Vertex positions, the unit here is in logical pixels.
     pub vertices: V,
+
+    /// Indices into `vertices`. Any unsigned index type up to `u32` can be
+    /// used, so meshes with more than 65,535 vertices need `u32` indices.
     pub indices: I,
     pub texture: Option<(TextureId, Rect)>,
     pub pipeline: Pipeline,
+
+    /// Data passed to the shader of a [`Pipeline::Custom`] pipeline when
+    /// drawing this mesh. Meshes are only batched together with other meshes
+    /// that have the same uniforms.
+    pub uniforms: Uniforms,
 }
 
 impl<V, I> PaintMesh<V, I>
 where
     V: IntoIterator<Item = Vertex>,
-    I: IntoIterator<Item = u16>,
+    I: IntoIterator,
+    I::Item: Into<u32>,
 {
     /// Create a new `PaintMesh` with the default pipeline, no texture, and the
     /// given vertices and indices.
@@ -25,6 +34,7 @@ where
             indices,
             texture: None,
             pipeline: Pipeline::Main,
+            uniforms: Uniforms::ZERO,
         }
     }
 }
@@ -33,9 +43,10 @@ where
 #[allow(missing_docs)]
 pub struct YakuiPaintCall {
     pub vertices: Vec<Vertex>,
-    pub indices: Vec<u16>,
+    pub indices: Vec<u32>,
     pub texture: Option<TextureId>,
     pub pipeline: Pipeline,
+    pub uniforms: Uniforms,
 }
 
 impl YakuiPaintCall {
@@ -46,6 +57,7 @@ impl YakuiPaintCall {
             indices: Vec::new(),
             texture: None,
             pipeline: Pipeline::Main,
+            uniforms: Uniforms::ZERO,
         }
     }
 }
@@ -98,4 +110,44 @@ pub enum Pipeline {
 
     /// Pipeline for drawing text: vertices and a coverage glyph texture.
     Text,
+
+    /// A pipeline with a shader provided by the application, which has been
+    /// registered with the renderer. It's given the same vertices and texture
+    /// as the main pipeline, as well as the [`Uniforms`] of the paint call.
+    Custom(CustomPipelineId),
+}
+
+/// An ID for a custom pipeline, given out by the renderer when the pipeline is
+/// registered with it.
+pub type CustomPipelineId = u32;
+
+/// A small amount of data passed to the shader of a custom pipeline, like the
+/// progress of a dissolve effect or the fill of a health bar.
+///
+/// Shaders see the uniforms as an array of four `vec4`s. The built-in pipelines
+/// ignore them.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[repr(C)]
+pub struct Uniforms(pub [Vec4; 4]);
+
+impl Uniforms {
+    /// Uniforms that are all zero, used by meshes that don't set any.
+    pub const ZERO: Self = Self([Vec4::ZERO; 4]);
+
+    /// The uniforms as plain floats, in the order that shaders see them.
+    pub fn to_array(&self) -> [[f32; 4]; 4] {
+        self.0.map(|value| value.to_array())
+    }
+}
+
+impl From<Vec4> for Uniforms {
+    fn from(value: Vec4) -> Self {
+        Self([value, Vec4::ZERO, Vec4::ZERO, Vec4::ZERO])
+    }
+}
+
+impl From<[Vec4; 4]> for Uniforms {
+    fn from(value: [Vec4; 4]) -> Self {
+        Self(value)
+    }
 }
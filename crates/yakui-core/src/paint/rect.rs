@@ -3,7 +3,7 @@ use glam::Vec2;
 use crate::geometry::{Color, Rect};
 use crate::TextureId;
 
-use super::{PaintDom, PaintMesh, Pipeline, Vertex};
+use super::{PaintDom, PaintMesh, Pipeline, Uniforms, Vertex};
 
 #[rustfmt::skip]
 const RECT_POS: [[f32; 2]; 4] = [
@@ -25,6 +25,7 @@ pub struct PaintRect {
     pub color: Color,
     pub texture: Option<(TextureId, Rect)>,
     pub pipeline: Pipeline,
+    pub uniforms: Uniforms,
 }
 
 impl PaintRect {
@@ -40,6 +41,7 @@ impl PaintRect {
             color: Color::WHITE,
             texture: None,
             pipeline: Pipeline::Main,
+            uniforms: Uniforms::ZERO,
         }
     }
 
@@ -64,6 +66,7 @@ impl PaintRect {
         let mut mesh = PaintMesh::new(vertices, RECT_INDEX);
         mesh.texture = self.texture;
         mesh.pipeline = self.pipeline;
+        mesh.uniforms = self.uniforms;
 
         output.add_mesh(mesh);
     }
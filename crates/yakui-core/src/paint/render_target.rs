@@ -0,0 +1,29 @@
+use crate::geometry::Rect;
+use crate::id::WidgetId;
+use crate::paint::PaintLayerCall;
+
+/// Part of the UI that is painted into an offscreen texture, which is then
+/// drawn by a paint call using [`TextureId::RenderTarget`][crate::TextureId::RenderTarget].
+///
+/// Render targets are only used if the renderer sets
+/// [`PaintLimits::render_targets`][super::PaintLimits::render_targets]. The
+/// texture of a target is as large as its [`rect`][RenderTarget::rect], and
+/// its paint calls are positioned and clipped relative to that texture the
+/// same way the paint calls in [`PaintDom::layers`][super::PaintDom::layers]
+/// are relative to the surface, so the same code can draw both. Before
+/// drawing, the texture should be cleared to transparent.
+///
+/// Targets that aren't painted in a frame can be dropped by the renderer.
+#[derive(Debug)]
+pub struct RenderTarget {
+    /// The widget that owns the target, which stays the same across frames.
+    pub id: WidgetId,
+
+    /// The area of the surface that the target is drawn to, in physical
+    /// pixels. Its size is the size of the target's texture.
+    pub rect: Rect,
+
+    /// The paint calls to draw into the target, or `None` if the texture from
+    /// the previous frame should be drawn again as it is.
+    pub calls: Option<Vec<PaintLayerCall>>,
+}
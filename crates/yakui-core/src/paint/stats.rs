@@ -0,0 +1,161 @@
+use crate::geometry::Rect;
+use crate::id::TextureId;
+use crate::paint::{ClipMask, PaintCall, PaintLayer, PaintLayerCall, Pipeline, RenderTarget};
+
+/// Statistics about the paint calls that make up a frame, returned by
+/// [`PaintDom::stats`][super::PaintDom::stats].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PaintStats {
+    /// Statistics for every paint call in the frame, including the ones that
+    /// paint render targets. Each render target is painted in a pass of its
+    /// own, so state changes are counted separately for each target.
+    pub total: LayerPaintStats,
+
+    /// Statistics for each paint layer, in the same order as
+    /// [`PaintDom::layers`][super::PaintDom::layers].
+    pub layers: Vec<LayerPaintStats>,
+
+    /// Statistics for each render target, in the same order as
+    /// [`PaintDom::render_targets`][super::PaintDom::render_targets]. Targets
+    /// that reuse their texture from the previous frame have no calls.
+    pub render_targets: Vec<LayerPaintStats>,
+
+    /// How many vertices each widget type emitted, from the most to the
+    /// fewest. Widgets only count the vertices they add themselves, not the
+    /// ones added by their children.
+    ///
+    /// This is only collected while enabled with
+    /// [`PaintDom::set_widget_stats`][super::PaintDom::set_widget_stats].
+    pub widgets: Vec<WidgetPaintStats>,
+}
+
+/// Statistics about a sequence of paint calls.
+///
+/// Texture, pipeline and clip changes count every call that needs different
+/// state bound than the call before it, including the first call.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LayerPaintStats {
+    /// The number of draw calls, including user-managed ones.
+    pub calls: usize,
+
+    /// The number of user-managed paint calls.
+    pub user_calls: usize,
+
+    /// The number of vertices across all yakui paint calls.
+    pub vertices: usize,
+
+    /// The number of indices across all yakui paint calls.
+    pub indices: usize,
+
+    /// How many times the bound texture changes.
+    pub texture_changes: usize,
+
+    /// How many times the pipeline changes.
+    pub pipeline_changes: usize,
+
+    /// How many times the clip rect or the clip mask changes.
+    pub clip_changes: usize,
+}
+
+/// The number of vertices emitted by one type of widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WidgetPaintStats {
+    /// The type name of the widget, as given by
+    /// [`ErasedWidget::type_name`][crate::widget::ErasedWidget::type_name].
+    pub type_name: &'static str,
+
+    /// How many instances of the widget were painted.
+    pub instances: usize,
+
+    /// How many vertices were added by all instances of the widget.
+    pub vertices: usize,
+}
+
+impl LayerPaintStats {
+    pub(crate) fn from_calls<'a, I>(calls: I) -> Self
+    where
+        I: IntoIterator<Item = &'a PaintLayerCall>,
+    {
+        let mut stats = Self::default();
+        let mut texture: Option<Option<TextureId>> = None;
+        let mut pipeline: Option<Pipeline> = None;
+        let mut clip: Option<(Rect, Option<&ClipMask>)> = None;
+
+        for call in calls {
+            stats.calls += 1;
+
+            let call_clip = (call.clip(), call.mask());
+            if clip != Some(call_clip) {
+                stats.clip_changes += 1;
+                clip = Some(call_clip);
+            }
+
+            match call.call() {
+                PaintCall::Internal(call) => {
+                    stats.vertices += call.vertices.len();
+                    stats.indices += call.indices.len();
+
+                    if texture != Some(call.texture) {
+                        stats.texture_changes += 1;
+                        texture = Some(call.texture);
+                    }
+
+                    if pipeline != Some(call.pipeline) {
+                        stats.pipeline_changes += 1;
+                        pipeline = Some(call.pipeline);
+                    }
+                }
+
+                // User calls can bind anything, so the next yakui call has to
+                // bind its state again.
+                PaintCall::User(_) => {
+                    stats.user_calls += 1;
+                    texture = None;
+                    pipeline = None;
+                }
+            }
+        }
+
+        stats
+    }
+
+    fn add(&mut self, other: &Self) {
+        self.calls += other.calls;
+        self.user_calls += other.user_calls;
+        self.vertices += other.vertices;
+        self.indices += other.indices;
+        self.texture_changes += other.texture_changes;
+        self.pipeline_changes += other.pipeline_changes;
+        self.clip_changes += other.clip_changes;
+    }
+}
+
+impl PaintStats {
+    pub(crate) fn new(
+        layers: &[PaintLayer],
+        render_targets: &[RenderTarget],
+        widgets: Vec<WidgetPaintStats>,
+    ) -> Self {
+        let mut total = LayerPaintStats::from_calls(layers.iter().flat_map(|layer| &layer.calls));
+        let layers = layers
+            .iter()
+            .map(|layer| LayerPaintStats::from_calls(&layer.calls))
+            .collect();
+
+        let render_targets: Vec<_> = render_targets
+            .iter()
+            .map(|target| LayerPaintStats::from_calls(target.calls.iter().flatten()))
+            .collect();
+
+        for target in &render_targets {
+            total.add(target);
+        }
+
+        Self {
+            total,
+            layers,
+            render_targets,
+            widgets,
+        }
+    }
+}
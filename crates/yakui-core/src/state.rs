@@ -86,6 +86,15 @@ impl Yakui {
         self.paint.set_unscaled_viewport(view);
     }
 
+    /// Tells yakui how much time passed since the previous frame, in seconds.
+    /// Should be called before [`Yakui::start`] every frame.
+    ///
+    /// Widgets that animate, like scrollables with smooth or kinetic scrolling
+    /// enabled, use this to animate at the same speed at any frame rate.
+    pub fn set_delta_time(&mut self, delta_time: f32) {
+        self.dom.set_delta_time(delta_time.max(0.0));
+    }
+
     /// Retrieve the scale factor currently used by yakui.
     pub fn scale_factor(&self) -> f32 {
         self.layout.scale_factor()
@@ -131,7 +140,7 @@ impl Yakui {
 
     /// Calculates the geometry needed to render the current state and gives
     /// access to the [`PaintDom`], which holds information about how to paint
-    /// widgets.
+    /// widgets. [`PaintDom::stats`] reports what the frame ended up costing.
     pub fn paint(&mut self) -> &mut PaintDom {
         self.paint.paint_all(&self.dom, &self.layout);
         &mut self.paint
@@ -157,6 +166,28 @@ impl Yakui {
         self.paint.set_limit(limits)
     }
 
+    /// Forgets the render targets painted in the previous frame that the
+    /// renderer doesn't have a texture for anymore. See
+    /// [`PaintDom::retain_render_targets`].
+    pub fn retain_render_targets<F>(&mut self, keep: F)
+    where
+        F: FnMut(WidgetId) -> bool,
+    {
+        self.paint.retain_render_targets(keep)
+    }
+
+    /// Sets whether striped bands are painted along the edges of widgets that
+    /// overflowed during layout. This only has an effect in debug builds.
+    pub fn set_show_overflow(&mut self, show: bool) {
+        self.paint.set_show_overflow(show)
+    }
+
+    /// Sets whether the vertices added by each type of widget are counted
+    /// while painting. See [`PaintDom::stats`].
+    pub fn set_widget_stats(&mut self, enabled: bool) {
+        self.paint.set_widget_stats(enabled)
+    }
+
     /// Tells whether a widget is currently looking for text input, like a
     /// focused textbox.
     pub fn text_input_enabled(&self) -> bool {
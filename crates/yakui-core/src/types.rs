@@ -121,6 +121,13 @@ pub enum CrossAxisAlignment {
 
     /// Stretch items to fill the maximum size of the container's cross axis.
     Stretch,
+
+    /// Align items so that the baselines of their first lines of text line up.
+    ///
+    /// Only applies when the cross axis is vertical, like in a row. Items
+    /// without a baseline, and items in containers with a horizontal cross
+    /// axis, are aligned to the start of the cross axis.
+    Baseline,
 }
 
 /// Defines the direction that a container will lay out its children.
@@ -177,6 +184,81 @@ impl Direction {
     }
 }
 
+/// Defines which intrinsic size of a widget is being queried.
+///
+/// Intrinsic sizes describe how big a widget would like to be along one axis,
+/// given a fixed extent along the other axis. They can be queried any number
+/// of times during layout, unlike
+/// [`LayoutContext::calculate_layout`][crate::widget::LayoutContext::calculate_layout].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntrinsicSize {
+    /// The smallest width the widget can be given without its contents
+    /// overflowing, for a given height.
+    MinWidth,
+
+    /// The smallest width beyond which growing wider would not reduce the
+    /// widget's height, for a given height.
+    MaxWidth,
+
+    /// The smallest height the widget can be given without its contents
+    /// overflowing, for a given width.
+    MinHeight,
+
+    /// The smallest height beyond which growing taller would not reduce the
+    /// widget's width, for a given width.
+    MaxHeight,
+}
+
+impl IntrinsicSize {
+    /// Constructs a minimum intrinsic size query along the main axis of the
+    /// given direction.
+    pub fn min(direction: Direction) -> Self {
+        match direction {
+            Direction::Down => Self::MinHeight,
+            Direction::Right => Self::MinWidth,
+        }
+    }
+
+    /// Constructs a maximum intrinsic size query along the main axis of the
+    /// given direction.
+    pub fn max(direction: Direction) -> Self {
+        match direction {
+            Direction::Down => Self::MaxHeight,
+            Direction::Right => Self::MaxWidth,
+        }
+    }
+
+    /// Tells whether this query measures a width.
+    pub fn is_width(&self) -> bool {
+        matches!(self, Self::MinWidth | Self::MaxWidth)
+    }
+
+    /// Tells whether this query measures a minimum size.
+    pub fn is_min(&self) -> bool {
+        matches!(self, Self::MinWidth | Self::MinHeight)
+    }
+
+    /// Returns the direction whose main axis is measured by this query.
+    pub fn direction(&self) -> Direction {
+        if self.is_width() {
+            Direction::Right
+        } else {
+            Direction::Down
+        }
+    }
+
+    /// Returns the value measured by this query from a [`Vec2`].
+    pub fn get(&self, vec: Vec2) -> f32 {
+        self.direction().get_main_axis(vec)
+    }
+
+    /// Returns the value of the axis opposite to the one measured by this
+    /// query from a [`Vec2`].
+    pub fn get_extent(&self, vec: Vec2) -> f32 {
+        self.direction().get_cross_axis(vec)
+    }
+}
+
 /// Defines alignment within a container.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Alignment {
@@ -197,6 +279,16 @@ impl Alignment {
     pub const fn as_vec2(&self) -> Vec2 {
         Vec2::new(self.x, self.y)
     }
+
+    /// Returns this alignment with left and right swapped if `direction` is
+    /// right-to-left, so that aligning to the left aligns to the start of the
+    /// layout direction.
+    pub fn resolve(self, direction: LayoutDirection) -> Self {
+        match direction {
+            LayoutDirection::LeftToRight => self,
+            LayoutDirection::RightToLeft => Self::new(1.0 - self.x, self.y),
+        }
+    }
 }
 
 #[allow(missing_docs)]
@@ -234,6 +326,15 @@ impl Pivot {
     pub const fn as_vec2(&self) -> Vec2 {
         Vec2::new(self.x, self.y)
     }
+
+    /// Returns this pivot with left and right swapped if `direction` is
+    /// right-to-left.
+    pub fn resolve(self, direction: LayoutDirection) -> Self {
+        match direction {
+            LayoutDirection::LeftToRight => self,
+            LayoutDirection::RightToLeft => Self::new(1.0 - self.x, self.y),
+        }
+    }
 }
 
 #[allow(missing_docs)]
@@ -250,3 +351,26 @@ impl Pivot {
     pub const BOTTOM_CENTER: Self = Self::new(0.5, 1.0);
     pub const BOTTOM_RIGHT: Self = Self::new(1.0, 1.0);
 }
+
+/// Defines whether content flows from left to right, like English, or from
+/// right to left, like Arabic and Hebrew.
+///
+/// Widgets read the layout direction from the dynamic scope they were created
+/// in. Horizontal lists, alignments, and padding are mirrored when laying out
+/// right to left.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LayoutDirection {
+    /// Content starts on the left side and flows to the right.
+    #[default]
+    LeftToRight,
+
+    /// Content starts on the right side and flows to the left.
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    /// Tells whether this direction is right-to-left.
+    pub fn is_rtl(self) -> bool {
+        self == Self::RightToLeft
+    }
+}
@@ -14,7 +14,7 @@ use crate::input::InputState;
 use crate::layout::LayoutDom;
 use crate::navigation::NavDirection;
 use crate::paint::PaintDom;
-use crate::{Flow, WidgetId};
+use crate::{Flow, IntrinsicSize, WidgetId};
 
 /// Trait that's automatically implemented for all widget props.
 ///
@@ -40,6 +40,44 @@ impl LayoutContext<'_> {
         self.layout
             .calculate(self.dom, self.input, self.paint, widget, constraints)
     }
+
+    /// Query the intrinsic size of the given widget along one axis, given an
+    /// extent along the other axis.
+    ///
+    /// Unlike [`calculate_layout`][Self::calculate_layout], this method can be
+    /// called any number of times per widget per layout phase.
+    pub fn intrinsic_size(&self, widget: WidgetId, kind: IntrinsicSize, extent: f32) -> f32 {
+        let ctx = IntrinsicContext {
+            dom: self.dom,
+            layout: self.layout,
+            paint: self.paint,
+        };
+
+        ctx.intrinsic_size(widget, kind, extent)
+    }
+}
+
+/// Information available to a widget when it is being queried for its
+/// intrinsic size.
+#[allow(missing_docs)]
+#[derive(Clone, Copy)]
+pub struct IntrinsicContext<'dom> {
+    pub dom: &'dom Dom,
+    pub layout: &'dom LayoutDom,
+    pub paint: &'dom PaintDom,
+}
+
+impl IntrinsicContext<'_> {
+    /// Query the intrinsic size of the given widget along one axis, given an
+    /// extent along the other axis.
+    pub fn intrinsic_size(&self, widget: WidgetId, kind: IntrinsicSize, extent: f32) -> f32 {
+        self.dom.enter(widget);
+        let node = self.dom.get(widget).unwrap();
+        let size = node.widget.intrinsic_size(*self, kind, extent);
+        self.dom.exit(widget);
+
+        size
+    }
 }
 
 /// Information available to a widget during the paint phase.
@@ -156,7 +194,11 @@ pub trait Widget: 'static + fmt::Debug {
     /// be done using `constraints.constrain(size)`.
     ///
     /// The default implementation will lay out all of this widget's children on
-    /// top of each other, and fit the widget tightly around them.
+    /// top of each other, and fit the widget tightly around them. The widget's
+    /// baseline is taken from its first child that has one.
+    ///
+    /// Widgets that contain text can report where its baseline is with
+    /// [`LayoutDom::set_baseline`][crate::layout::LayoutDom::set_baseline].
     fn layout(&self, ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
         self.default_layout(ctx, constraints)
     }
@@ -173,9 +215,42 @@ pub trait Widget: 'static + fmt::Debug {
             size = size.max(child_size);
         }
 
+        ctx.layout.propagate_baseline(ctx.dom, &node.children);
         constraints.constrain_min(size)
     }
 
+    /// Returns the intrinsic size of this widget along one axis, given an
+    /// extent along the other axis. See [`IntrinsicSize`] for the meaning of
+    /// each query.
+    ///
+    /// `extent` may be infinite, in which case the widget should assume it is
+    /// unconstrained along that axis.
+    ///
+    /// The default implementation returns the largest intrinsic size of this
+    /// widget's children, matching the default layout strategy.
+    fn intrinsic_size(&self, ctx: IntrinsicContext<'_>, kind: IntrinsicSize, extent: f32) -> f32 {
+        self.default_intrinsic_size(ctx, kind, extent)
+    }
+
+    /// A convenience method that always performs the default intrinsic size
+    /// query for a widget. This method is intended to be called from custom
+    /// widget's `intrinsic_size` methods.
+    #[inline]
+    fn default_intrinsic_size(
+        &self,
+        ctx: IntrinsicContext<'_>,
+        kind: IntrinsicSize,
+        extent: f32,
+    ) -> f32 {
+        let node = ctx.dom.get_current();
+        let mut size: f32 = 0.0;
+        for &child in &node.children {
+            size = size.max(ctx.intrinsic_size(child, kind, extent));
+        }
+
+        size
+    }
+
     /// Paint the widget based on its current state.
     ///
     /// The default implementation will paint all of the widget's children.
@@ -307,6 +382,9 @@ pub trait ErasedWidget: Any + fmt::Debug {
     /// See [`Widget::flow`].
     fn flow(&self) -> Flow;
 
+    /// See [`Widget::intrinsic_size`].
+    fn intrinsic_size(&self, ctx: IntrinsicContext<'_>, kind: IntrinsicSize, extent: f32) -> f32;
+
     /// See [`Widget::paint`].
     fn paint(&self, ctx: PaintContext<'_>);
 
@@ -339,6 +417,10 @@ where
         <T as Widget>::flow(self)
     }
 
+    fn intrinsic_size(&self, ctx: IntrinsicContext<'_>, kind: IntrinsicSize, extent: f32) -> f32 {
+        <T as Widget>::intrinsic_size(self, ctx, kind, extent)
+    }
+
     fn paint(&self, ctx: PaintContext<'_>) {
         <T as Widget>::paint(self, ctx)
     }
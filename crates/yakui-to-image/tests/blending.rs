@@ -0,0 +1,44 @@
+use yakui_core::geometry::{Color, Rect, UVec2, Vec2};
+use yakui_core::paint::{Texture, TextureFormat};
+use yakui_core::Yakui;
+use yakui_widgets::{colored_box, colored_box_container, image, row};
+
+/// Translucent colors and straight alpha textures should have their alpha
+/// applied once, landing halfway between black and the white background in
+/// linear space.
+#[test]
+fn translucent_black_over_white() {
+    let mut yak = Yakui::new();
+    let size = Vec2::new(100.0, 50.0);
+    yak.set_surface_size(size);
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, size));
+
+    let texture = yak.add_texture(Texture::new(
+        TextureFormat::Rgba8Srgb,
+        UVec2::new(1, 1),
+        vec![0, 0, 0, 128],
+    ));
+
+    yak.start();
+    colored_box_container(Color::WHITE, || {
+        row(|| {
+            colored_box(Color::BLACK.with_alpha(0.5), [50.0, 50.0]);
+            image(texture, [50.0, 50.0]);
+        });
+    });
+    yak.finish();
+
+    let output = yakui_to_image::paint(&mut yak);
+
+    for (x, y) in [(25, 25), (75, 25)] {
+        let [r, g, b, a] = output.get_pixel(x, y).0;
+        for channel in [r, g, b] {
+            assert!(
+                channel.abs_diff(188) <= 2,
+                "pixel at ({x}, {y}) was {:?}",
+                [r, g, b, a]
+            );
+        }
+        assert_eq!(a, 255);
+    }
+}
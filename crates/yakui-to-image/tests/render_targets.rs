@@ -0,0 +1,53 @@
+use yakui_core::geometry::{Color, Rect, Vec2};
+use yakui_core::Yakui;
+use yakui_widgets::widgets::{Opacity, Pad};
+use yakui_widgets::{align, colored_box, row};
+
+/// Offscreen opacity paints into a texture covering only the widget, which
+/// has to land back in the same spot on the surface.
+#[test]
+fn offscreen_opacity_in_place() {
+    let mut yak = Yakui::new();
+    let size = Vec2::new(100.0, 100.0);
+    yak.set_surface_size(size);
+    yak.set_unscaled_viewport(Rect::from_pos_size(Vec2::ZERO, size));
+
+    yak.start();
+    align(yakui_core::Alignment::TOP_LEFT, || {
+        Pad::all(20.0).show(|| {
+            Opacity::new(0.5).offscreen(true).show(|| {
+                row(|| {
+                    colored_box(Color::RED, [20.0, 40.0]);
+                    colored_box(Color::BLUE, [20.0, 40.0]);
+                });
+            });
+        });
+    });
+    yak.finish();
+
+    let output = yakui_to_image::paint(&mut yak);
+
+    let red = [188, 0, 0, 255];
+    let blue = [0, 0, 188, 255];
+    let black = [0, 0, 0, 255];
+    let expected = [
+        ((21, 21), red),
+        ((38, 58), red),
+        ((42, 21), blue),
+        ((58, 58), blue),
+        ((18, 18), black),
+        ((62, 40), black),
+        ((40, 62), black),
+    ];
+
+    for ((x, y), expected) in expected {
+        let pixel = output.get_pixel(x, y).0;
+        assert!(
+            pixel
+                .iter()
+                .zip(expected)
+                .all(|(&channel, expected)| channel.abs_diff(expected) <= 2),
+            "pixel at ({x}, {y}) was {pixel:?}, expected {expected:?}"
+        );
+    }
+}
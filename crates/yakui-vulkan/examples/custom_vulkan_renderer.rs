@@ -125,13 +125,16 @@ unsafe fn my_custom_paint(
         .duration_since(renderer.initial_time)
         .as_secs_f32();
 
-    for (clip, call) in layers.iter().flat_map(|layer| &layer.calls) {
-        match call {
+    // This example doesn't use clip masks, so they're ignored. See
+    // `yakui_vulkan::paint` for how to paint them.
+    for call in layers.iter().flat_map(|layer| &layer.calls) {
+        let clip = call.clip();
+        match call.call() {
             PaintCall::Internal(call) => {
                 draw_calls.push(yakui_vulkan.build_draw_call(
                     &mut vertices,
                     &mut indices,
-                    *clip,
+                    clip,
                     call,
                 ));
             }
@@ -169,7 +172,7 @@ unsafe fn my_custom_paint(
                     });
                 }
 
-                draw_calls.push((*clip, DrawCall::User(*id)));
+                draw_calls.push((clip, DrawCall::User(*id)));
                 custom_draws.insert_at(index, (index_offset, index_count));
             }
         }
@@ -3,6 +3,7 @@
 
 mod buffer;
 mod descriptors;
+mod stats;
 mod util;
 mod vulkan_context;
 mod vulkan_texture;
@@ -10,14 +11,18 @@ mod vulkan_texture;
 use ash::util::read_spv;
 use buffer::Buffer;
 use bytemuck::{bytes_of, Pod, Zeroable};
+use std::ops::Range;
 use std::{collections::HashMap, io::Cursor};
 use vulkan_texture::UploadQueue;
-use yakui_core::geometry::{Rect, UVec2};
-use yakui_core::paint::{PaintCall, PaintLimits, Vertex as YakuiVertex};
+use yakui_core::geometry::{Rect, UVec2, Vec2, Vec4};
+use yakui_core::paint::{
+    ClipMask, CustomPipelineId, PaintCall, PaintLimits, Pipeline, Uniforms, Vertex as YakuiVertex,
+};
 use yakui_core::ManagedTextureId;
 
 pub use ash::vk;
 pub use descriptors::Descriptors;
+pub use stats::RenderStats;
 pub use vulkan_context::VulkanContext;
 pub use vulkan_texture::NO_TEXTURE_ID;
 pub use vulkan_texture::{VulkanTexture, VulkanTextureCreateInfo};
@@ -36,6 +41,12 @@ pub struct YakuiVulkan {
     pub pipeline_layout: vk::PipelineLayout,
     /// The graphics pipeline used to draw
     pub graphics_pipeline: vk::Pipeline,
+    /// Pipelines registered with [`YakuiVulkan::add_pipeline`], indexed by their ID
+    custom_pipelines: Vec<vk::Pipeline>,
+    /// Pipelines used to write clip masks into the stencil buffer, if there is one
+    clip_mask_pipelines: Option<ClipMaskPipelines>,
+    /// The options this instance was created with, which custom pipelines are also created with
+    options: Options,
     /// A single index buffer, shared between all draw calls
     pub index_buffer: Buffer<u32>,
     /// A single vertex buffer, shared between all draw calls
@@ -49,10 +60,12 @@ pub struct YakuiVulkan {
     /// A wrapper around descriptor set functionality
     pub descriptors: Descriptors,
     uploads: UploadQueue,
+    /// Counters for the work done by the last paint and transfer
+    stats: RenderStats,
 }
 
 /// Vulkan configuration
-#[derive(Default)]
+#[derive(Default, Clone, Copy)]
 pub struct Options {
     /// Indicates that VK_KHR_dynamic_rendering is enabled and should be used with the given format
     pub dynamic_rendering_format: Option<vk::Format>,
@@ -60,6 +73,42 @@ pub struct Options {
     pub render_pass: vk::RenderPass,
     /// Subpass that the GUI will be drawn in. Ignored if `dynamic_rendering_format` is set.
     pub subpass: u32,
+    /// The format of the stencil or depth/stencil attachment that the GUI will be drawn with, if
+    /// there is one. Clip masks, like the rounded corners of clipping `RoundRect` widgets, are
+    /// written to the stencil attachment. Without one, paint calls are only clipped to their clip
+    /// rect.
+    ///
+    /// The stencil contents inside of the GUI's clip rects are overwritten while painting.
+    pub stencil_format: Option<vk::Format>,
+}
+
+/// Pipelines sharing the main pipeline's shaders and layout that only write to the stencil buffer.
+struct ClipMaskPipelines {
+    /// Clears the stencil buffer inside of a clip rect.
+    clear: vk::Pipeline,
+    /// Draws a shape of a clip mask, incrementing the stencil value of pixels that were inside of
+    /// every previous shape.
+    mask: vk::Pipeline,
+}
+
+/// The indices used to write a clip mask into the stencil buffer.
+///
+/// The stencil buffer is first cleared inside of the clip rect, then each shape is drawn in order,
+/// incrementing the stencil value of the pixels that were inside of every shape before it.
+/// Afterwards, only the pixels with a value equal to the number of shapes are inside of the mask.
+pub struct ClipMaskDraw {
+    /// Indices of a quad covering the clip rect.
+    pub clear_range: Range<u32>,
+    /// Indices of the triangles of each shape in the mask.
+    pub shape_ranges: Vec<Range<u32>>,
+}
+
+impl ClipMaskDraw {
+    /// The stencil reference value to draw with so that only the pixels inside of the mask are
+    /// painted.
+    pub fn stencil_reference(&self) -> u32 {
+        self.shape_ranges.len() as u32
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -69,6 +118,9 @@ pub struct YakuiDrawCall {
     index_count: u32,
     texture_id: u32,
     workflow: Workflow,
+    /// The custom pipeline to draw with instead of the main pipeline, if any
+    custom_pipeline: Option<vk::Pipeline>,
+    uniforms: Uniforms,
 }
 
 /// A single draw call to either render a yakui mesh, or identify a user issued draw call
@@ -82,10 +134,13 @@ pub enum DrawCall {
 #[repr(C)]
 #[derive(Clone, Copy, Debug)]
 #[allow(missing_docs)]
-/// Push constant used to determine texture and workflow
+/// Push constant used to determine texture and workflow, followed by the uniforms of the paint
+/// call for custom pipelines
 pub struct PushConstant {
     texture_id: u32,
     workflow: Workflow,
+    _padding: [u32; 2],
+    uniforms: [[f32; 4]; 4],
 }
 
 unsafe impl Zeroable for PushConstant {}
@@ -97,8 +152,15 @@ impl PushConstant {
         Self {
             texture_id,
             workflow,
+            _padding: [0; 2],
+            uniforms: Uniforms::ZERO.to_array(),
         }
     }
+
+    pub fn with_uniforms(mut self, uniforms: Uniforms) -> Self {
+        self.uniforms = uniforms.to_array();
+        self
+    }
 }
 
 #[repr(u32)]
@@ -118,6 +180,7 @@ impl From<yakui_core::paint::Pipeline> for Workflow {
         match p {
             yakui_core::paint::Pipeline::Main => Workflow::Main,
             yakui_core::paint::Pipeline::Text => Workflow::Text,
+            yakui_core::paint::Pipeline::Custom(_) => Workflow::Main,
         }
     }
 }
@@ -154,29 +217,6 @@ impl YakuiVulkan {
         let index_buffer = Buffer::new(vulkan_context, vk::BufferUsageFlags::INDEX_BUFFER, &[]);
         let vertex_buffer = Buffer::new(vulkan_context, vk::BufferUsageFlags::VERTEX_BUFFER, &[]);
 
-        let mut vertex_spv_file = Cursor::new(&include_bytes!("../shaders/main.vert.spv")[..]);
-        let mut frag_spv_file = Cursor::new(&include_bytes!("../shaders/main.frag.spv")[..]);
-
-        let vertex_code =
-            read_spv(&mut vertex_spv_file).expect("Failed to read vertex shader spv file");
-        let vertex_shader_info = vk::ShaderModuleCreateInfo::default().code(&vertex_code);
-
-        let frag_code =
-            read_spv(&mut frag_spv_file).expect("Failed to read fragment shader spv file");
-        let frag_shader_info = vk::ShaderModuleCreateInfo::default().code(&frag_code);
-
-        let vertex_shader_module = unsafe {
-            device
-                .create_shader_module(&vertex_shader_info, None)
-                .expect("Vertex shader module error")
-        };
-
-        let fragment_shader_module = unsafe {
-            device
-                .create_shader_module(&frag_shader_info, None)
-                .expect("Fragment shader module error")
-        };
-
         let pipeline_layout = unsafe {
             device
                 .create_pipeline_layout(
@@ -192,166 +232,74 @@ impl YakuiVulkan {
                 .unwrap()
         };
 
-        let shader_entry_name = c"main";
-        let shader_stage_create_infos = [
-            vk::PipelineShaderStageCreateInfo {
-                module: vertex_shader_module,
-                p_name: shader_entry_name.as_ptr(),
-                stage: vk::ShaderStageFlags::VERTEX,
-                ..Default::default()
-            },
-            vk::PipelineShaderStageCreateInfo {
-                s_type: vk::StructureType::PIPELINE_SHADER_STAGE_CREATE_INFO,
-                module: fragment_shader_module,
-                p_name: shader_entry_name.as_ptr(),
-                stage: vk::ShaderStageFlags::FRAGMENT,
-                ..Default::default()
-            },
-        ];
-        let vertex_input_binding_descriptions = [vk::VertexInputBindingDescription {
-            binding: 0,
-            stride: std::mem::size_of::<Vertex>() as u32,
-            input_rate: vk::VertexInputRate::VERTEX,
-        }];
-
-        let vertex_input_attribute_descriptions = [
-            // position
-            vk::VertexInputAttributeDescription {
-                location: 0,
-                binding: 0,
-                format: vk::Format::R32G32_SFLOAT,
-                offset: bytemuck::offset_of!(Vertex, position) as _,
-            },
-            // UV / texcoords
-            vk::VertexInputAttributeDescription {
-                location: 1,
-                binding: 0,
-                format: vk::Format::R32G32_SFLOAT,
-                offset: bytemuck::offset_of!(Vertex, texcoord) as _,
-            },
-            // color
-            vk::VertexInputAttributeDescription {
-                location: 2,
-                binding: 0,
-                format: vk::Format::R32G32B32A32_SFLOAT,
-                offset: bytemuck::offset_of!(Vertex, color) as _,
-            },
-        ];
-
-        let vertex_input_state_info = vk::PipelineVertexInputStateCreateInfo::default()
-            .vertex_attribute_descriptions(&vertex_input_attribute_descriptions)
-            .vertex_binding_descriptions(&vertex_input_binding_descriptions);
-        let vertex_input_assembly_state_info = vk::PipelineInputAssemblyStateCreateInfo {
-            topology: vk::PrimitiveTopology::TRIANGLE_LIST,
-            ..Default::default()
-        };
-        let viewport_state_info = vk::PipelineViewportStateCreateInfo::default()
-            .scissor_count(1)
-            .viewport_count(1);
-
-        let rasterization_info = vk::PipelineRasterizationStateCreateInfo {
-            front_face: vk::FrontFace::COUNTER_CLOCKWISE,
-            line_width: 1.0,
-            polygon_mode: vk::PolygonMode::FILL,
-            ..Default::default()
-        };
-        let multisample_state_info = vk::PipelineMultisampleStateCreateInfo {
-            rasterization_samples: vk::SampleCountFlags::TYPE_1,
-            ..Default::default()
-        };
-        let noop_stencil_state = vk::StencilOpState {
-            fail_op: vk::StencilOp::KEEP,
-            pass_op: vk::StencilOp::KEEP,
-            depth_fail_op: vk::StencilOp::KEEP,
-            compare_op: vk::CompareOp::ALWAYS,
-            ..Default::default()
-        };
-        let depth_state_info = vk::PipelineDepthStencilStateCreateInfo {
-            depth_test_enable: 1,
-            depth_write_enable: 1,
-            depth_compare_op: vk::CompareOp::LESS_OR_EQUAL,
-            front: noop_stencil_state,
-            back: noop_stencil_state,
-            max_depth_bounds: 1.0,
-            ..Default::default()
-        };
-        let color_blend_attachment_states = [vk::PipelineColorBlendAttachmentState {
-            blend_enable: 1,
-            src_color_blend_factor: vk::BlendFactor::SRC_ALPHA,
-            dst_color_blend_factor: vk::BlendFactor::ONE_MINUS_SRC_ALPHA,
-            color_blend_op: vk::BlendOp::ADD,
-            src_alpha_blend_factor: vk::BlendFactor::ONE,
-            dst_alpha_blend_factor: vk::BlendFactor::ZERO,
-            alpha_blend_op: vk::BlendOp::ADD,
-            color_write_mask: vk::ColorComponentFlags::RGBA,
-        }];
-        let color_blend_state = vk::PipelineColorBlendStateCreateInfo::default()
-            .logic_op(vk::LogicOp::CLEAR)
-            .attachments(&color_blend_attachment_states);
-
-        let dynamic_state = [vk::DynamicState::VIEWPORT, vk::DynamicState::SCISSOR];
-        let dynamic_state_info =
-            vk::PipelineDynamicStateCreateInfo::default().dynamic_states(&dynamic_state);
-
-        let mut graphic_pipeline_info = vk::GraphicsPipelineCreateInfo::default()
-            .stages(&shader_stage_create_infos)
-            .vertex_input_state(&vertex_input_state_info)
-            .input_assembly_state(&vertex_input_assembly_state_info)
-            .viewport_state(&viewport_state_info)
-            .rasterization_state(&rasterization_info)
-            .multisample_state(&multisample_state_info)
-            .depth_stencil_state(&depth_state_info)
-            .color_blend_state(&color_blend_state)
-            .dynamic_state(&dynamic_state_info)
-            .layout(pipeline_layout);
-        let rendering_info_formats;
-        let mut rendering_info;
-        assert!(
-            options.dynamic_rendering_format.is_some()
-                || options.render_pass != vk::RenderPass::null(),
-            "either dynamic_rendering_format or render_pass must be set"
-        );
-        if let Some(format) = options.dynamic_rendering_format {
-            rendering_info_formats = [format];
-            rendering_info = vk::PipelineRenderingCreateInfo::default()
-                .color_attachment_formats(&rendering_info_formats);
-            graphic_pipeline_info = graphic_pipeline_info.push_next(&mut rendering_info);
-        } else {
-            graphic_pipeline_info = graphic_pipeline_info
-                .render_pass(options.render_pass)
-                .subpass(options.subpass);
-        }
+        let mut frag_spv_file = Cursor::new(&include_bytes!("../shaders/main.frag.spv")[..]);
+        let frag_code =
+            read_spv(&mut frag_spv_file).expect("Failed to read fragment shader spv file");
 
-        let graphics_pipelines = unsafe {
-            device
-                .create_graphics_pipelines(
-                    vk::PipelineCache::null(),
-                    &[graphic_pipeline_info],
-                    None,
-                )
-                .expect("Unable to create graphics pipeline")
-        };
+        let use_stencil = options.stencil_format.is_some();
+        let graphics_pipelines =
+            create_pipelines(device, pipeline_layout, &options, &frag_code, use_stencil);
 
         let graphics_pipeline = graphics_pipelines[0];
-
-        unsafe {
-            device.destroy_shader_module(vertex_shader_module, None);
-            device.destroy_shader_module(fragment_shader_module, None);
-        }
+        let clip_mask_pipelines = use_stencil.then(|| ClipMaskPipelines {
+            clear: graphics_pipelines[1],
+            mask: graphics_pipelines[2],
+        });
 
         Self {
             descriptors,
             pipeline_layout,
             graphics_pipeline,
+            custom_pipelines: Vec::new(),
+            clip_mask_pipelines,
+            options,
             index_buffer,
             vertex_buffer,
             user_textures: Default::default(),
             yakui_managed_textures: Default::default(),
             initial_textures_synced: false,
             uploads: UploadQueue::new(),
+            stats: RenderStats::default(),
         }
     }
 
+    /// Registers a pipeline that draws with the given fragment shader, returning the [`Pipeline`]
+    /// that meshes can be painted with to use it.
+    ///
+    /// The pipeline uses the same vertex shader, descriptor set and blending as the main
+    /// pipeline. The uniforms of each paint call are passed in the push constants after the
+    /// texture ID, so the fragment shader should declare them like this:
+    ///
+    /// ```glsl
+    /// layout(set = 0, binding = 0) uniform sampler2D textures[1000];
+    /// layout(push_constant) uniform push_constants {
+    ///     uint texture_id;
+    ///     uint workflow;
+    ///     vec4 uniforms[4];
+    /// };
+    /// ```
+    ///
+    /// ## Safety
+    /// - `vulkan_context` must be the same as the one used to create this instance
+    /// - `fragment_code` must be valid SPIR-V for a fragment shader with that interface
+    pub unsafe fn add_pipeline(
+        &mut self,
+        vulkan_context: &VulkanContext,
+        fragment_code: &[u32],
+    ) -> Pipeline {
+        let pipelines = create_pipelines(
+            vulkan_context.device,
+            self.pipeline_layout,
+            &self.options,
+            fragment_code,
+            false,
+        );
+
+        let id = self.custom_pipelines.len() as CustomPipelineId;
+        self.custom_pipelines.push(pipelines[0]);
+        Pipeline::Custom(id)
+    }
+
     /// Record transfer commands that must complete before painting this `paint`
     ///
     /// ## Safety
@@ -362,10 +310,17 @@ impl YakuiVulkan {
         vulkan_context: &VulkanContext,
         cmd: vk::CommandBuffer,
     ) {
+        self.stats.texture_uploads = 0;
         self.update_textures(vulkan_context, paint);
         self.uploads.record(vulkan_context, cmd);
     }
 
+    /// Returns counters for the work done by the last calls to [`YakuiVulkan::paint`] and
+    /// [`YakuiVulkan::transfer`].
+    pub fn stats(&self) -> RenderStats {
+        self.stats
+    }
+
     /// Call when commands recorded by zero or more successive `transfer` calls have been submitted to
     /// a queue
     ///
@@ -391,6 +346,7 @@ impl YakuiVulkan {
             max_texture_size_1d: vulkan_context.properties.limits.max_image_dimension1_d,
             max_texture_size_2d: vulkan_context.properties.limits.max_image_dimension2_d,
             max_texture_size_3d: vulkan_context.properties.limits.max_image_dimension3_d,
+            render_targets: false,
         });
     }
 
@@ -453,6 +409,13 @@ impl YakuiVulkan {
         }
         device.destroy_pipeline_layout(self.pipeline_layout, None);
         device.destroy_pipeline(self.graphics_pipeline, None);
+        for pipeline in self.custom_pipelines.drain(..) {
+            device.destroy_pipeline(pipeline, None);
+        }
+        if let Some(pipelines) = self.clip_mask_pipelines.take() {
+            device.destroy_pipeline(pipelines.clear, None);
+            device.destroy_pipeline(pipelines.mask, None);
+        }
         self.index_buffer.cleanup(device);
         self.vertex_buffer.cleanup(device);
         self.uploads.cleanup(device);
@@ -481,6 +444,7 @@ impl YakuiVulkan {
                     texture,
                     &mut self.uploads,
                 );
+                self.stats.texture_uploads += 1;
                 self.yakui_managed_textures.insert(id, texture);
             }
 
@@ -499,6 +463,7 @@ impl YakuiVulkan {
                         texture,
                         &mut self.uploads,
                     );
+                    self.stats.texture_uploads += 1;
                     self.yakui_managed_textures.insert(id, texture);
                 }
 
@@ -523,6 +488,7 @@ impl YakuiVulkan {
                         new,
                         &mut self.uploads,
                     );
+                    self.stats.texture_uploads += 1;
                     self.yakui_managed_textures.insert(id, texture);
                 }
             }
@@ -542,7 +508,7 @@ impl YakuiVulkan {
         let index_count = call.indices.len() as u32;
 
         for index in &call.indices {
-            indices.push(*index as u32 + base);
+            indices.push(*index + base);
         }
         for vertex in &call.vertices {
             vertices.push(vertex.into())
@@ -561,9 +527,17 @@ impl YakuiVulkan {
                         .get(thunderdome::Index::from_bits(bits)?)?;
                     Some(texture.id)
                 }
+                // Render targets are never painted because they aren't supported yet.
+                yakui_core::TextureId::RenderTarget(_) => None,
             })
             .unwrap_or(NO_TEXTURE_ID);
 
+        // Calls using a pipeline that was never registered are drawn like any other mesh instead.
+        let custom_pipeline = match call.pipeline {
+            Pipeline::Custom(id) => self.custom_pipelines.get(id as usize).copied(),
+            _ => None,
+        };
+
         (
             clip,
             DrawCall::Yakui(YakuiDrawCall {
@@ -571,10 +545,125 @@ impl YakuiVulkan {
                 index_count,
                 texture_id,
                 workflow: call.pipeline.into(),
+                custom_pipeline,
+                uniforms: call.uniforms,
             }),
         )
     }
 
+    /// Build a [`ClipMaskDraw`] that writes a clip mask for the given clip rect into the stencil
+    /// buffer. `surface_size` is the size of the surface in physical pixels.
+    pub fn build_clip_mask(
+        &self,
+        vertices: &mut Vec<Vertex>,
+        indices: &mut Vec<u32>,
+        surface_size: Vec2,
+        clip: Rect,
+        mask: &ClipMask,
+    ) -> ClipMaskDraw {
+        let mut add_triangles = |triangles: &[Vec2]| {
+            let start = indices.len() as u32;
+
+            for &position in triangles {
+                indices.push(vertices.len() as u32);
+                vertices.push(Vertex {
+                    position: position / surface_size,
+                    texcoord: Vec2::ZERO,
+                    color: Vec4::ONE,
+                });
+            }
+
+            start..indices.len() as u32
+        };
+
+        let (min, max) = (clip.pos(), clip.max());
+        let clear_range = add_triangles(&[
+            min,
+            Vec2::new(max.x, min.y),
+            max,
+            max,
+            Vec2::new(min.x, max.y),
+            min,
+        ]);
+
+        let shape_ranges = mask
+            .shapes()
+            .iter()
+            .map(|shape| add_triangles(&shape.triangles()))
+            .collect();
+
+        ClipMaskDraw {
+            clear_range,
+            shape_ranges,
+        }
+    }
+
+    /// Write a clip mask into the stencil buffer, inside of the current scissor rect, then bind
+    /// the main pipeline again. Content should be drawn with the stencil reference returned by
+    /// [`ClipMaskDraw::stencil_reference`] afterwards.
+    ///
+    /// Does nothing if this instance was created without a stencil format.
+    pub fn draw_clip_mask(
+        &self,
+        vulkan_context: &VulkanContext,
+        cmd: vk::CommandBuffer,
+        mask: &ClipMaskDraw,
+    ) {
+        let Some(pipelines) = &self.clip_mask_pipelines else {
+            return;
+        };
+
+        let device = vulkan_context.device;
+        let draw = |range: &Range<u32>| unsafe {
+            device.cmd_draw_indexed(cmd, range.end - range.start, 1, range.start, 0, 1);
+        };
+
+        unsafe {
+            device.cmd_push_constants(
+                cmd,
+                self.pipeline_layout,
+                vk::ShaderStageFlags::FRAGMENT,
+                0,
+                bytes_of(&PushConstant::new(NO_TEXTURE_ID, Workflow::Main)),
+            );
+
+            device.cmd_bind_pipeline(cmd, vk::PipelineBindPoint::GRAPHICS, pipelines.clear);
+            draw(&mask.clear_range);
+
+            device.cmd_bind_pipeline(cmd, vk::PipelineBindPoint::GRAPHICS, pipelines.mask);
+            for (depth, range) in mask.shape_ranges.iter().enumerate() {
+                device.cmd_set_stencil_reference(
+                    cmd,
+                    vk::StencilFaceFlags::FRONT_AND_BACK,
+                    depth as u32,
+                );
+                draw(range);
+            }
+
+            device.cmd_bind_pipeline(cmd, vk::PipelineBindPoint::GRAPHICS, self.graphics_pipeline);
+        }
+    }
+
+    /// Set the stencil reference that content is drawn with. See [`ClipMaskDraw`].
+    ///
+    /// Does nothing if this instance was created without a stencil format.
+    pub fn set_stencil_reference(
+        &self,
+        vulkan_context: &VulkanContext,
+        cmd: vk::CommandBuffer,
+        reference: u32,
+    ) {
+        if self.clip_mask_pipelines.is_some() {
+            unsafe {
+                vulkan_context.device.cmd_set_stencil_reference(
+                    cmd,
+                    vk::StencilFaceFlags::FRONT_AND_BACK,
+                    reference,
+                );
+            }
+        }
+    }
+
     /// Execute one single yakui draw call.
     pub fn draw_yakui(
         &self,
@@ -585,20 +674,291 @@ impl YakuiVulkan {
         let device = vulkan_context.device;
 
         unsafe {
+            if let Some(pipeline) = draw_call.custom_pipeline {
+                device.cmd_bind_pipeline(cmd, vk::PipelineBindPoint::GRAPHICS, pipeline);
+            }
+
             // Instead of using different pipelines for text and non-text rendering, we just
             // pass the "workflow" down through a push constant and branch in the shader.
+            let push_constant = PushConstant::new(draw_call.texture_id, draw_call.workflow)
+                .with_uniforms(draw_call.uniforms);
             device.cmd_push_constants(
                 cmd,
                 self.pipeline_layout,
                 vk::ShaderStageFlags::FRAGMENT,
                 0,
-                bytes_of(&PushConstant::new(draw_call.texture_id, draw_call.workflow)),
+                bytes_of(&push_constant),
             );
 
             // Draw the mesh with the indexes we were provided
             device.cmd_draw_indexed(cmd, draw_call.index_count, 1, draw_call.index_offset, 0, 1);
+
+            // Custom pipelines are only bound for the calls that use them, so the main pipeline is
+            // bound again afterwards.
+            if draw_call.custom_pipeline.is_some() {
+                device.cmd_bind_pipeline(
+                    cmd,
+                    vk::PipelineBindPoint::GRAPHICS,
+                    self.graphics_pipeline,
+                );
+            }
+        }
+    }
+}
+
+/// A draw call along with the clip mask it needs.
+struct MaskedDrawCall {
+    clip: Rect,
+    /// A clip mask that needs to be written before drawing the call, if it isn't already in the
+    /// stencil buffer.
+    write_mask: Option<ClipMaskDraw>,
+    stencil_reference: u32,
+    draw_call: DrawCall,
+}
+
+/// Creates a pipeline drawing yakui's vertices with the given fragment shader. If
+/// `with_clip_masks` is set, pipelines for clearing and writing clip masks are created after it.
+fn create_pipelines(
+    device: &ash::Device,
+    pipeline_layout: vk::PipelineLayout,
+    options: &Options,
+    frag_code: &[u32],
+    with_clip_masks: bool,
+) -> Vec<vk::Pipeline> {
+    let mut vertex_spv_file = Cursor::new(&include_bytes!("../shaders/main.vert.spv")[..]);
+    let vertex_code =
+        read_spv(&mut vertex_spv_file).expect("Failed to read vertex shader spv file");
+    let vertex_shader_info = vk::ShaderModuleCreateInfo::default().code(&vertex_code);
+    let frag_shader_info = vk::ShaderModuleCreateInfo::default().code(frag_code);
+
+    let vertex_shader_module = unsafe {
+        device
+            .create_shader_module(&vertex_shader_info, None)
+            .expect("Vertex shader module error")
+    };
+
+    let fragment_shader_module = unsafe {
+        device
+            .create_shader_module(&frag_shader_info, None)
+            .expect("Fragment shader module error")
+    };
+
+    let shader_entry_name = c"main";
+    let shader_stage_create_infos = [
+        vk::PipelineShaderStageCreateInfo {
+            module: vertex_shader_module,
+            p_name: shader_entry_name.as_ptr(),
+            stage: vk::ShaderStageFlags::VERTEX,
+            ..Default::default()
+        },
+        vk::PipelineShaderStageCreateInfo {
+            s_type: vk::StructureType::PIPELINE_SHADER_STAGE_CREATE_INFO,
+            module: fragment_shader_module,
+            p_name: shader_entry_name.as_ptr(),
+            stage: vk::ShaderStageFlags::FRAGMENT,
+            ..Default::default()
+        },
+    ];
+    let vertex_input_binding_descriptions = [vk::VertexInputBindingDescription {
+        binding: 0,
+        stride: std::mem::size_of::<Vertex>() as u32,
+        input_rate: vk::VertexInputRate::VERTEX,
+    }];
+
+    let vertex_input_attribute_descriptions = [
+        // position
+        vk::VertexInputAttributeDescription {
+            location: 0,
+            binding: 0,
+            format: vk::Format::R32G32_SFLOAT,
+            offset: bytemuck::offset_of!(Vertex, position) as _,
+        },
+        // UV / texcoords
+        vk::VertexInputAttributeDescription {
+            location: 1,
+            binding: 0,
+            format: vk::Format::R32G32_SFLOAT,
+            offset: bytemuck::offset_of!(Vertex, texcoord) as _,
+        },
+        // color
+        vk::VertexInputAttributeDescription {
+            location: 2,
+            binding: 0,
+            format: vk::Format::R32G32B32A32_SFLOAT,
+            offset: bytemuck::offset_of!(Vertex, color) as _,
+        },
+    ];
+
+    let vertex_input_state_info = vk::PipelineVertexInputStateCreateInfo::default()
+        .vertex_attribute_descriptions(&vertex_input_attribute_descriptions)
+        .vertex_binding_descriptions(&vertex_input_binding_descriptions);
+    let vertex_input_assembly_state_info = vk::PipelineInputAssemblyStateCreateInfo {
+        topology: vk::PrimitiveTopology::TRIANGLE_LIST,
+        ..Default::default()
+    };
+    let viewport_state_info = vk::PipelineViewportStateCreateInfo::default()
+        .scissor_count(1)
+        .viewport_count(1);
+
+    let rasterization_info = vk::PipelineRasterizationStateCreateInfo {
+        front_face: vk::FrontFace::COUNTER_CLOCKWISE,
+        line_width: 1.0,
+        polygon_mode: vk::PolygonMode::FILL,
+        ..Default::default()
+    };
+    let multisample_state_info = vk::PipelineMultisampleStateCreateInfo {
+        rasterization_samples: vk::SampleCountFlags::TYPE_1,
+        ..Default::default()
+    };
+    let noop_stencil_state = vk::StencilOpState {
+        fail_op: vk::StencilOp::KEEP,
+        pass_op: vk::StencilOp::KEEP,
+        depth_fail_op: vk::StencilOp::KEEP,
+        compare_op: vk::CompareOp::ALWAYS,
+        ..Default::default()
+    };
+    let stencil_state = |compare_op, pass_op| vk::StencilOpState {
+        fail_op: vk::StencilOp::KEEP,
+        pass_op,
+        depth_fail_op: vk::StencilOp::KEEP,
+        compare_op,
+        compare_mask: 0xff,
+        write_mask: 0xff,
+        reference: 0,
+    };
+    let depth_state =
+        |stencil: Option<vk::StencilOpState>| vk::PipelineDepthStencilStateCreateInfo {
+            depth_test_enable: 1,
+            depth_write_enable: 1,
+            depth_compare_op: vk::CompareOp::LESS_OR_EQUAL,
+            stencil_test_enable: stencil.is_some() as u32,
+            front: stencil.unwrap_or(noop_stencil_state),
+            back: stencil.unwrap_or(noop_stencil_state),
+            max_depth_bounds: 1.0,
+            ..Default::default()
+        };
+
+    // With a stencil attachment, content is only drawn where the stencil value is at least the
+    // reference value, so drawing with a reference of zero ignores the stencil buffer.
+    let use_stencil = options.stencil_format.is_some();
+    let depth_state_info = depth_state(
+        use_stencil.then(|| stencil_state(vk::CompareOp::LESS_OR_EQUAL, vk::StencilOp::KEEP)),
+    );
+    let clear_depth_state_info = depth_state(Some(stencil_state(
+        vk::CompareOp::ALWAYS,
+        vk::StencilOp::ZERO,
+    )));
+    let mask_depth_state_info = depth_state(Some(stencil_state(
+        vk::CompareOp::EQUAL,
+        vk::StencilOp::INCREMENT_AND_CLAMP,
+    )));
+    let color_blend_attachment_states = [vk::PipelineColorBlendAttachmentState {
+        blend_enable: 1,
+        src_color_blend_factor: vk::BlendFactor::SRC_ALPHA,
+        dst_color_blend_factor: vk::BlendFactor::ONE_MINUS_SRC_ALPHA,
+        color_blend_op: vk::BlendOp::ADD,
+        src_alpha_blend_factor: vk::BlendFactor::ONE,
+        dst_alpha_blend_factor: vk::BlendFactor::ZERO,
+        alpha_blend_op: vk::BlendOp::ADD,
+        color_write_mask: vk::ColorComponentFlags::RGBA,
+    }];
+    let color_blend_state = vk::PipelineColorBlendStateCreateInfo::default()
+        .logic_op(vk::LogicOp::CLEAR)
+        .attachments(&color_blend_attachment_states);
+
+    let stencil_only_attachment_states = [vk::PipelineColorBlendAttachmentState {
+        color_write_mask: vk::ColorComponentFlags::empty(),
+        ..color_blend_attachment_states[0]
+    }];
+    let stencil_only_blend_state = vk::PipelineColorBlendStateCreateInfo::default()
+        .logic_op(vk::LogicOp::CLEAR)
+        .attachments(&stencil_only_attachment_states);
+
+    let dynamic_state = if use_stencil {
+        &[
+            vk::DynamicState::VIEWPORT,
+            vk::DynamicState::SCISSOR,
+            vk::DynamicState::STENCIL_REFERENCE,
+        ][..]
+    } else {
+        &[vk::DynamicState::VIEWPORT, vk::DynamicState::SCISSOR][..]
+    };
+    let dynamic_state_info =
+        vk::PipelineDynamicStateCreateInfo::default().dynamic_states(dynamic_state);
+
+    let mut graphic_pipeline_info = vk::GraphicsPipelineCreateInfo::default()
+        .stages(&shader_stage_create_infos)
+        .vertex_input_state(&vertex_input_state_info)
+        .input_assembly_state(&vertex_input_assembly_state_info)
+        .viewport_state(&viewport_state_info)
+        .rasterization_state(&rasterization_info)
+        .multisample_state(&multisample_state_info)
+        .depth_stencil_state(&depth_state_info)
+        .color_blend_state(&color_blend_state)
+        .dynamic_state(&dynamic_state_info)
+        .layout(pipeline_layout);
+    let rendering_info_formats;
+    let mut rendering_info;
+    assert!(
+        options.dynamic_rendering_format.is_some() || options.render_pass != vk::RenderPass::null(),
+        "either dynamic_rendering_format or render_pass must be set"
+    );
+    if let Some(format) = options.dynamic_rendering_format {
+        rendering_info_formats = [format];
+        rendering_info = vk::PipelineRenderingCreateInfo::default()
+            .color_attachment_formats(&rendering_info_formats);
+
+        if let Some(stencil_format) = options.stencil_format {
+            rendering_info = rendering_info.stencil_attachment_format(stencil_format);
+
+            if has_depth(stencil_format) {
+                rendering_info = rendering_info.depth_attachment_format(stencil_format);
+            }
         }
+
+        graphic_pipeline_info = graphic_pipeline_info.push_next(&mut rendering_info);
+    } else {
+        graphic_pipeline_info = graphic_pipeline_info
+            .render_pass(options.render_pass)
+            .subpass(options.subpass);
     }
+
+    let mut pipeline_infos = vec![graphic_pipeline_info];
+    if with_clip_masks {
+        pipeline_infos.push(
+            graphic_pipeline_info
+                .depth_stencil_state(&clear_depth_state_info)
+                .color_blend_state(&stencil_only_blend_state),
+        );
+        pipeline_infos.push(
+            graphic_pipeline_info
+                .depth_stencil_state(&mask_depth_state_info)
+                .color_blend_state(&stencil_only_blend_state),
+        );
+    }
+
+    let graphics_pipelines = unsafe {
+        device
+            .create_graphics_pipelines(vk::PipelineCache::null(), &pipeline_infos, None)
+            .expect("Unable to create graphics pipeline")
+    };
+
+    unsafe {
+        device.destroy_shader_module(vertex_shader_module, None);
+        device.destroy_shader_module(fragment_shader_module, None);
+    }
+
+    graphics_pipelines
+}
+
+/// Whether a depth/stencil format has a depth component.
+fn has_depth(format: vk::Format) -> bool {
+    matches!(
+        format,
+        vk::Format::D16_UNORM_S8_UINT
+            | vk::Format::D24_UNORM_S8_UINT
+            | vk::Format::D32_SFLOAT_S8_UINT
+    )
 }
 
 /// Paint the yakui GUI using the provided [`VulkanContext`]
@@ -614,6 +974,12 @@ pub unsafe fn paint(
     resolution: vk::Extent2D,
 ) {
     // --- yakui ---
+    let texture_uploads = yakui_vulkan.stats.texture_uploads;
+    yakui_vulkan.stats = RenderStats {
+        texture_uploads,
+        ..RenderStats::default()
+    };
+
     // If there's nothing to paint, well... don't paint!
     let layers = &paint.layers;
     if layers.iter().all(|layer| layer.calls.is_empty()) {
@@ -627,18 +993,46 @@ pub unsafe fn paint(
 
     let mut vertices: Vec<Vertex> = Default::default();
     let mut indices: Vec<u32> = Default::default();
-    let mut draw_calls: Vec<(Rect, DrawCall)> = Default::default();
+    let mut draw_calls: Vec<MaskedDrawCall> = Default::default();
+    let mut written_mask: Option<(Rect, &ClipMask)> = None;
     // --- yakui ---
 
-    for (clip, call) in layers.iter().flat_map(|layer| &layer.calls) {
-        match call {
-            PaintCall::Internal(call) => {
-                draw_calls.push(yakui_vulkan.build_draw_call(
+    for call in layers.iter().flat_map(|layer| &layer.calls) {
+        let mask = call.mask();
+        match call.call() {
+            PaintCall::Internal(internal) => {
+                let (clip, draw_call) = yakui_vulkan.build_draw_call(
                     &mut vertices,
                     &mut indices,
-                    *clip,
-                    call,
-                ));
+                    call.clip(),
+                    internal,
+                );
+
+                // The stencil buffer is only written to within the clip rect, so masks need to be
+                // written again whenever it changes.
+                let mut write_mask = None;
+                let mut stencil_reference = 0;
+                if let Some(mask) = mask {
+                    if written_mask != Some((clip, mask)) {
+                        written_mask = Some((clip, mask));
+                        write_mask = Some(yakui_vulkan.build_clip_mask(
+                            &mut vertices,
+                            &mut indices,
+                            paint.surface_size(),
+                            clip,
+                            mask,
+                        ));
+                    }
+
+                    stencil_reference = mask.shapes().len() as u32;
+                }
+
+                draw_calls.push(MaskedDrawCall {
+                    clip,
+                    write_mask,
+                    stencil_reference,
+                    draw_call,
+                });
             }
             PaintCall::User(_) => {
                 panic!("yakui does not handle User PaintCall's by default. Please set up your own rendering logic instead.");
@@ -654,6 +1048,10 @@ pub unsafe fn paint(
             .write(vulkan_context, 0, &vertices);
     }
 
+    yakui_vulkan.stats.buffer_uploads += 2;
+    yakui_vulkan.stats.bytes_uploaded +=
+        std::mem::size_of_val(indices.as_slice()) + std::mem::size_of_val(vertices.as_slice());
+
     let device = vulkan_context.device;
     let surface_size = UVec2::new(resolution.width, resolution.height);
     // --- yakui ---
@@ -665,8 +1063,10 @@ pub unsafe fn paint(
             vk::PipelineBindPoint::GRAPHICS,
             yakui_vulkan.graphics_pipeline,
         );
+        yakui_vulkan.stats.pipeline_switches += 1;
         let default_scissor = [resolution.into()];
         device.cmd_set_scissor(cmd, 0, &default_scissor);
+        yakui_vulkan.set_stencil_reference(vulkan_context, cmd, 0);
 
         device.cmd_bind_vertex_buffers(cmd, 0, &[yakui_vulkan.vertex_buffer.handle], &[0]);
         device.cmd_bind_index_buffer(
@@ -686,7 +1086,8 @@ pub unsafe fn paint(
 
         let mut last_clip = None;
 
-        for (clip, draw_call) in draw_calls {
+        for call in draw_calls {
+            let clip = call.clip;
             if Some(clip) != last_clip {
                 last_clip = Some(clip);
 
@@ -716,10 +1117,26 @@ pub unsafe fn paint(
                 // If there's a clip, update the scissor
                 device.cmd_set_scissor(cmd, 0, &scissors);
             }
+
+            if let Some(mask) = &call.write_mask {
+                yakui_vulkan.draw_clip_mask(vulkan_context, cmd, mask);
+
+                // The clear and mask pipelines are bound, followed by the main pipeline again.
+                if yakui_vulkan.clip_mask_pipelines.is_some() {
+                    yakui_vulkan.stats.draw_calls += 1 + mask.shape_ranges.len();
+                    yakui_vulkan.stats.pipeline_switches += 3;
+                }
+            }
+            yakui_vulkan.set_stencil_reference(vulkan_context, cmd, call.stencil_reference);
             // --- yakui ---
 
-            match draw_call {
+            match call.draw_call {
                 DrawCall::Yakui(draw_call) => {
+                    yakui_vulkan.stats.draw_calls += 1;
+                    if draw_call.custom_pipeline.is_some() {
+                        yakui_vulkan.stats.pipeline_switches += 2;
+                    }
+
                     yakui_vulkan.draw_yakui(vulkan_context, cmd, draw_call);
                 }
                 DrawCall::User(_) => {
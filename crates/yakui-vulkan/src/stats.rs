@@ -0,0 +1,22 @@
+/// Counters for the work done by the renderer, returned by [`YakuiVulkan::stats`][crate::YakuiVulkan::stats].
+///
+/// Combined with [`PaintStats`][yakui_core::paint::PaintStats], these tell how much of a frame's cost
+/// comes from the UI itself and how much from getting it onto the GPU.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    /// The number of draw calls recorded by the last paint, including the ones writing clip masks.
+    pub draw_calls: usize,
+
+    /// How many times a pipeline was bound by the last paint.
+    pub pipeline_switches: usize,
+
+    /// How many vertex and index buffers were written to by the last paint.
+    pub buffer_uploads: usize,
+
+    /// The number of bytes written to those buffers.
+    pub bytes_uploaded: usize,
+
+    /// How many yakui-managed textures were created or updated by the last call to
+    /// [`YakuiVulkan::transfer`][crate::YakuiVulkan::transfer].
+    pub texture_uploads: usize,
+}
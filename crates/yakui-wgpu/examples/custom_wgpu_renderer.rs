@@ -121,6 +121,7 @@ fn my_custom_paint(
     yakui_wgpu.vertices.clear();
     yakui_wgpu.indices.clear();
     yakui_wgpu.texture_bindgroup_cache.clear();
+    yakui_wgpu.custom_pipelines.clear();
 
     let mut draw_calls = Vec::with_capacity(layers.len());
     // --- yakui ---
@@ -133,10 +134,13 @@ fn my_custom_paint(
         .duration_since(renderer.initial_time)
         .as_secs_f32();
 
-    for (clip, call) in layers.iter().flat_map(|layer| &layer.calls) {
-        match call {
+    // This example doesn't use clip masks, so they're ignored. See
+    // `yakui_wgpu::paint` for how to paint them.
+    for call in layers.iter().flat_map(|layer| &layer.calls) {
+        let clip = call.clip();
+        match call.call() {
             PaintCall::Internal(call) => {
-                draw_calls.push(yakui_wgpu.build_draw_call(device, *clip, call));
+                draw_calls.push(yakui_wgpu.build_draw_call(device, clip, call));
             }
             PaintCall::User(id) => {
                 let min = clip.pos();
@@ -171,7 +175,7 @@ fn my_custom_paint(
                 yakui_wgpu.vertices.extend(vertices);
                 yakui_wgpu.indices.extend(indices);
 
-                draw_calls.push((*clip, DrawCall::User(*id)));
+                draw_calls.push((clip, DrawCall::User(*id)));
                 custom_draws.insert_at(index, start..end);
             }
         }
@@ -190,6 +194,9 @@ fn my_custom_paint(
             yakui_wgpu::main_pipeline(&mut yakui_wgpu.main_pipeline, device, &surface);
         let text_pipeline =
             yakui_wgpu::text_pipeline(&mut yakui_wgpu.text_pipeline, device, &surface);
+        let custom_pipelines = yakui_wgpu
+            .custom_pipelines
+            .prepare(device, queue, &surface, None);
 
         let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
             label: Some("yakui Render Pass"),
@@ -236,6 +243,7 @@ fn my_custom_paint(
                         &mut render_pass,
                         main_pipeline,
                         text_pipeline,
+                        &custom_pipelines,
                         call,
                     );
                 }
@@ -451,12 +459,11 @@ impl ApplicationHandler for App {
         _window_id: WindowId,
         event: WindowEvent,
     ) {
-        if self
-            .yak_window
-            .as_mut()
-            .unwrap()
-            .handle_window_event(&mut self.yak, &event)
-        {
+        if self.yak_window.as_mut().unwrap().handle_window_event(
+            &mut self.yak,
+            &event,
+            self.window.as_ref().unwrap(),
+        ) {
             return;
         }
 
@@ -498,13 +505,15 @@ impl ApplicationHandler for App {
                 self.window.as_ref().unwrap().request_redraw();
             }
 
-            WindowEvent::MouseInput { state, button, .. } => {
+            WindowEvent::MouseInput {
+                state,
+                button: winit::event::MouseButton::Left,
+                ..
+            } => {
                 // This print is a handy way to show which mouse events are
                 // handled by yakui, and which ones will make it to the
                 // underlying application.
-                if button == winit::event::MouseButton::Left {
-                    println!("Left mouse button {state:?}");
-                }
+                println!("Left mouse button {state:?}");
             }
 
             WindowEvent::Resized(..) => {
@@ -37,5 +37,7 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     var color = textureSample(color_texture, color_sampler, in.texcoord);
     color *= in.color.a;
 
-    return in.color * color;
+    // Vertex colors have straight alpha, but the output is premultiplied, so
+    // alpha is only applied once.
+    return vec4(in.color.rgb * color.rgb, color.a);
 }
\ No newline at end of file
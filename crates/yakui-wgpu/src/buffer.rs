@@ -25,12 +25,16 @@ impl Buffer {
         self.len
     }
 
+    /// The size of the buffer's contents in bytes.
+    pub fn byte_len(&self) -> usize {
+        self.cpu_buffer.len()
+    }
+
     pub fn clear(&mut self) {
         self.len = 0;
         self.cpu_buffer.clear();
     }
 
-    #[allow(unused)]
     pub fn push(&mut self, value: &impl NoUninit) {
         self.len += 1;
         self.cpu_buffer.extend(bytes_of(value));
@@ -53,6 +57,13 @@ impl Buffer {
         }
     }
 
+    /// Pads the buffer with zeroes until its size in bytes is a multiple of
+    /// `alignment`.
+    pub fn pad_to(&mut self, alignment: usize) {
+        let padded = self.cpu_buffer.len().next_multiple_of(alignment);
+        self.cpu_buffer.resize(padded, 0);
+    }
+
     pub fn upload(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) -> &wgpu::Buffer {
         let buffer = if self.gpu_buffer_len >= self.cpu_buffer.len() {
             self.gpu_buffer.as_ref().unwrap()
@@ -0,0 +1,161 @@
+use std::ops::Range;
+
+use glam::UVec2;
+
+use crate::pipeline_cache::PipelineCache;
+
+/// The format of the stencil buffer that clip masks are written to.
+pub const STENCIL_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Stencil8;
+
+/// The geometry used to write a clip mask into the stencil buffer.
+///
+/// The stencil buffer is first cleared inside of the clip rect, then each shape
+/// is drawn in order, incrementing the stencil value of the pixels that were
+/// inside of every shape before it. Afterwards, only the pixels with a value
+/// equal to the number of shapes are inside of the mask.
+pub struct ClipMaskDraw {
+    /// Indices of a quad covering the clip rect.
+    pub clear_range: Range<u32>,
+    /// Indices of the triangles of each shape in the mask.
+    pub shape_ranges: Vec<Range<u32>>,
+}
+
+impl ClipMaskDraw {
+    /// The stencil reference value to draw with so that only the pixels
+    /// inside of the mask are painted.
+    pub fn stencil_reference(&self) -> u32 {
+        self.shape_ranges.len() as u32
+    }
+}
+
+/// The pipelines used when painting with clip masks, which all have a stencil
+/// attachment.
+pub struct ClipPipelines {
+    pub main: PipelineCache,
+    pub text: PipelineCache,
+    pub clear: PipelineCache,
+    pub mask: PipelineCache,
+    pub stencil: StencilBuffer,
+}
+
+/// A stencil buffer matching the size and sample count of the surface being
+/// painted to.
+pub struct StencilBuffer {
+    current: Option<StencilTarget>,
+}
+
+struct StencilTarget {
+    view: wgpu::TextureView,
+    size: UVec2,
+    samples: u32,
+}
+
+impl ClipPipelines {
+    pub fn new(device: &wgpu::Device, layout: &wgpu::BindGroupLayout) -> Self {
+        let pipeline_layout = || {
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("yakui Clip Mask Pipeline Layout"),
+                bind_group_layouts: &[Some(layout)],
+                immediate_size: 0,
+            })
+        };
+
+        Self {
+            main: PipelineCache::new(pipeline_layout()),
+            text: PipelineCache::new(pipeline_layout()),
+            clear: PipelineCache::new(pipeline_layout()),
+            mask: PipelineCache::new(pipeline_layout()),
+            stencil: StencilBuffer::new(),
+        }
+    }
+}
+
+impl StencilBuffer {
+    pub fn new() -> Self {
+        Self { current: None }
+    }
+
+    /// Returns the stencil buffer created by the last call to `get`, if there
+    /// was one.
+    pub fn current(&self) -> Option<&wgpu::TextureView> {
+        self.current.as_ref().map(|current| &current.view)
+    }
+
+    /// Returns a stencil buffer matching the given surface, creating a new one
+    /// if the surface changed.
+    pub fn get(&mut self, device: &wgpu::Device, size: UVec2, samples: u32) -> &wgpu::TextureView {
+        match &self.current {
+            Some(existing) if existing.size == size && existing.samples == samples => (),
+            _ => {
+                let texture = device.create_texture(&wgpu::TextureDescriptor {
+                    label: Some("yakui Clip Mask Stencil"),
+                    size: wgpu::Extent3d {
+                        width: size.x,
+                        height: size.y,
+                        depth_or_array_layers: 1,
+                    },
+                    mip_level_count: 1,
+                    sample_count: samples,
+                    dimension: wgpu::TextureDimension::D2,
+                    format: STENCIL_FORMAT,
+                    usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                    view_formats: &[],
+                });
+
+                self.current = Some(StencilTarget {
+                    view: texture.create_view(&Default::default()),
+                    size,
+                    samples,
+                });
+            }
+        }
+
+        &self.current.as_ref().unwrap().view
+    }
+}
+
+/// Stencil state for painting content, which passes where the stencil value
+/// is at least the reference value. Painting with a reference of zero ignores
+/// the stencil buffer entirely.
+pub fn content_stencil() -> wgpu::DepthStencilState {
+    stencil_state(
+        wgpu::CompareFunction::LessEqual,
+        wgpu::StencilOperation::Keep,
+    )
+}
+
+/// Stencil state for clearing the stencil buffer before writing a mask.
+pub fn clear_stencil() -> wgpu::DepthStencilState {
+    stencil_state(wgpu::CompareFunction::Always, wgpu::StencilOperation::Zero)
+}
+
+/// Stencil state for drawing one shape of a mask, which increments the pixels
+/// that were inside of every previous shape.
+pub fn mask_stencil() -> wgpu::DepthStencilState {
+    stencil_state(
+        wgpu::CompareFunction::Equal,
+        wgpu::StencilOperation::IncrementClamp,
+    )
+}
+
+fn stencil_state(
+    compare: wgpu::CompareFunction,
+    pass_op: wgpu::StencilOperation,
+) -> wgpu::DepthStencilState {
+    let face = wgpu::StencilFaceState {
+        compare,
+        fail_op: wgpu::StencilOperation::Keep,
+        depth_fail_op: wgpu::StencilOperation::Keep,
+        pass_op,
+    };
+
+    wgpu::DepthStencilState::stencil(
+        STENCIL_FORMAT,
+        wgpu::StencilState {
+            front: face,
+            back: face,
+            read_mask: 0xff,
+            write_mask: 0xff,
+        },
+    )
+}
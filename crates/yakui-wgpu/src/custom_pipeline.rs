@@ -0,0 +1,194 @@
+use std::mem::size_of;
+
+use yakui_core::paint::{CustomPipelineId, Pipeline, Uniforms};
+
+use crate::buffer::Buffer;
+use crate::pipeline_cache::PipelineCache;
+use crate::SurfaceInfo;
+
+/// Pipelines with shaders provided by the application, which are registered
+/// with [`YakuiWgpu::add_pipeline`][crate::YakuiWgpu::add_pipeline].
+///
+/// Custom shaders are given the same vertices as the main pipeline and must
+/// output premultiplied colors. Their bind groups are laid out like this:
+///
+/// ```wgsl
+/// @group(0) @binding(0) var color_texture: texture_2d<f32>;
+/// @group(0) @binding(1) var color_sampler: sampler;
+///
+/// @group(1) @binding(0) var<uniform> uniforms: array<vec4<f32>, 4>;
+/// ```
+pub struct CustomPipelines {
+    pipelines: Vec<CustomPipeline>,
+    texture_layout: wgpu::BindGroupLayout,
+    uniform_layout: wgpu::BindGroupLayout,
+
+    /// The uniforms of every paint call using a custom pipeline this frame,
+    /// each aligned so that it can be bound with a dynamic offset.
+    uniforms: Buffer,
+    uniform_alignment: usize,
+}
+
+struct CustomPipeline {
+    shader: wgpu::ShaderModule,
+    plain: PipelineCache,
+    stencil: PipelineCache,
+}
+
+/// The custom pipelines for the surface being painted to, along with the
+/// uniforms of this frame's paint calls.
+pub struct PreparedCustomPipelines<'a> {
+    pipelines: Vec<&'a wgpu::RenderPipeline>,
+    uniforms: Option<wgpu::BindGroup>,
+}
+
+impl CustomPipelines {
+    pub fn new(device: &wgpu::Device, texture_layout: &wgpu::BindGroupLayout) -> Self {
+        let uniform_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("yakui Uniforms Bind Group Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: true,
+                    min_binding_size: wgpu::BufferSize::new(size_of::<Uniforms>() as u64),
+                },
+                count: None,
+            }],
+        });
+
+        Self {
+            pipelines: Vec::new(),
+            texture_layout: texture_layout.clone(),
+            uniform_layout,
+            uniforms: Buffer::new(wgpu::BufferUsages::UNIFORM),
+            uniform_alignment: device.limits().min_uniform_buffer_offset_alignment as usize,
+        }
+    }
+
+    /// Registers a pipeline using the given shader, which must have a vertex
+    /// and a fragment entry point.
+    pub fn add(&mut self, device: &wgpu::Device, shader: wgpu::ShaderModule) -> Pipeline {
+        let layout = || {
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("yakui Custom Pipeline Layout"),
+                bind_group_layouts: &[Some(&self.texture_layout), Some(&self.uniform_layout)],
+                immediate_size: 0,
+            })
+        };
+
+        let pipeline = CustomPipeline {
+            shader,
+            plain: PipelineCache::new(layout()),
+            stencil: PipelineCache::new(layout()),
+        };
+
+        let id = self.pipelines.len() as CustomPipelineId;
+        self.pipelines.push(pipeline);
+        Pipeline::Custom(id)
+    }
+
+    pub fn clear(&mut self) {
+        self.uniforms.clear();
+    }
+
+    /// The size in bytes of the uniforms pushed this frame.
+    pub fn uniforms_len(&self) -> usize {
+        self.uniforms.byte_len()
+    }
+
+    /// Adds the uniforms of a paint call, returning the offset that they need
+    /// to be bound at.
+    pub fn push_uniforms(&mut self, uniforms: &Uniforms) -> u32 {
+        self.uniforms.pad_to(self.uniform_alignment);
+
+        let offset = self.uniforms.byte_len() as u32;
+        self.uniforms.push(&uniforms.to_array());
+        offset
+    }
+
+    /// Uploads this frame's uniforms and gets every custom pipeline for the
+    /// given surface. Pipelines used in a render pass with a clip mask stencil
+    /// attachment need the same `depth_stencil` state as the main pipeline.
+    pub fn prepare<'a>(
+        &'a mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        surface: &SurfaceInfo<'_>,
+        depth_stencil: Option<wgpu::DepthStencilState>,
+    ) -> PreparedCustomPipelines<'a> {
+        let uniforms = (self.uniforms.byte_len() > 0).then(|| {
+            let buffer = self.uniforms.upload(device, queue);
+
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("yakui Uniforms Bind Group"),
+                layout: &self.uniform_layout,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                        buffer,
+                        offset: 0,
+                        size: wgpu::BufferSize::new(size_of::<Uniforms>() as u64),
+                    }),
+                }],
+            })
+        });
+
+        let pipelines = self
+            .pipelines
+            .iter_mut()
+            .enumerate()
+            .map(|(id, pipeline)| {
+                let shader = &pipeline.shader;
+                let cache = match depth_stencil {
+                    Some(_) => &mut pipeline.stencil,
+                    None => &mut pipeline.plain,
+                };
+
+                cache.get(
+                    device,
+                    surface.format,
+                    surface.sample_count,
+                    |device, layout, format, samples| {
+                        crate::make_pipeline(
+                            device,
+                            layout,
+                            format,
+                            samples,
+                            Pipeline::Custom(id as CustomPipelineId),
+                            shader,
+                            wgpu::ColorWrites::ALL,
+                            depth_stencil.clone(),
+                        )
+                    },
+                )
+            })
+            .collect();
+
+        PreparedCustomPipelines {
+            pipelines,
+            uniforms,
+        }
+    }
+}
+
+impl PreparedCustomPipelines<'_> {
+    /// Sets the pipeline and uniforms for a paint call using a custom
+    /// pipeline. Returns false if the pipeline wasn't registered.
+    pub(crate) fn set(
+        &self,
+        render_pass: &mut wgpu::RenderPass,
+        id: CustomPipelineId,
+        uniform_offset: u32,
+    ) -> bool {
+        let (Some(pipeline), Some(uniforms)) = (self.pipelines.get(id as usize), &self.uniforms)
+        else {
+            return false;
+        };
+
+        render_pass.set_pipeline(pipeline);
+        render_pass.set_bind_group(1, uniforms, &[uniform_offset]);
+        true
+    }
+}
@@ -3,8 +3,12 @@
 
 mod bindgroup_cache;
 mod buffer;
+mod clip_mask;
+mod custom_pipeline;
 mod pipeline_cache;
+mod render_target;
 mod samplers;
+mod stats;
 mod texture;
 
 use std::collections::HashMap;
@@ -18,21 +22,30 @@ use glam::UVec2;
 use thunderdome::{Arena, Index};
 use yakui_core::geometry::{Rect, Vec2, Vec4};
 use yakui_core::paint::{
-    PaintCall, PaintDom, PaintLimits, Pipeline, Texture, TextureChange, TextureFormat,
-    UserPaintCallId, Vertex as YakuiVertex, YakuiPaintCall,
+    ClipMask, PaintCall, PaintDom, PaintLayerCall, PaintLimits, Pipeline, Texture, TextureChange,
+    TextureFormat, UserPaintCallId, Vertex as YakuiVertex, YakuiPaintCall,
 };
 use yakui_core::{ManagedTextureId, TextureId, Yakui};
 
 use self::bindgroup_cache::TextureBindgroupCache;
 use self::bindgroup_cache::TextureBindgroupCacheEntry;
+use self::clip_mask::ClipPipelines;
 use self::pipeline_cache::PipelineCache;
+use self::render_target::{RenderTargets, TargetPass};
 use self::samplers::Samplers;
 use self::texture::{GpuManagedTexture, GpuTexture};
 
+pub use self::clip_mask::{ClipMaskDraw, STENCIL_FORMAT};
+pub use self::custom_pipeline::{CustomPipelines, PreparedCustomPipelines};
+pub use self::stats::RenderStats;
+
 pub struct YakuiDrawCall {
     pub index_range: Range<u32>,
     pub bind_group_entry: Option<TextureBindgroupCacheEntry>,
     pub pipeline: Pipeline,
+    /// The offset of the call's uniforms in the uniform buffer, if it uses a
+    /// custom pipeline.
+    pub uniform_offset: u32,
 }
 
 pub enum DrawCall {
@@ -45,6 +58,8 @@ pub struct YakuiWgpu {
 
     pub main_pipeline: PipelineCache,
     pub text_pipeline: PipelineCache,
+    pub custom_pipelines: CustomPipelines,
+    clip_pipelines: ClipPipelines,
 
     premul_pipeline: wgpu::RenderPipeline,
     premul_bind_group_layout: wgpu::BindGroupLayout,
@@ -56,6 +71,9 @@ pub struct YakuiWgpu {
 
     pub vertices: Buffer,
     pub indices: Buffer,
+
+    render_targets: RenderTargets,
+    stats: RenderStats,
 }
 
 #[derive(Debug, Clone)]
@@ -101,6 +119,7 @@ impl YakuiWgpu {
             max_texture_size_1d: device.limits().max_texture_dimension_1d,
             max_texture_size_2d: device.limits().max_texture_dimension_2d,
             max_texture_size_3d: device.limits().max_texture_dimension_3d,
+            render_targets: false,
         };
 
         let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -141,6 +160,9 @@ impl YakuiWgpu {
 
         let text_pipeline = PipelineCache::new(pipeline_layout);
 
+        let custom_pipelines = CustomPipelines::new(device, &layout);
+        let clip_pipelines = ClipPipelines::new(device, &layout);
+
         let samplers = Samplers::new(device);
 
         let premul_bind_group_layout =
@@ -232,6 +254,8 @@ impl YakuiWgpu {
             limits,
             main_pipeline,
             text_pipeline,
+            custom_pipelines,
+            clip_pipelines,
             samplers,
             premul_pipeline,
             premul_bind_group_layout,
@@ -241,9 +265,18 @@ impl YakuiWgpu {
             texture_bindgroup_cache: TextureBindgroupCache::new(layout, default_bindgroup),
             vertices: Buffer::new(wgpu::BufferUsages::VERTEX),
             indices: Buffer::new(wgpu::BufferUsages::INDEX),
+
+            render_targets: RenderTargets::new(),
+            stats: RenderStats::default(),
         }
     }
 
+    /// Returns counters for the work done by the last call to
+    /// [`YakuiWgpu::paint`].
+    pub fn stats(&self) -> RenderStats {
+        self.stats
+    }
+
     pub fn set_paint_limits(&self, state: &mut Yakui) {
         state.set_paint_limit(self.limits);
     }
@@ -287,6 +320,13 @@ impl YakuiWgpu {
         existing.view = view.into();
     }
 
+    /// Registers a pipeline that draws with the given shader, returning the
+    /// [`Pipeline`] that meshes can be painted with to use it. See
+    /// [`CustomPipelines`] for what the shader is given.
+    pub fn add_pipeline(&mut self, device: &wgpu::Device, shader: wgpu::ShaderModule) -> Pipeline {
+        self.custom_pipelines.add(device, shader)
+    }
+
     #[must_use = "YakuiWgpu::paint returns a command buffer which MUST be submitted to wgpu."]
     pub fn paint(
         &mut self,
@@ -326,7 +366,7 @@ impl YakuiWgpu {
         let vertices = call.vertices.iter().map(Vertex::from);
 
         let base = self.vertices.len() as u32;
-        let indices = call.indices.iter().map(|&index| base + index as u32);
+        let indices = call.indices.iter().map(|&index| base + index);
 
         let start = self.indices.len() as u32;
         let end = start + indices.len() as u32;
@@ -334,6 +374,11 @@ impl YakuiWgpu {
         self.vertices.extend(vertices);
         self.indices.extend(indices);
 
+        let uniform_offset = match call.pipeline {
+            Pipeline::Custom(_) => self.custom_pipelines.push_uniforms(&call.uniforms),
+            _ => 0,
+        };
+
         let bind_group_entry = call
             .texture
             .and_then(|id| match id {
@@ -360,6 +405,16 @@ impl YakuiWgpu {
                         texture.address_mode,
                     ))
                 }
+                // Render targets are as large as the surface, so their texels
+                // line up with the pixels they're drawn to.
+                TextureId::RenderTarget(widget) => Some((
+                    id,
+                    self.render_targets.get(widget)?,
+                    wgpu::FilterMode::Nearest,
+                    wgpu::FilterMode::Nearest,
+                    wgpu::MipmapFilterMode::Nearest,
+                    wgpu::AddressMode::ClampToEdge,
+                )),
             })
             .map(
                 |(id, view, min_filter, mag_filter, mipmap_filter, address_mode)| {
@@ -383,15 +438,89 @@ impl YakuiWgpu {
                 index_range: start..end,
                 bind_group_entry,
                 pipeline: call.pipeline,
+                uniform_offset,
             }),
         )
     }
 
+    /// Builds the geometry needed to write a clip mask for the given clip rect
+    /// into the stencil buffer. `surface_size` is the size of the surface in
+    /// physical pixels.
+    pub fn build_clip_mask(
+        &mut self,
+        surface_size: Vec2,
+        clip: Rect,
+        mask: &ClipMask,
+    ) -> ClipMaskDraw {
+        let mut add_triangles = |triangles: &[Vec2]| {
+            let base = self.vertices.len() as u32;
+            let start = self.indices.len() as u32;
+
+            self.vertices
+                .extend(triangles.iter().map(|&position| Vertex {
+                    position: position / surface_size,
+                    texcoord: Vec2::ZERO,
+                    color: Vec4::ONE,
+                }));
+            self.indices
+                .extend((0..triangles.len() as u32).map(|index| base + index));
+
+            start..start + triangles.len() as u32
+        };
+
+        let (min, max) = (clip.pos(), clip.max());
+        let clear_range = add_triangles(&[
+            min,
+            Vec2::new(max.x, min.y),
+            max,
+            max,
+            Vec2::new(min.x, max.y),
+            min,
+        ]);
+
+        let shape_ranges = mask
+            .shapes()
+            .iter()
+            .map(|shape| add_triangles(&shape.triangles()))
+            .collect();
+
+        ClipMaskDraw {
+            clear_range,
+            shape_ranges,
+        }
+    }
+
+    /// Writes a clip mask built by [`YakuiWgpu::build_clip_mask`] into the
+    /// stencil buffer, inside of the current scissor rect. Content should be
+    /// drawn with the stencil reference returned by
+    /// [`ClipMaskDraw::stencil_reference`] afterwards.
+    pub fn draw_clip_mask(
+        texture_bindgroup_cache: &TextureBindgroupCache,
+        render_pass: &mut wgpu::RenderPass,
+        clear_pipeline: &wgpu::RenderPipeline,
+        mask_pipeline: &wgpu::RenderPipeline,
+        mask: &ClipMaskDraw,
+    ) {
+        profiling::scope!("yakui-wgpu draw_clip_mask");
+
+        render_pass.set_bind_group(0, &texture_bindgroup_cache.default, &[]);
+
+        render_pass.set_pipeline(clear_pipeline);
+        render_pass.draw_indexed(mask.clear_range.clone(), 0, 0..1);
+
+        render_pass.set_pipeline(mask_pipeline);
+        for (depth, range) in mask.shape_ranges.iter().enumerate() {
+            render_pass.set_stencil_reference(depth as u32);
+            render_pass.draw_indexed(range.clone(), 0, 0..1);
+        }
+    }
+
     pub fn draw_yakui(
         texture_bindgroup_cache: &TextureBindgroupCache,
         render_pass: &mut wgpu::RenderPass,
         main_pipeline: &wgpu::RenderPipeline,
         text_pipeline: &wgpu::RenderPipeline,
+        custom_pipelines: &PreparedCustomPipelines<'_>,
         call: YakuiDrawCall,
     ) {
         profiling::scope!("yakui-wgpu draw_yakui");
@@ -399,6 +528,14 @@ impl YakuiWgpu {
         match call.pipeline {
             Pipeline::Main => render_pass.set_pipeline(main_pipeline),
             Pipeline::Text => render_pass.set_pipeline(text_pipeline),
+
+            // Calls using a pipeline that was never registered are drawn like
+            // any other mesh instead.
+            Pipeline::Custom(id) => {
+                if !custom_pipelines.set(render_pass, id, call.uniform_offset) {
+                    render_pass.set_pipeline(main_pipeline);
+                }
+            }
         }
 
         let bindgroup = call
@@ -423,6 +560,8 @@ impl YakuiWgpu {
         {
             for (id, texture) in textures.iter() {
                 self.managed_textures.entry(id).or_insert_with(|| {
+                    self.stats.texture_uploads += 1;
+
                     GpuManagedTexture::new(
                         device,
                         queue,
@@ -439,6 +578,7 @@ impl YakuiWgpu {
             match change {
                 TextureChange::Added => {
                     let texture = textures.get(id).unwrap();
+                    self.stats.texture_uploads += 1;
                     self.managed_textures.insert(
                         id,
                         GpuManagedTexture::new(
@@ -459,6 +599,7 @@ impl YakuiWgpu {
                 TextureChange::Modified => {
                     if let Some(existing) = self.managed_textures.get_mut(&id) {
                         let texture = textures.get(id).unwrap();
+                        self.stats.texture_uploads += 1;
                         existing.update(
                             device,
                             queue,
@@ -480,77 +621,83 @@ fn make_main_pipeline(
     format: wgpu::TextureFormat,
     samples: u32,
 ) -> wgpu::RenderPipeline {
-    let main_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-        label: Some("Main Shader"),
-        source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/main.wgsl").into()),
-    });
+    make_pipeline(
+        device,
+        layout,
+        format,
+        samples,
+        Pipeline::Main,
+        &builtin_shader(device, Pipeline::Main),
+        wgpu::ColorWrites::ALL,
+        None,
+    )
+}
 
-    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-        label: Some("yakui Main Pipeline"),
-        layout: Some(layout),
-        vertex: wgpu::VertexState {
-            module: &main_shader,
-            entry_point: None,
-            compilation_options: Default::default(),
-            buffers: &[Vertex::DESCRIPTOR],
-        },
-        fragment: Some(wgpu::FragmentState {
-            module: &main_shader,
-            entry_point: None,
-            compilation_options: Default::default(),
-            targets: &[Some(wgpu::ColorTargetState {
-                format,
-                blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
-                write_mask: wgpu::ColorWrites::ALL,
-            })],
-        }),
-        primitive: wgpu::PrimitiveState {
-            topology: wgpu::PrimitiveTopology::TriangleList,
-            strip_index_format: None,
-            front_face: wgpu::FrontFace::Ccw,
-            cull_mode: None,
-            polygon_mode: wgpu::PolygonMode::Fill,
-            unclipped_depth: false,
-            conservative: false,
-        },
-        depth_stencil: None,
-        multisample: wgpu::MultisampleState {
-            count: samples,
-            ..Default::default()
-        },
-        multiview_mask: None,
-        cache: None,
+fn make_text_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    format: wgpu::TextureFormat,
+    samples: u32,
+) -> wgpu::RenderPipeline {
+    make_pipeline(
+        device,
+        layout,
+        format,
+        samples,
+        Pipeline::Text,
+        &builtin_shader(device, Pipeline::Text),
+        wgpu::ColorWrites::ALL,
+        None,
+    )
+}
+
+fn builtin_shader(device: &wgpu::Device, pipeline: Pipeline) -> wgpu::ShaderModule {
+    let (label, source) = match pipeline {
+        Pipeline::Main => ("Main Shader", include_str!("../shaders/main.wgsl")),
+        Pipeline::Text => ("Text Shader", include_str!("../shaders/text.wgsl")),
+        Pipeline::Custom(_) => unreachable!("custom pipelines provide their own shader"),
+    };
+
+    device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some(label),
+        source: wgpu::ShaderSource::Wgsl(source.into()),
     })
 }
 
-fn make_text_pipeline(
+#[allow(clippy::too_many_arguments)]
+fn make_pipeline(
     device: &wgpu::Device,
     layout: &wgpu::PipelineLayout,
     format: wgpu::TextureFormat,
     samples: u32,
+    pipeline: Pipeline,
+    shader: &wgpu::ShaderModule,
+    write_mask: wgpu::ColorWrites,
+    depth_stencil: Option<wgpu::DepthStencilState>,
 ) -> wgpu::RenderPipeline {
-    let text_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-        label: Some("Text Shader"),
-        source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/text.wgsl").into()),
-    });
+    let label = match pipeline {
+        Pipeline::Main => "yakui Main Pipeline",
+        Pipeline::Text => "yakui Text Pipeline",
+        Pipeline::Custom(_) => "yakui Custom Pipeline",
+    };
 
     device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-        label: Some("yakui Text Pipeline"),
+        label: Some(label),
         layout: Some(layout),
         vertex: wgpu::VertexState {
-            module: &text_shader,
+            module: shader,
             entry_point: None,
             compilation_options: Default::default(),
             buffers: &[Vertex::DESCRIPTOR],
         },
         fragment: Some(wgpu::FragmentState {
-            module: &text_shader,
+            module: shader,
             entry_point: None,
             compilation_options: Default::default(),
             targets: &[Some(wgpu::ColorTargetState {
                 format,
                 blend: Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
-                write_mask: wgpu::ColorWrites::ALL,
+                write_mask,
             })],
         }),
         primitive: wgpu::PrimitiveState {
@@ -562,7 +709,7 @@ fn make_text_pipeline(
             unclipped_depth: false,
             conservative: false,
         },
-        depth_stencil: None,
+        depth_stencil,
         multisample: wgpu::MultisampleState {
             count: samples,
             ..Default::default()
@@ -598,6 +745,54 @@ pub fn text_pipeline<'a>(
     )
 }
 
+/// Gets a pipeline for drawing into a render pass with a [`STENCIL_FORMAT`]
+/// stencil attachment, which is needed to paint with clip masks.
+fn stencil_pipeline<'a>(
+    cache: &'a mut PipelineCache,
+    device: &wgpu::Device,
+    surface: &SurfaceInfo<'_>,
+    pipeline: Pipeline,
+    write_mask: wgpu::ColorWrites,
+    depth_stencil: wgpu::DepthStencilState,
+) -> &'a wgpu::RenderPipeline {
+    cache.get(
+        device,
+        surface.format,
+        surface.sample_count,
+        |device, layout, format, samples| {
+            make_pipeline(
+                device,
+                layout,
+                format,
+                samples,
+                pipeline,
+                &builtin_shader(device, pipeline),
+                write_mask,
+                Some(depth_stencil),
+            )
+        },
+    )
+}
+
+/// A draw call along with the clip mask it needs.
+struct MaskedDrawCall {
+    clip: Rect,
+    /// A clip mask that needs to be written before drawing the call, if it
+    /// isn't already in the stencil buffer.
+    write_mask: Option<ClipMaskDraw>,
+    stencil_reference: u32,
+    draw_call: DrawCall,
+}
+
+/// The pipelines shared by the render passes of a frame.
+struct FramePipelines<'a> {
+    main: &'a wgpu::RenderPipeline,
+    text: &'a wgpu::RenderPipeline,
+    custom: PreparedCustomPipelines<'a>,
+    clear: Option<&'a wgpu::RenderPipeline>,
+    mask: Option<&'a wgpu::RenderPipeline>,
+}
+
 pub fn paint(
     yakui_wgpu: &mut YakuiWgpu,
     state: &mut Yakui,
@@ -607,7 +802,20 @@ pub fn paint(
     surface: SurfaceInfo<'_>,
 ) {
     // --- yakui ---
-    yakui_wgpu.set_paint_limits(state);
+    // Render targets are painted here, so they're only enabled for this
+    // renderer and not for renderers built from the parts of `YakuiWgpu`.
+    state.set_paint_limit(PaintLimits {
+        render_targets: true,
+        ..yakui_wgpu.limits
+    });
+    yakui_wgpu.stats = RenderStats::default();
+
+    // Targets are painted again if their texture was dropped, or doesn't match
+    // the surface anymore.
+    let render_targets = &mut yakui_wgpu.render_targets;
+    render_targets.retain_matching(surface.format);
+    state.retain_render_targets(|id| render_targets.contains(id));
+
     let paint = state.paint();
 
     yakui_wgpu.update_textures(device, paint, queue);
@@ -626,82 +834,301 @@ pub fn paint(
     yakui_wgpu.vertices.clear();
     yakui_wgpu.indices.clear();
     yakui_wgpu.texture_bindgroup_cache.clear();
+    yakui_wgpu.custom_pipelines.clear();
 
-    let mut draw_calls = Vec::with_capacity(layers.len());
-    // --- yakui ---
-
-    for (clip, call) in layers.iter().flat_map(|layer| &layer.calls) {
-        match call {
-            PaintCall::Internal(call) => {
-                draw_calls.push(yakui_wgpu.build_draw_call(device, *clip, call));
-            }
-            PaintCall::User(_) => {
-                panic!("yakui does not handle User PaintCall's by default. Please set up your own rendering logic instead.");
-            }
+    let surface_size = paint.surface_size().as_uvec2();
+    yakui_wgpu.render_targets.prepare(
+        device,
+        paint.render_targets(),
+        surface.format,
+        surface.sample_count,
+    );
+
+    // Render targets are drawn before anything that draws their texture.
+    // Targets without any calls keep their texture from the previous frame.
+    let target_calls: Vec<_> = paint
+        .render_targets()
+        .iter()
+        .filter_map(|target| {
+            let calls = target.calls.as_ref()?;
+            let size = render_target::target_size(target).as_vec2();
+            let calls = build_draw_calls(yakui_wgpu, device, size, calls);
+            Some((target.id, calls))
+        })
+        .collect();
+
+    let draw_calls = build_draw_calls(
+        yakui_wgpu,
+        device,
+        paint.surface_size(),
+        layers.iter().flat_map(|layer| &layer.calls),
+    );
+
+    let uniform_bytes = yakui_wgpu.custom_pipelines.uniforms_len();
+    let stats = &mut yakui_wgpu.stats;
+    for bytes in [
+        yakui_wgpu.vertices.byte_len(),
+        yakui_wgpu.indices.byte_len(),
+        uniform_bytes,
+    ] {
+        if bytes > 0 {
+            stats.buffer_uploads += 1;
+            stats.bytes_uploaded += bytes;
         }
     }
 
-    // --- yakui ---
     let vertices = yakui_wgpu.vertices.upload(device, queue);
     let indices = yakui_wgpu.indices.upload(device, queue);
+
+    // Clip masks need a stencil buffer, which is only used if any paint call
+    // needs one.
+    let use_stencil = target_calls
+        .iter()
+        .flat_map(|(_, calls)| calls)
+        .chain(&draw_calls)
+        .any(|call| call.write_mask.is_some());
+
+    let pipelines;
+    let stencil_view;
+    if use_stencil {
+        let clip_pipelines = &mut yakui_wgpu.clip_pipelines;
+        let content = clip_mask::content_stencil();
+        let all = wgpu::ColorWrites::ALL;
+        let none = wgpu::ColorWrites::empty();
+
+        pipelines = FramePipelines {
+            main: stencil_pipeline(
+                &mut clip_pipelines.main,
+                device,
+                &surface,
+                Pipeline::Main,
+                all,
+                content.clone(),
+            ),
+            text: stencil_pipeline(
+                &mut clip_pipelines.text,
+                device,
+                &surface,
+                Pipeline::Text,
+                all,
+                content.clone(),
+            ),
+            custom: yakui_wgpu
+                .custom_pipelines
+                .prepare(device, queue, &surface, Some(content)),
+            clear: Some(stencil_pipeline(
+                &mut clip_pipelines.clear,
+                device,
+                &surface,
+                Pipeline::Main,
+                none,
+                clip_mask::clear_stencil(),
+            )),
+            mask: Some(stencil_pipeline(
+                &mut clip_pipelines.mask,
+                device,
+                &surface,
+                Pipeline::Main,
+                none,
+                clip_mask::mask_stencil(),
+            )),
+        };
+        stencil_view = Some(
+            clip_pipelines
+                .stencil
+                .get(device, surface_size, surface.sample_count),
+        );
+        yakui_wgpu
+            .render_targets
+            .prepare_stencils(device, surface.sample_count);
+    } else {
+        pipelines = FramePipelines {
+            main: crate::main_pipeline(&mut yakui_wgpu.main_pipeline, device, &surface),
+            text: crate::text_pipeline(&mut yakui_wgpu.text_pipeline, device, &surface),
+            custom: yakui_wgpu
+                .custom_pipelines
+                .prepare(device, queue, &surface, None),
+            clear: None,
+            mask: None,
+        };
+        stencil_view = None;
+    }
+
+    // Each target is painted in a pass as large as its texture.
+    let surface_pass = TargetPass {
+        color_attachment: surface.color_attachment,
+        stencil: stencil_view,
+        size: surface_size,
+    };
+    let render_targets = &yakui_wgpu.render_targets;
+    let passes = target_calls
+        .into_iter()
+        .filter_map(|(id, calls)| Some((render_targets.pass(id, use_stencil)?, calls)))
+        .chain([(surface_pass, draw_calls)]);
     // --- yakui ---
 
-    {
+    for (pass, draw_calls) in passes {
         // --- yakui ---
-        let main_pipeline = main_pipeline(&mut yakui_wgpu.main_pipeline, device, &surface);
-        let text_pipeline = text_pipeline(&mut yakui_wgpu.text_pipeline, device, &surface);
+        let depth_stencil_attachment =
+            pass.stencil
+                .map(|view| wgpu::RenderPassDepthStencilAttachment {
+                    view,
+                    depth_ops: None,
+                    stencil_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(0),
+                        store: wgpu::StoreOp::Discard,
+                    }),
+                });
 
         let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
             label: Some("yakui Render Pass"),
-            color_attachments: &[Some(surface.color_attachment)],
+            color_attachments: &[Some(pass.color_attachment)],
+            depth_stencil_attachment,
             ..Default::default()
         });
 
         render_pass.set_vertex_buffer(0, vertices.slice(..));
         render_pass.set_index_buffer(indices.slice(..), wgpu::IndexFormat::Uint32);
 
-        let surface = paint.surface_size().as_uvec2();
+        let surface = pass.size;
         render_pass.set_viewport(0.0, 0.0, surface.x as f32, surface.y as f32, 0.0, 1.0);
+        // --- yakui ---
+
+        draw_pass(
+            &mut render_pass,
+            &pipelines,
+            &yakui_wgpu.texture_bindgroup_cache,
+            &mut yakui_wgpu.stats,
+            surface,
+            use_stencil,
+            draw_calls,
+        );
+    }
+}
+
+/// Builds the draw calls for a list of paint calls, along with the clip masks
+/// that need to be written for them.
+fn build_draw_calls<'a, I>(
+    yakui_wgpu: &mut YakuiWgpu,
+    device: &wgpu::Device,
+    surface_size: Vec2,
+    calls: I,
+) -> Vec<MaskedDrawCall>
+where
+    I: IntoIterator<Item = &'a PaintLayerCall>,
+{
+    let mut draw_calls = Vec::new();
+    let mut written_mask: Option<(Rect, &ClipMask)> = None;
+
+    for call in calls {
+        let mask = call.mask();
+        match call.call() {
+            PaintCall::Internal(internal) => {
+                let (clip, draw_call) = yakui_wgpu.build_draw_call(device, call.clip(), internal);
+
+                // The stencil buffer is only written to within the clip rect,
+                // so masks need to be written again whenever it changes.
+                let mut write_mask = None;
+                let mut stencil_reference = 0;
+                if let Some(mask) = mask {
+                    if written_mask != Some((clip, mask)) {
+                        written_mask = Some((clip, mask));
+                        write_mask = Some(yakui_wgpu.build_clip_mask(surface_size, clip, mask));
+                    }
+
+                    stencil_reference = mask.shapes().len() as u32;
+                }
+
+                draw_calls.push(MaskedDrawCall {
+                    clip,
+                    write_mask,
+                    stencil_reference,
+                    draw_call,
+                });
+            }
+            PaintCall::User(_) => {
+                panic!("yakui does not handle User PaintCall's by default. Please set up your own rendering logic instead.");
+            }
+        }
+    }
+
+    draw_calls
+}
+
+/// Draws a list of draw calls into a render pass.
+fn draw_pass(
+    render_pass: &mut wgpu::RenderPass<'_>,
+    pipelines: &FramePipelines<'_>,
+    texture_bindgroup_cache: &TextureBindgroupCache,
+    stats: &mut RenderStats,
+    surface: UVec2,
+    use_stencil: bool,
+    draw_calls: Vec<MaskedDrawCall>,
+) {
+    let mut last_clip = None;
+    let mut last_pipeline = None;
 
-        let mut last_clip = None;
+    for call in draw_calls {
         // --- yakui ---
+        let clip = call.clip;
+        if Some(clip) != last_clip {
+            last_clip = Some(clip);
 
-        for (clip, draw_call) in draw_calls {
-            // --- yakui ---
-            if Some(clip) != last_clip {
-                last_clip = Some(clip);
+            let pos = clip.pos().as_uvec2();
+            let size = clip.size().as_uvec2();
 
-                let surface = paint.surface_size().as_uvec2();
+            let max = (pos + size).min(surface);
+            let size = UVec2::new(max.x.saturating_sub(pos.x), max.y.saturating_sub(pos.y));
 
-                let pos = clip.pos().as_uvec2();
-                let size = clip.size().as_uvec2();
+            // If the scissor rect isn't valid, we can skip this
+            // entire draw call.
+            if pos.x > surface.x || pos.y > surface.y || size.x == 0 || size.y == 0 {
+                continue;
+            }
+
+            render_pass.set_scissor_rect(pos.x, pos.y, size.x, size.y);
+        }
 
-                let max = (pos + size).min(surface);
-                let size = UVec2::new(max.x.saturating_sub(pos.x), max.y.saturating_sub(pos.y));
+        if let (Some(mask), Some(clear_pipeline), Some(mask_pipeline)) =
+            (&call.write_mask, pipelines.clear, pipelines.mask)
+        {
+            YakuiWgpu::draw_clip_mask(
+                texture_bindgroup_cache,
+                render_pass,
+                clear_pipeline,
+                mask_pipeline,
+                mask,
+            );
 
-                // If the scissor rect isn't valid, we can skip this
-                // entire draw call.
-                if pos.x > surface.x || pos.y > surface.y || size.x == 0 || size.y == 0 {
-                    continue;
+            stats.draw_calls += 1 + mask.shape_ranges.len();
+            stats.pipeline_switches += 2;
+            last_pipeline = None;
+        }
+
+        if use_stencil {
+            render_pass.set_stencil_reference(call.stencil_reference);
+        }
+        // --- yakui ---
+
+        match call.draw_call {
+            DrawCall::Yakui(call) => {
+                stats.draw_calls += 1;
+                if last_pipeline != Some(call.pipeline) {
+                    last_pipeline = Some(call.pipeline);
+                    stats.pipeline_switches += 1;
                 }
 
-                render_pass.set_scissor_rect(pos.x, pos.y, size.x, size.y);
+                YakuiWgpu::draw_yakui(
+                    texture_bindgroup_cache,
+                    render_pass,
+                    pipelines.main,
+                    pipelines.text,
+                    &pipelines.custom,
+                    call,
+                );
             }
-            // --- yakui ---
-
-            match draw_call {
-                DrawCall::Yakui(call) => {
-                    YakuiWgpu::draw_yakui(
-                        &yakui_wgpu.texture_bindgroup_cache,
-                        &mut render_pass,
-                        main_pipeline,
-                        text_pipeline,
-                        call,
-                    );
-                }
-                DrawCall::User(_) => {
-                    panic!("yakui does not handle User PaintCall's by default. Please set up your own rendering logic instead.");
-                }
+            DrawCall::User(_) => {
+                panic!("yakui does not handle User PaintCall's by default. Please set up your own rendering logic instead.");
             }
         }
     }
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use glam::UVec2;
+use yakui_core::paint::RenderTarget;
+use yakui_core::WidgetId;
+
+use crate::clip_mask::StencilBuffer;
+
+/// The offscreen textures that [`RenderTarget`]s are painted into, which are
+/// kept for as long as their widgets keep using them.
+pub struct RenderTargets {
+    targets: HashMap<WidgetId, Target>,
+}
+
+/// The textures used to paint a single render target.
+struct Target {
+    texture: TargetTexture,
+
+    /// A texture that the target is painted into and resolved from when the
+    /// surface is multisampled.
+    multisampled: Option<TargetTexture>,
+
+    /// The stencil buffer that clip masks are written to while painting the
+    /// target, if any of them need one.
+    stencil: StencilBuffer,
+}
+
+struct TargetTexture {
+    view: wgpu::TextureView,
+    size: UVec2,
+    format: wgpu::TextureFormat,
+    samples: u32,
+}
+
+/// The attachments and size of the render pass that paints a render target.
+pub struct TargetPass<'a> {
+    pub color_attachment: wgpu::RenderPassColorAttachment<'a>,
+    pub stencil: Option<&'a wgpu::TextureView>,
+    pub size: UVec2,
+}
+
+impl Target {
+    fn new(device: &wgpu::Device, size: UVec2, format: wgpu::TextureFormat) -> Self {
+        Self {
+            texture: TargetTexture::new(device, size, format, 1),
+            multisampled: None,
+            stencil: StencilBuffer::new(),
+        }
+    }
+}
+
+impl TargetTexture {
+    fn new(device: &wgpu::Device, size: UVec2, format: wgpu::TextureFormat, samples: u32) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("yakui Render Target"),
+            size: wgpu::Extent3d {
+                width: size.x,
+                height: size.y,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: samples,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+
+        Self {
+            view: texture.create_view(&Default::default()),
+            size,
+            format,
+            samples,
+        }
+    }
+
+    fn matches(&self, size: UVec2, format: wgpu::TextureFormat, samples: u32) -> bool {
+        self.size == size && self.format == format && self.samples == samples
+    }
+}
+
+impl RenderTargets {
+    pub fn new() -> Self {
+        Self {
+            targets: HashMap::new(),
+        }
+    }
+
+    /// Drops the textures that don't match the format of the surface anymore.
+    pub fn retain_matching(&mut self, format: wgpu::TextureFormat) {
+        self.targets
+            .retain(|_, target| target.texture.format == format);
+    }
+
+    /// Tells whether the given target has a texture.
+    pub fn contains(&self, id: WidgetId) -> bool {
+        self.targets.contains_key(&id)
+    }
+
+    /// Makes sure that every target painted this frame has a texture matching
+    /// its size, and drops the textures of targets that aren't used anymore.
+    pub fn prepare(
+        &mut self,
+        device: &wgpu::Device,
+        targets: &[RenderTarget],
+        format: wgpu::TextureFormat,
+        samples: u32,
+    ) {
+        self.targets
+            .retain(|id, _| targets.iter().any(|target| target.id == *id));
+
+        for target in targets {
+            let size = target_size(target);
+
+            let existing = self
+                .targets
+                .entry(target.id)
+                .or_insert_with(|| Target::new(device, size, format));
+
+            if !existing.texture.matches(size, format, 1) {
+                *existing = Target::new(device, size, format);
+            }
+
+            if samples > 1 {
+                match &existing.multisampled {
+                    Some(texture) if texture.matches(size, format, samples) => (),
+                    _ => {
+                        let texture = TargetTexture::new(device, size, format, samples);
+                        existing.multisampled = Some(texture);
+                    }
+                }
+            } else {
+                existing.multisampled = None;
+            }
+        }
+    }
+
+    /// Makes sure that every target has a stencil buffer for clip masks to be
+    /// written to.
+    pub fn prepare_stencils(&mut self, device: &wgpu::Device, samples: u32) {
+        for target in self.targets.values_mut() {
+            target.stencil.get(device, target.texture.size, samples);
+        }
+    }
+
+    /// Returns the texture that the given target is painted into.
+    pub fn get(&self, id: WidgetId) -> Option<&wgpu::TextureView> {
+        self.targets.get(&id).map(|target| &target.texture.view)
+    }
+
+    /// Returns the attachments of a render pass that clears the texture of the
+    /// given target and paints into it.
+    pub fn pass(&self, id: WidgetId, use_stencil: bool) -> Option<TargetPass<'_>> {
+        let target = self.targets.get(&id)?;
+        let (view, resolve_target) = match &target.multisampled {
+            Some(multisampled) => (&multisampled.view, Some(&target.texture.view)),
+            None => (&target.texture.view, None),
+        };
+
+        let color_attachment = wgpu::RenderPassColorAttachment {
+            view,
+            depth_slice: None,
+            resolve_target,
+            ops: wgpu::Operations {
+                load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                store: wgpu::StoreOp::Store,
+            },
+        };
+
+        Some(TargetPass {
+            color_attachment,
+            stencil: use_stencil.then(|| target.stencil.current()).flatten(),
+            size: target.texture.size,
+        })
+    }
+}
+
+/// Returns the size of the texture that the given target is painted into.
+pub fn target_size(target: &RenderTarget) -> UVec2 {
+    target.rect.size().as_uvec2().max(UVec2::ONE)
+}
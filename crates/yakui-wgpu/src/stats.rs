@@ -0,0 +1,24 @@
+/// Counters for the work done by the renderer in the last call to
+/// [`YakuiWgpu::paint`][crate::YakuiWgpu::paint], returned by
+/// [`YakuiWgpu::stats`][crate::YakuiWgpu::stats].
+///
+/// Combined with [`PaintStats`][yakui_core::paint::PaintStats], these tell
+/// how much of a frame's cost comes from the UI itself and how much from
+/// getting it onto the GPU.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    /// The number of draw calls issued, including the ones writing clip masks.
+    pub draw_calls: usize,
+
+    /// How many times a different pipeline was bound.
+    pub pipeline_switches: usize,
+
+    /// How many vertex, index and uniform buffers were written to.
+    pub buffer_uploads: usize,
+
+    /// The number of bytes written to those buffers.
+    pub bytes_uploaded: usize,
+
+    /// How many yakui-managed textures were created or updated.
+    pub texture_uploads: usize,
+}
@@ -1,4 +1,5 @@
-use yakui_core::geometry::Color;
+use yakui_core::geometry::{Color, Rect};
+use yakui_core::paint::ClipShape;
 
 use crate::auto_builders;
 
@@ -112,4 +113,15 @@ impl BorderRadius {
             bottom_right: radius,
         }
     }
+
+    /// A clip shape matching a rectangle with these corner radii.
+    pub fn clip_shape(&self, rect: Rect) -> ClipShape {
+        ClipShape::RoundedRect {
+            rect,
+            top_left: self.top_left,
+            top_right: self.top_right,
+            bottom_left: self.bottom_left,
+            bottom_right: self.bottom_right,
+        }
+    }
 }
@@ -0,0 +1,46 @@
+use yakui_core::geometry::{Color, Vec2};
+
+use crate::auto_builders;
+
+/// A soft shadow cast by a box, like the CSS `box-shadow` property.
+///
+/// The shadow is the shape of the box moved by `offset` and grown by `spread`
+/// on every side, then blurred by `blur_radius`. The blur fades out over
+/// `blur_radius` on either side of the edge of the shadow.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoxShadow {
+    pub offset: Vec2,
+    pub blur_radius: f32,
+    pub spread: f32,
+    pub color: Color,
+}
+
+auto_builders!(BoxShadow {
+    offset: Vec2,
+    blur_radius: f32,
+    spread: f32,
+    color: Color,
+});
+
+impl BoxShadow {
+    pub const fn new(offset: Vec2, blur_radius: f32, spread: f32, color: Color) -> Self {
+        Self {
+            offset,
+            blur_radius,
+            spread,
+            color,
+        }
+    }
+
+    /// A subtle shadow below a box, suitable for floating UI like windows and
+    /// popups.
+    pub const fn floating() -> Self {
+        Self::new(Vec2::new(0.0, 4.0), 12.0, 0.0, Color::rgba(0, 0, 0, 128))
+    }
+}
+
+impl Default for BoxShadow {
+    fn default() -> Self {
+        Self::floating()
+    }
+}
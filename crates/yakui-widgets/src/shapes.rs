@@ -1,11 +1,18 @@
-use std::f32::consts::TAU;
+use std::f32::consts::{PI, SQRT_2, TAU};
 
-use yakui_core::geometry::{Color, Rect, Vec2};
-use yakui_core::paint::{PaintDom, PaintMesh, PaintRect, Vertex};
+use yakui_core::geometry::{Color, Rect, Vec2, Vec4};
+use yakui_core::paint::{Fill, PaintDom, PaintMesh, PaintRect, Vertex};
 use yakui_core::TextureId;
 
 use crate::auto_builders;
 use crate::border::{Border, BorderRadius};
+use crate::shadow::BoxShadow;
+
+mod fill;
+mod path;
+mod stroke;
+
+pub use self::path::*;
 
 pub fn cross(output: &mut PaintDom, rect: Rect, color: Color) {
     static POSITIONS: [[f32; 2]; 12] = [
@@ -110,9 +117,16 @@ pub struct Circle {
     pub center: Vec2,
     pub radius: f32,
     pub color: Color,
+
+    /// Whether the edge of the circle should fade out over about a physical
+    /// pixel, which smooths it out without needing multisampling.
+    pub anti_alias: bool,
 }
 
-auto_builders!(Circle { color: Color });
+auto_builders!(Circle {
+    color: Color,
+    anti_alias: bool,
+});
 
 impl Circle {
     pub fn new(center: Vec2, radius: f32) -> Self {
@@ -120,6 +134,7 @@ impl Circle {
             center,
             radius,
             color: Color::WHITE,
+            anti_alias: false,
         }
     }
 
@@ -136,6 +151,17 @@ impl Circle {
             vertices.push(Vertex::new(pos, [0.0, 0.0], color));
         }
 
+        if self.anti_alias {
+            let outline: Vec<Vec2> = vertices.iter().map(|vertex| vertex.position).collect();
+            let (mut vertices, indices) = fill::convex(&outline, 1.0 / output.scale_factor());
+            for vertex in &mut vertices {
+                vertex.color *= color;
+            }
+
+            output.add_mesh(PaintMesh::new(vertices, indices));
+            return;
+        }
+
         vertices.push(Vertex::new(self.center, [0.0, 0.0], color));
         let middle_vertex = (vertices.len() - 1) as u16;
 
@@ -169,26 +195,33 @@ const RECT_INDEX: [u16; 6] = [
 
 pub struct RoundedRectangle {
     pub rect: Rect,
-    pub color: Color,
+    pub color: Fill,
     pub texture: Option<(TextureId, Rect)>,
     pub radius: BorderRadius,
     pub border: Option<Border>,
+
+    /// Whether the edges of the rectangle should fade out over about a
+    /// physical pixel, which smooths out rounded corners without needing
+    /// multisampling.
+    pub anti_alias: bool,
 }
 
 auto_builders!(RoundedRectangle {
-    color: Color,
+    color: Fill,
     texture: Option<(TextureId, Rect)>,
     border: Option<Border>,
+    anti_alias: bool,
 });
 
 impl RoundedRectangle {
     pub fn new<T: Into<BorderRadius>>(rect: Rect, radius: T) -> Self {
         Self {
             rect,
-            color: Color::WHITE,
+            color: Color::WHITE.into(),
             texture: None,
             radius: radius.into(),
             border: None,
+            anti_alias: false,
         }
     }
 
@@ -231,15 +264,34 @@ impl RoundedRectangle {
         let bottom_left = bottom_left.min(max_radius);
         let bottom_right = bottom_right.min(max_radius);
 
-        // Fallback to a rectangle if the radius is too small.
-        if top_left < 1.0 && top_right < 1.0 && bottom_left < 1.0 && bottom_right < 1.0 {
-            let mut p = PaintRect::new(rect);
-            p.texture = self.texture;
-            p.color = self.color;
-            return p.add(output);
+        if self.anti_alias {
+            let radius = BorderRadius {
+                top_left,
+                top_right,
+                bottom_left,
+                bottom_right,
+            };
+            return self.add_anti_aliased(output, rect, radius);
         }
 
-        let color = self.color.to_linear();
+        let square = top_left < 1.0 && top_right < 1.0 && bottom_left < 1.0 && bottom_right < 1.0;
+
+        // Fallback to a rectangle if the radius is too small.
+        // Gradients still need a mesh, which is just the center rectangle.
+        let (top_left, top_right, bottom_left, bottom_right) = match self.color.as_solid() {
+            Some(color) if square => {
+                let mut p = PaintRect::new(rect);
+                p.texture = self.texture;
+                p.color = color;
+                return p.add(output);
+            }
+            None if square => (0.0, 0.0, 0.0, 0.0),
+            _ => (top_left, top_right, bottom_left, bottom_right),
+        };
+
+        // Gradients are applied to the finished mesh, so vertices start out
+        // white.
+        let color = Vec4::ONE;
 
         let max_radius = top_left.max(top_right).max(bottom_left).max(bottom_right);
         let slices = f32::ceil(TAU / 8.0 / f32::acos(1.0 - 0.2 / max_radius)) as u32;
@@ -270,7 +322,7 @@ impl RoundedRectangle {
                 .map(Vec2::from)
                 .map(|vert| create_vertex(vert * size + min));
 
-            let rect_indices = RECT_INDEX.map(|index| index + base_vertex as u16);
+            let rect_indices = RECT_INDEX.map(|index| index as u32 + base_vertex as u32);
 
             vertices.extend(rect_vertices);
             indices.extend(rect_indices);
@@ -343,9 +395,9 @@ impl RoundedRectangle {
                 vertices.push(create_vertex(center + offset));
 
                 indices.extend_from_slice(&[
-                    center_vertex as u16,
-                    (index - 1) as u16,
-                    index as u16,
+                    center_vertex as u32,
+                    (index - 1) as u32,
+                    index as u32,
                 ]);
             }
         };
@@ -375,6 +427,72 @@ impl RoundedRectangle {
             self.draw_border(output, border);
         }
 
+        self.color.apply(self.rect, &mut vertices, &mut indices);
+
+        let mut mesh = PaintMesh::new(vertices, indices);
+        mesh.texture = self.texture;
+        output.add_mesh(mesh);
+    }
+
+    fn add_anti_aliased(&self, output: &mut PaintDom, rect: Rect, radius: BorderRadius) {
+        let max_radius = radius
+            .top_left
+            .max(radius.top_right)
+            .max(radius.bottom_left)
+            .max(radius.bottom_right);
+        let slices = f32::ceil(TAU / 8.0 / f32::acos(1.0 - 0.2 / max_radius)).max(1.0) as u32;
+
+        let (min, max) = (rect.pos(), rect.max());
+        let corners = [
+            (
+                radius.top_right,
+                Vec2::new(max.x, min.y),
+                Vec2::new(-1.0, 1.0),
+            ),
+            (radius.top_left, min, Vec2::new(1.0, 1.0)),
+            (
+                radius.bottom_left,
+                Vec2::new(min.x, max.y),
+                Vec2::new(1.0, -1.0),
+            ),
+            (radius.bottom_right, max, Vec2::new(-1.0, -1.0)),
+        ];
+
+        let mut outline = Vec::with_capacity(4 * (slices as usize + 1));
+        for (i, (radius, corner, inward)) in corners.into_iter().enumerate() {
+            if radius <= 0.0 {
+                outline.push(corner);
+                continue;
+            }
+
+            let center = corner + inward * radius;
+            let start_angle = i as f32 * TAU / 4.0;
+            for slice in 0..=slices {
+                let angle = start_angle + slice as f32 / slices as f32 * TAU / 4.0;
+                outline.push(center + radius * Vec2::new(angle.cos(), -angle.sin()));
+            }
+        }
+
+        // Corners that take up a whole side meet the next corner at the same
+        // point.
+        outline.dedup_by(|a, b| a.distance_squared(*b) < MIN_DISTANCE_SQUARED);
+        if outline.len() > 1
+            && outline[0].distance_squared(outline[outline.len() - 1]) < MIN_DISTANCE_SQUARED
+        {
+            outline.pop();
+        }
+
+        let (mut vertices, mut indices) = fill::convex(&outline, 1.0 / output.scale_factor());
+
+        if let Some((_, texture_rect)) = self.texture {
+            let uv_factor = texture_rect.size() / rect.size();
+            for vertex in &mut vertices {
+                vertex.texcoord = (vertex.position - rect.pos()) * uv_factor + texture_rect.pos();
+            }
+        }
+
+        self.color.apply(self.rect, &mut vertices, &mut indices);
+
         let mut mesh = PaintMesh::new(vertices, indices);
         mesh.texture = self.texture;
         output.add_mesh(mesh);
@@ -383,7 +501,168 @@ impl RoundedRectangle {
     // Just draws a larger rectangle behind the main one... probably has issues with opacity?
     fn draw_border(&self, output: &mut PaintDom, border: &Border) {
         let mut border_shape = RoundedRectangle::new(self.rect, self.radius);
-        border_shape.color = border.color;
+        border_shape.color = border.color.into();
+        border_shape.anti_alias = self.anti_alias;
         border_shape.add(output);
     }
 }
+
+/// How many rings of vertices are used to approximate the falloff of a blurred
+/// shadow.
+const SHADOW_RINGS: u16 = 8;
+
+pub struct Shadow {
+    pub rect: Rect,
+    pub radius: BorderRadius,
+    pub shadow: BoxShadow,
+}
+
+impl Shadow {
+    pub fn new<T: Into<BorderRadius>>(rect: Rect, radius: T, shadow: BoxShadow) -> Self {
+        Self {
+            rect,
+            radius: radius.into(),
+            shadow,
+        }
+    }
+
+    pub fn add(&self, output: &mut PaintDom) {
+        let BoxShadow {
+            offset,
+            blur_radius,
+            spread,
+            color,
+        } = self.shadow;
+
+        let size = (self.rect.size() + Vec2::splat(spread * 2.0)).max(Vec2::ZERO);
+        let rect = Rect::from_pos_size(self.rect.pos() + offset - Vec2::splat(spread), size);
+
+        // Corners keep their shape as the shadow spreads, and like rounded
+        // rectangles can't be rounder than half of a side.
+        let max_radius = size.min_element() / 2.0;
+        let corner = |radius: f32| (radius + spread).clamp(0.0, max_radius);
+        let radius = BorderRadius {
+            top_left: corner(self.radius.top_left),
+            top_right: corner(self.radius.top_right),
+            bottom_left: corner(self.radius.bottom_left),
+            bottom_right: corner(self.radius.bottom_right),
+        };
+
+        if blur_radius <= 0.0 {
+            if size.min_element() > 0.0 {
+                RoundedRectangle::new(rect, radius).color(color).add(output);
+            }
+            return;
+        }
+
+        // The blur is a gaussian whose standard deviation is half of the blur
+        // radius, which matches how browsers draw shadows.
+        let sigma = blur_radius / 2.0;
+        let coverage = |distance: f32| 0.5 * (1.0 - erf(distance / (sigma * SQRT_2)));
+
+        // Shapes that are small compared to the blur never reach full opacity.
+        let peak = erf(size.x / 2.0 / (sigma * SQRT_2)) * erf(size.y / 2.0 / (sigma * SQRT_2));
+
+        // Each ring is the outline of the shadow grown or shrunk by some
+        // distance, and is colored by how much of the blurred shape covers it.
+        let inset = blur_radius.min(max_radius);
+        let distances = (0..=SHADOW_RINGS)
+            .map(|ring| -inset + (inset + blur_radius) * ring as f32 / SHADOW_RINGS as f32);
+
+        let largest_radius = radius
+            .top_left
+            .max(radius.top_right)
+            .max(radius.bottom_left)
+            .max(radius.bottom_right)
+            + blur_radius;
+        let slices = f32::ceil(TAU / 8.0 / f32::acos(1.0 - 0.2 / largest_radius)).max(1.0) as u16;
+
+        let (min, max) = (rect.pos(), rect.max());
+        let corners = [
+            (
+                radius.top_right,
+                Vec2::new(max.x, min.y),
+                Vec2::new(-1.0, 1.0),
+            ),
+            (radius.top_left, min, Vec2::new(1.0, 1.0)),
+            (
+                radius.bottom_left,
+                Vec2::new(min.x, max.y),
+                Vec2::new(1.0, -1.0),
+            ),
+            (radius.bottom_right, max, Vec2::new(-1.0, -1.0)),
+        ];
+
+        let ring_len = 4 * (slices + 1);
+        let mut vertices = Vec::with_capacity(1 + (SHADOW_RINGS + 1) as usize * ring_len as usize);
+        let mut indices = Vec::new();
+
+        let linear = color.to_linear();
+        let shaded = |alpha: f32| linear * Vec4::new(1.0, 1.0, 1.0, alpha * peak);
+
+        vertices.push(Vertex::new(
+            rect.center(),
+            [0.0, 0.0],
+            shaded(coverage(-inset)),
+        ));
+
+        for (ring, distance) in distances.enumerate() {
+            let alpha = if ring == SHADOW_RINGS as usize {
+                0.0
+            } else {
+                coverage(distance)
+            };
+
+            for (i, (radius, corner, inward)) in corners.into_iter().enumerate() {
+                let center = corner + inward * radius;
+                let start_angle = i as f32 * TAU / 4.0;
+
+                for slice in 0..=slices {
+                    let angle = start_angle + slice as f32 / slices as f32 * TAU / 4.0;
+
+                    // Shrinking a corner past its radius leaves a sharp corner.
+                    let pos = if radius + distance >= 0.0 {
+                        center + (radius + distance) * Vec2::new(angle.cos(), -angle.sin())
+                    } else {
+                        corner - inward * distance
+                    };
+
+                    vertices.push(Vertex::new(pos, [0.0, 0.0], shaded(alpha)));
+                }
+            }
+        }
+
+        for i in 0..ring_len {
+            let next = (i + 1) % ring_len;
+            indices.extend_from_slice(&[0, 1 + i, 1 + next]);
+        }
+
+        for ring in 0..SHADOW_RINGS {
+            let inner = 1 + ring * ring_len;
+            let outer = inner + ring_len;
+
+            for i in 0..ring_len {
+                let next = (i + 1) % ring_len;
+                indices.extend_from_slice(&[
+                    inner + i,
+                    outer + i,
+                    outer + next,
+                    inner + i,
+                    outer + next,
+                    inner + next,
+                ]);
+            }
+        }
+
+        output.add_mesh(PaintMesh::new(vertices, indices));
+    }
+}
+
+/// An approximation of the error function, accurate to within about 0.0001.
+fn erf(x: f32) -> f32 {
+    const A: f32 = 0.147;
+
+    let x2 = x * x;
+    let inner = -x2 * (4.0 / PI + A * x2) / (1.0 + A * x2);
+    x.signum() * (1.0 - inner.exp()).sqrt()
+}
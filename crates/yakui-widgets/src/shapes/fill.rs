@@ -0,0 +1,316 @@
+use lyon_tessellation::math::point;
+use lyon_tessellation::path::Path as LyonPath;
+use lyon_tessellation::{BuffersBuilder, FillOptions, FillTessellator, FillVertex, VertexBuffers};
+use yakui_core::geometry::Vec2;
+use yakui_core::paint::Vertex;
+
+use super::path::{blank_vertex, FillRule, Polyline, MIN_DISTANCE_SQUARED};
+
+/// The longest that the corners of an anti-aliased edge can be stretched, as a
+/// multiple of the feather. Sharper corners are blunted instead.
+const MAX_MITER: f32 = 4.0;
+
+/// Turns the given polylines into a mesh of their inside, treating every
+/// polyline as closed.
+///
+/// If `feather` is set, the edges of the mesh fade out over that distance,
+/// centered on the true edge.
+pub(crate) fn tessellate(
+    polylines: &[Polyline],
+    rule: FillRule,
+    feather: Option<f32>,
+) -> (Vec<Vertex>, Vec<u32>) {
+    let contours: Vec<&[Vec2]> = polylines
+        .iter()
+        .map(|polyline| polyline.points.as_slice())
+        .filter(|points| points.len() > 2)
+        .collect();
+
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    let Some(feather) = feather else {
+        fill_interior(contours.iter().copied(), rule, &mut vertices, &mut indices);
+        return (vertices, indices);
+    };
+
+    // Where contours cross, the fill can switch to the other side of an edge,
+    // so edges are split there first.
+    let split: Vec<Vec<Vec2>> = contours
+        .iter()
+        .map(|contour| split_at_crossings(contour, &contours))
+        .collect();
+    let contours: Vec<&[Vec2]> = split.iter().map(Vec::as_slice).collect();
+
+    // The inside is shrunk by half of the feather, and a strip fading from the
+    // shrunk edge to an edge grown by the same amount goes around it.
+    let fringes: Vec<Fringe> = contours
+        .iter()
+        .map(|contour| Fringe::new(contour, &contours, rule, feather))
+        .collect();
+
+    let inner: Vec<&[Vec2]> = fringes
+        .iter()
+        .map(|fringe| fringe.inner.as_slice())
+        .collect();
+    fill_interior(inner.into_iter(), rule, &mut vertices, &mut indices);
+
+    for fringe in &fringes {
+        let count = fringe.inner.len();
+
+        for i in 0..count {
+            if !fringe.edges[i] {
+                continue;
+            }
+
+            let next = (i + 1) % count;
+            let base = vertices.len() as u32;
+            vertices.extend_from_slice(&[
+                blank_vertex(fringe.inner[i], 1.0),
+                blank_vertex(fringe.outer[i], 0.0),
+                blank_vertex(fringe.outer[next], 0.0),
+                blank_vertex(fringe.inner[next], 1.0),
+            ]);
+            indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+        }
+    }
+
+    (vertices, indices)
+}
+
+/// Turns a convex outline into a mesh whose edges fade out over `feather`,
+/// centered on the true edge.
+///
+/// This is much cheaper than [`tessellate`], and is used for the built-in
+/// shapes, which are always convex.
+pub(crate) fn convex(outline: &[Vec2], feather: f32) -> (Vec<Vertex>, Vec<u32>) {
+    let count = outline.len();
+    if count < 3 {
+        return (Vec::new(), Vec::new());
+    }
+
+    // Outlines can go either way around, which decides which side of each
+    // edge is the outside.
+    let area: f32 = edges(outline).map(|(start, end)| start.perp_dot(end)).sum();
+    let outside = if area > 0.0 { -1.0 } else { 1.0 };
+
+    let normals: Vec<Vec2> = edges(outline)
+        .map(|(start, end)| (end - start).normalize_or_zero().perp() * outside)
+        .collect();
+
+    // The inside can't be shrunk past its middle, which would turn it inside
+    // out.
+    let (min, max) = outline
+        .iter()
+        .fold((outline[0], outline[0]), |(min, max), &point| {
+            (min.min(point), max.max(point))
+        });
+    let inset = (feather / 2.0).min((max - min).min_element() / 2.0);
+
+    let mut vertices = Vec::with_capacity(count * 2);
+    let mut indices = Vec::with_capacity((count - 2) * 3 + count * 6);
+
+    for (i, &point) in outline.iter().enumerate() {
+        let (before, after) = (normals[(i + count - 1) % count], normals[i]);
+        let miter = (before + after) / (1.0 + before.dot(after)).max(1e-3);
+        let offset = miter.clamp_length_max(MAX_MITER);
+
+        vertices.push(blank_vertex(point - offset * inset, 1.0));
+        vertices.push(blank_vertex(point + offset * feather / 2.0, 0.0));
+    }
+
+    for i in 1..count as u32 - 1 {
+        indices.extend_from_slice(&[0, i * 2, (i + 1) * 2]);
+    }
+
+    for i in 0..count as u32 {
+        let next = (i + 1) % count as u32;
+        let (inner, outer) = (i * 2, i * 2 + 1);
+        let (next_inner, next_outer) = (next * 2, next * 2 + 1);
+        indices.extend_from_slice(&[inner, outer, next_outer, inner, next_outer, next_inner]);
+    }
+
+    (vertices, indices)
+}
+
+/// The anti-aliased edge of one contour of a filled path.
+struct Fringe {
+    inner: Vec<Vec2>,
+    outer: Vec<Vec2>,
+
+    /// Whether each edge, starting at the point with the same index, is on the
+    /// boundary of the filled area. Edges with the fill on both sides or on
+    /// neither side don't fade out.
+    edges: Vec<bool>,
+}
+
+impl Fringe {
+    fn new(contour: &[Vec2], contours: &[&[Vec2]], rule: FillRule, feather: f32) -> Self {
+        let count = contour.len();
+
+        // Which way is outside of the fill can't be told from the contour
+        // alone, since it depends on the other contours and the fill rule.
+        let outward: Vec<Option<Vec2>> = (0..count)
+            .map(|i| {
+                let (start, end) = (contour[i], contour[(i + 1) % count]);
+                let left = (end - start).normalize().perp();
+                let middle = (start + end) / 2.0;
+                let probe = left * feather / 4.0;
+
+                match (
+                    is_inside(middle + probe, contours, rule),
+                    is_inside(middle - probe, contours, rule),
+                ) {
+                    (true, false) => Some(-left),
+                    (false, true) => Some(left),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        let mut inner = Vec::with_capacity(count);
+        let mut outer = Vec::with_capacity(count);
+
+        for (i, &point) in contour.iter().enumerate() {
+            let before = outward[(i + count - 1) % count];
+            let after = outward[i];
+
+            let offset = match (before, after) {
+                (Some(before), Some(after)) => {
+                    let miter = (before + after) / (1.0 + before.dot(after)).max(1e-3);
+                    miter.clamp_length_max(MAX_MITER)
+                }
+                (Some(normal), None) | (None, Some(normal)) => normal,
+                (None, None) => Vec2::ZERO,
+            };
+
+            inner.push(point - offset * feather / 2.0);
+            outer.push(point + offset * feather / 2.0);
+        }
+
+        Self {
+            inner,
+            outer,
+            edges: outward.iter().map(Option::is_some).collect(),
+        }
+    }
+}
+
+fn fill_interior<'a>(
+    contours: impl Iterator<Item = &'a [Vec2]>,
+    rule: FillRule,
+    vertices: &mut Vec<Vertex>,
+    indices: &mut Vec<u32>,
+) {
+    let mut builder = LyonPath::builder();
+    for contour in contours {
+        builder.begin(point(contour[0].x, contour[0].y));
+        for position in &contour[1..] {
+            builder.line_to(point(position.x, position.y));
+        }
+        builder.end(true);
+    }
+
+    let rule = match rule {
+        FillRule::NonZero => lyon_tessellation::FillRule::NonZero,
+        FillRule::EvenOdd => lyon_tessellation::FillRule::EvenOdd,
+    };
+
+    let mut buffers: VertexBuffers<Vec2, u32> = VertexBuffers::new();
+    let result = FillTessellator::new().tessellate_path(
+        &builder.build(),
+        &FillOptions::default().with_fill_rule(rule),
+        &mut BuffersBuilder::new(&mut buffers, |vertex: FillVertex| {
+            Vec2::from(vertex.position().to_array())
+        }),
+    );
+
+    if let Err(err) = result {
+        log::debug!("Could not fill path: {err:?}");
+        return;
+    }
+
+    let base = vertices.len() as u32;
+    vertices.extend(
+        buffers
+            .vertices
+            .into_iter()
+            .map(|position| blank_vertex(position, 1.0)),
+    );
+    indices.extend(buffers.indices.into_iter().map(|index| base + index));
+}
+
+/// Adds a point to the contour everywhere that it crosses itself or any of the
+/// other contours.
+fn split_at_crossings(contour: &[Vec2], contours: &[&[Vec2]]) -> Vec<Vec2> {
+    let mut split = Vec::with_capacity(contour.len());
+    let mut crossings = Vec::new();
+
+    for (start, end) in edges(contour) {
+        split.push(start);
+
+        crossings.clear();
+        for &other in contours {
+            for (other_start, other_end) in edges(other) {
+                if let Some(t) = crossing(start, end, other_start, other_end) {
+                    crossings.push(t);
+                }
+            }
+        }
+
+        crossings.sort_by(f32::total_cmp);
+        split.extend(crossings.iter().map(|&t| start.lerp(end, t)));
+    }
+
+    split.dedup_by(|a, b| a.distance_squared(*b) < MIN_DISTANCE_SQUARED);
+    split
+}
+
+/// Every edge of a contour, including the one closing it.
+fn edges(contour: &[Vec2]) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+    let count = contour.len();
+    (0..count).map(move |i| (contour[i], contour[(i + 1) % count]))
+}
+
+/// Where the line from `start` to `end` crosses another line, as a fraction of
+/// the way along it. Lines that only touch at their ends don't count.
+fn crossing(start: Vec2, end: Vec2, other_start: Vec2, other_end: Vec2) -> Option<f32> {
+    const EPSILON: f32 = 1e-5;
+
+    let (direction, other_direction) = (end - start, other_end - other_start);
+    let denominator = direction.perp_dot(other_direction);
+    if denominator.abs() < EPSILON {
+        return None;
+    }
+
+    let offset = other_start - start;
+    let t = offset.perp_dot(other_direction) / denominator;
+    let u = offset.perp_dot(direction) / denominator;
+
+    let inside = |t: f32| (EPSILON..=1.0 - EPSILON).contains(&t);
+    (inside(t) && (0.0..=1.0).contains(&u)).then_some(t)
+}
+
+/// Whether a point is inside of the area enclosed by the given contours.
+fn is_inside(point: Vec2, contours: &[&[Vec2]], rule: FillRule) -> bool {
+    let mut winding = 0;
+
+    for contour in contours {
+        for (start, end) in edges(contour) {
+            let side = (end - start).perp_dot(point - start);
+
+            if start.y <= point.y {
+                if end.y > point.y && side > 0.0 {
+                    winding += 1;
+                }
+            } else if end.y <= point.y && side < 0.0 {
+                winding -= 1;
+            }
+        }
+    }
+
+    match rule {
+        FillRule::NonZero => winding != 0,
+        FillRule::EvenOdd => winding % 2 != 0,
+    }
+}
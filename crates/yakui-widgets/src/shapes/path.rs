@@ -0,0 +1,441 @@
+use std::f32::consts::TAU;
+
+use yakui_core::geometry::{Rect, Vec2, Vec4};
+use yakui_core::paint::{Fill, PaintDom, PaintMesh, Vertex};
+
+use crate::auto_builders;
+
+use super::{fill, stroke};
+
+/// How far flattened curves may be from the true curve, in physical pixels.
+const CURVE_TOLERANCE: f32 = 0.25;
+
+/// Points closer together than this are merged when flattening a path, since
+/// the direction between them isn't reliable.
+pub(crate) const MIN_DISTANCE_SQUARED: f32 = 1e-8;
+
+/// The most line segments that a single curve is flattened into.
+const MAX_CURVE_SEGMENTS: u32 = 1024;
+
+/// A shape made out of lines and curves that can be filled or stroked.
+///
+/// A path is made of subpaths. Each subpath starts with [`move_to`][Self::move_to]
+/// and is optionally ended with [`close`][Self::close], which connects it back
+/// to its starting point. Positions are in logical pixels, like widget rects.
+///
+/// ```
+/// # let _yak = yakui_widgets::DocTest::start();
+/// use yakui::{shapes::Path, Vec2};
+///
+/// let mut path = Path::new();
+/// path.move_to(Vec2::new(0.0, 0.0))
+///     .line_to(Vec2::new(100.0, 0.0))
+///     .quad_to(Vec2::new(100.0, 100.0), Vec2::new(0.0, 100.0))
+///     .close();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Path {
+    segments: Vec<PathSegment>,
+}
+
+/// One piece of a [`Path`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[allow(missing_docs)]
+pub enum PathSegment {
+    /// Starts a new subpath at the given point.
+    MoveTo(Vec2),
+
+    /// A straight line to the given point.
+    LineTo(Vec2),
+
+    /// A quadratic bézier curve to `to`.
+    QuadTo { ctrl: Vec2, to: Vec2 },
+
+    /// A cubic bézier curve to `to`.
+    CubicTo { ctrl1: Vec2, ctrl2: Vec2, to: Vec2 },
+
+    /// An arc of a circle, starting at `start_angle` and turning `sweep`
+    /// radians. If a subpath is in progress, a line connects it to the start
+    /// of the arc.
+    Arc {
+        center: Vec2,
+        radius: f32,
+        start_angle: f32,
+        sweep: f32,
+    },
+
+    /// Connects the current subpath back to its starting point.
+    Close,
+}
+
+/// How to decide which parts of a path are inside of it when filling it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FillRule {
+    /// A point is inside if the path winds around it a different number of
+    /// times clockwise than counter-clockwise.
+    #[default]
+    NonZero,
+
+    /// A point is inside if a line from it crosses the path an odd number of
+    /// times.
+    EvenOdd,
+}
+
+/// The shape drawn where two segments of a stroked path meet.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LineJoin {
+    /// The outer edges are extended until they meet. Joins that would be longer
+    /// than the stroke's miter limit are beveled instead.
+    #[default]
+    Miter,
+
+    /// The outer corner is rounded.
+    Round,
+
+    /// The outer corner is cut off.
+    Bevel,
+}
+
+/// The shape drawn at the ends of open subpaths when they're stroked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LineCap {
+    /// The stroke ends exactly at the end of the subpath.
+    #[default]
+    Butt,
+
+    /// The stroke ends with a half circle.
+    Round,
+
+    /// The stroke is extended past the end of the subpath by half its width.
+    Square,
+}
+
+/// How to fill a [`Path`].
+#[derive(Debug, Clone)]
+pub struct FillStyle {
+    pub color: Fill,
+    pub rule: FillRule,
+    pub anti_alias: bool,
+}
+
+auto_builders!(FillStyle {
+    color: Fill,
+    rule: FillRule,
+    anti_alias: bool,
+});
+
+impl FillStyle {
+    pub fn new<T: Into<Fill>>(color: T) -> Self {
+        Self {
+            color: color.into(),
+            rule: FillRule::NonZero,
+            anti_alias: true,
+        }
+    }
+}
+
+/// How to stroke a [`Path`].
+///
+/// If `dashes` isn't empty, the stroke alternates between drawn and skipped
+/// pieces with those lengths, starting `dash_offset` into the pattern. Like in
+/// SVG, a pattern with an odd number of lengths is repeated to make it even.
+#[derive(Debug, Clone)]
+pub struct StrokeStyle {
+    pub width: f32,
+    pub color: Fill,
+    pub join: LineJoin,
+    pub cap: LineCap,
+    pub miter_limit: f32,
+    pub dashes: Vec<f32>,
+    pub dash_offset: f32,
+    pub anti_alias: bool,
+}
+
+auto_builders!(StrokeStyle {
+    width: f32,
+    color: Fill,
+    join: LineJoin,
+    cap: LineCap,
+    miter_limit: f32,
+    dashes: Vec<f32>,
+    dash_offset: f32,
+    anti_alias: bool,
+});
+
+impl StrokeStyle {
+    pub fn new<T: Into<Fill>>(width: f32, color: T) -> Self {
+        Self {
+            width,
+            color: color.into(),
+            join: LineJoin::Miter,
+            cap: LineCap::Butt,
+            miter_limit: 4.0,
+            dashes: Vec::new(),
+            dash_offset: 0.0,
+            anti_alias: true,
+        }
+    }
+}
+
+/// A subpath that has been turned into straight lines.
+#[derive(Debug, Clone)]
+pub(crate) struct Polyline {
+    pub points: Vec<Vec2>,
+    pub closed: bool,
+}
+
+impl Path {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The segments that make up the path.
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    pub fn move_to(&mut self, to: Vec2) -> &mut Self {
+        self.segments.push(PathSegment::MoveTo(to));
+        self
+    }
+
+    pub fn line_to(&mut self, to: Vec2) -> &mut Self {
+        self.segments.push(PathSegment::LineTo(to));
+        self
+    }
+
+    pub fn quad_to(&mut self, ctrl: Vec2, to: Vec2) -> &mut Self {
+        self.segments.push(PathSegment::QuadTo { ctrl, to });
+        self
+    }
+
+    pub fn cubic_to(&mut self, ctrl1: Vec2, ctrl2: Vec2, to: Vec2) -> &mut Self {
+        self.segments
+            .push(PathSegment::CubicTo { ctrl1, ctrl2, to });
+        self
+    }
+
+    /// Adds an arc of a circle. Angles are in radians, where 0 points right and
+    /// positive angles turn clockwise on screen.
+    ///
+    /// If a subpath is in progress, a straight line connects it to the start of
+    /// the arc. Otherwise, the arc starts a new subpath.
+    pub fn arc(&mut self, center: Vec2, radius: f32, start_angle: f32, sweep: f32) -> &mut Self {
+        self.segments.push(PathSegment::Arc {
+            center,
+            radius,
+            start_angle,
+            sweep,
+        });
+        self
+    }
+
+    pub fn close(&mut self) -> &mut Self {
+        self.segments.push(PathSegment::Close);
+        self
+    }
+
+    /// A closed path going around a circle.
+    pub fn circle(center: Vec2, radius: f32) -> Self {
+        let mut path = Self::new();
+        path.arc(center, radius, 0.0, TAU).close();
+        path
+    }
+
+    /// A closed path going around a rectangle.
+    pub fn rect(rect: Rect) -> Self {
+        let (min, max) = (rect.pos(), rect.max());
+
+        let mut path = Self::new();
+        path.move_to(min)
+            .line_to(Vec2::new(max.x, min.y))
+            .line_to(max)
+            .line_to(Vec2::new(min.x, max.y))
+            .close();
+        path
+    }
+
+    /// Fills the inside of the path.
+    pub fn fill(&self, output: &mut PaintDom, style: &FillStyle) {
+        output.add_mesh(self.fill_mesh(style, output.scale_factor()));
+    }
+
+    /// Draws a line along the path.
+    pub fn stroke(&self, output: &mut PaintDom, style: &StrokeStyle) {
+        output.add_mesh(self.stroke_mesh(style, output.scale_factor()));
+    }
+
+    /// Tessellates the inside of the path into a mesh. The scale factor is used
+    /// to decide how finely to flatten curves and how wide anti-aliased edges
+    /// should be.
+    pub fn fill_mesh(
+        &self,
+        style: &FillStyle,
+        scale_factor: f32,
+    ) -> PaintMesh<Vec<Vertex>, Vec<u32>> {
+        let polylines = self.flatten(CURVE_TOLERANCE / scale_factor);
+        let feather = style.anti_alias.then_some(1.0 / scale_factor);
+
+        let (mut vertices, mut indices) = fill::tessellate(&polylines, style.rule, feather);
+        style
+            .color
+            .apply(bounds(&polylines), &mut vertices, &mut indices);
+
+        PaintMesh::new(vertices, indices)
+    }
+
+    /// Tessellates a line along the path into a mesh. The scale factor is used
+    /// to decide how finely to flatten curves and how wide anti-aliased edges
+    /// should be.
+    pub fn stroke_mesh(
+        &self,
+        style: &StrokeStyle,
+        scale_factor: f32,
+    ) -> PaintMesh<Vec<Vertex>, Vec<u32>> {
+        let tolerance = CURVE_TOLERANCE / scale_factor;
+        let polylines = self.flatten(tolerance);
+        let feather = style.anti_alias.then_some(1.0 / scale_factor);
+
+        let (mut vertices, mut indices) = stroke::tessellate(&polylines, style, feather, tolerance);
+        style
+            .color
+            .apply(bounds(&polylines), &mut vertices, &mut indices);
+
+        PaintMesh::new(vertices, indices)
+    }
+
+    /// Turns every subpath into straight lines that are within `tolerance` of
+    /// the true curves.
+    pub(crate) fn flatten(&self, tolerance: f32) -> Vec<Polyline> {
+        let mut polylines = Vec::new();
+        let mut current: Vec<Vec2> = Vec::new();
+
+        // Subpaths made of only a move aren't drawn, but zero-length lines are,
+        // since they can still have caps.
+        let mut drawn = false;
+
+        let mut finish = |current: &mut Vec<Vec2>, drawn: &mut bool, closed: bool| {
+            let mut points = std::mem::take(current);
+            points.dedup_by(|a, b| a.distance_squared(*b) < MIN_DISTANCE_SQUARED);
+
+            if closed && points.len() > 1 {
+                let (first, last) = (points[0], points[points.len() - 1]);
+                if first.distance_squared(last) < MIN_DISTANCE_SQUARED {
+                    points.pop();
+                }
+            }
+
+            if std::mem::take(drawn) && !points.is_empty() {
+                polylines.push(Polyline { points, closed });
+            }
+        };
+
+        for segment in &self.segments {
+            let from = current.last().copied();
+
+            match *segment {
+                PathSegment::MoveTo(to) => {
+                    finish(&mut current, &mut drawn, false);
+                    current.push(to);
+                }
+
+                PathSegment::LineTo(to) => {
+                    current.push(to);
+                    drawn = true;
+                }
+
+                PathSegment::QuadTo { ctrl, to } => {
+                    let from = from.unwrap_or(ctrl);
+                    let deviation = (from - 2.0 * ctrl + to).length();
+                    let count = curve_segments(deviation / 4.0, tolerance);
+
+                    current.extend((1..=count).map(|i| {
+                        let t = i as f32 / count as f32;
+                        from.lerp(ctrl, t).lerp(ctrl.lerp(to, t), t)
+                    }));
+                    drawn = true;
+                }
+
+                PathSegment::CubicTo { ctrl1, ctrl2, to } => {
+                    let from = from.unwrap_or(ctrl1);
+                    let deviation = (from - 2.0 * ctrl1 + ctrl2)
+                        .length()
+                        .max((ctrl1 - 2.0 * ctrl2 + to).length());
+                    let count = curve_segments(deviation * 0.75, tolerance);
+
+                    current.extend((1..=count).map(|i| {
+                        let t = i as f32 / count as f32;
+                        let a = from.lerp(ctrl1, t).lerp(ctrl1.lerp(ctrl2, t), t);
+                        let b = ctrl1.lerp(ctrl2, t).lerp(ctrl2.lerp(to, t), t);
+                        a.lerp(b, t)
+                    }));
+                    drawn = true;
+                }
+
+                PathSegment::Arc {
+                    center,
+                    radius,
+                    start_angle,
+                    sweep,
+                } => {
+                    let count = arc_segments(radius, sweep.abs(), tolerance);
+
+                    current.extend((0..=count).map(|i| {
+                        let angle = start_angle + sweep * i as f32 / count as f32;
+                        center + radius * Vec2::new(angle.cos(), angle.sin())
+                    }));
+                    drawn = true;
+                }
+
+                PathSegment::Close => {
+                    let start = current.first().copied();
+                    finish(&mut current, &mut drawn, true);
+
+                    // Like other vector APIs, drawing after closing a subpath
+                    // continues from its starting point.
+                    current.extend(start);
+                }
+            }
+        }
+
+        finish(&mut current, &mut drawn, false);
+        polylines
+    }
+}
+
+/// A vertex that will be colored by a [`Fill`] later, with the given alpha.
+pub(crate) fn blank_vertex(position: Vec2, alpha: f32) -> Vertex {
+    Vertex::new(position, Vec2::ZERO, Vec4::new(1.0, 1.0, 1.0, alpha))
+}
+
+/// How many straight lines a curve needs to stay within `tolerance` of the
+/// true curve, given how far its control points are from a straight line.
+fn curve_segments(deviation: f32, tolerance: f32) -> u32 {
+    let count = (deviation / tolerance).sqrt().ceil();
+    (count as u32).clamp(1, MAX_CURVE_SEGMENTS)
+}
+
+/// How many straight lines an arc needs to stay within `tolerance` of the true
+/// arc.
+pub(crate) fn arc_segments(radius: f32, angle: f32, tolerance: f32) -> u32 {
+    if radius <= tolerance {
+        return 1;
+    }
+
+    let max_step = 2.0 * f32::acos(1.0 - tolerance / radius);
+    ((angle / max_step).ceil() as u32).clamp(1, MAX_CURVE_SEGMENTS)
+}
+
+/// The rect containing every point of the given polylines, which gradients are
+/// positioned relative to.
+fn bounds(polylines: &[Polyline]) -> Rect {
+    let points = polylines.iter().flat_map(|polyline| &polyline.points);
+    let min = points.clone().copied().fold(Vec2::INFINITY, Vec2::min);
+    let max = points.copied().fold(Vec2::NEG_INFINITY, Vec2::max);
+
+    if min.cmpgt(max).any() {
+        return Rect::ZERO;
+    }
+
+    Rect::from_pos_size(min, max - min)
+}
@@ -0,0 +1,452 @@
+use std::f32::consts::FRAC_PI_2;
+
+use yakui_core::geometry::Vec2;
+use yakui_core::paint::Vertex;
+
+use super::path::{
+    arc_segments, blank_vertex, LineCap, LineJoin, Polyline, StrokeStyle, MIN_DISTANCE_SQUARED,
+};
+
+/// Turns the given polylines into a mesh of a line along them.
+///
+/// If `feather` is set, the edges of the line fade out over that distance,
+/// centered on the true edge.
+pub(crate) fn tessellate(
+    polylines: &[Polyline],
+    style: &StrokeStyle,
+    feather: Option<f32>,
+    tolerance: f32,
+) -> (Vec<Vertex>, Vec<u32>) {
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    let feather = feather.unwrap_or(0.0);
+    let mut width = style.width;
+    let mut alpha = 1.0;
+
+    if width <= 0.0 {
+        return (vertices, indices);
+    }
+
+    // Lines thinner than the feather can't fade out on both sides, so they're
+    // drawn as wide as the feather and made more transparent instead.
+    if width < feather {
+        alpha = width / feather;
+        width = feather;
+    }
+
+    let mut stroker = Stroker {
+        style,
+        half_width: width / 2.0,
+        tolerance,
+        strip: Strip {
+            vertices: &mut vertices,
+            indices: &mut indices,
+            feather,
+            alpha,
+            previous: None,
+        },
+    };
+
+    let dashes = dash_pattern(&style.dashes);
+
+    for polyline in polylines {
+        if let Some(dashes) = &dashes {
+            for points in dash(polyline, dashes, style.dash_offset) {
+                stroker.open(&points);
+            }
+        } else if polyline.closed && polyline.points.len() > 2 {
+            stroker.closed(&polyline.points);
+        } else if polyline.closed {
+            let mut points = polyline.points.clone();
+            points.push(points[0]);
+            stroker.open(&points);
+        } else {
+            stroker.open(&polyline.points);
+        }
+    }
+
+    (vertices, indices)
+}
+
+/// A cross-section of a stroke, going from its left edge to its right edge.
+/// Each edge has a direction pointing away from the stroke, which anti-aliased
+/// edges fade out along.
+#[derive(Debug, Clone, Copy)]
+struct Section {
+    left: Vec2,
+    left_normal: Vec2,
+    right: Vec2,
+    right_normal: Vec2,
+    alpha: f32,
+}
+
+impl Section {
+    fn new(center: Vec2, normal: Vec2, half_width: f32) -> Self {
+        Self {
+            left: center + normal * half_width,
+            left_normal: normal,
+            right: center - normal * half_width,
+            right_normal: -normal,
+            alpha: 1.0,
+        }
+    }
+
+    fn alpha(self, alpha: f32) -> Self {
+        Self { alpha, ..self }
+    }
+
+    /// The same section, as seen from a line heading the other way.
+    fn flipped(self) -> Self {
+        Self {
+            left: self.right,
+            left_normal: self.right_normal,
+            right: self.left,
+            right_normal: self.left_normal,
+            alpha: self.alpha,
+        }
+    }
+}
+
+/// Connects consecutive sections of a stroke with triangles.
+struct Strip<'a> {
+    vertices: &'a mut Vec<Vertex>,
+    indices: &'a mut Vec<u32>,
+    feather: f32,
+    alpha: f32,
+    previous: Option<u32>,
+}
+
+impl Strip<'_> {
+    fn push(&mut self, section: Section) {
+        let base = self.vertices.len() as u32;
+        let alpha = section.alpha * self.alpha;
+
+        let columns = if self.feather > 0.0 {
+            let offset = self.feather / 2.0;
+
+            self.vertices.extend_from_slice(&[
+                blank_vertex(section.left + section.left_normal * offset, 0.0),
+                blank_vertex(section.left - section.left_normal * offset, alpha),
+                blank_vertex(section.right - section.right_normal * offset, alpha),
+                blank_vertex(section.right + section.right_normal * offset, 0.0),
+            ]);
+            4
+        } else {
+            self.vertices.extend_from_slice(&[
+                blank_vertex(section.left, alpha),
+                blank_vertex(section.right, alpha),
+            ]);
+            2
+        };
+
+        if let Some(previous) = self.previous {
+            for column in 0..columns - 1 {
+                let (a, b) = (previous + column, previous + column + 1);
+                let (c, d) = (base + column + 1, base + column);
+                self.indices.extend_from_slice(&[a, b, c, a, c, d]);
+            }
+        }
+
+        self.previous = Some(base);
+    }
+
+    fn end(&mut self) {
+        self.previous = None;
+    }
+}
+
+struct Stroker<'a, 'b> {
+    style: &'a StrokeStyle,
+    half_width: f32,
+    tolerance: f32,
+    strip: Strip<'b>,
+}
+
+impl Stroker<'_, '_> {
+    fn open(&mut self, points: &[Vec2]) {
+        let (first, last) = match points {
+            [] => return,
+
+            // Zero-length lines are only visible because of their caps, which
+            // face right by convention.
+            [point] => {
+                if self.style.cap == LineCap::Butt {
+                    return;
+                }
+
+                ((*point, Vec2::X), (*point, Vec2::X))
+            }
+
+            _ => {
+                let start = (points[0], (points[1] - points[0]).normalize());
+                let end = points.len() - 1;
+                let end = (points[end], (points[end] - points[end - 1]).normalize());
+                (start, end)
+            }
+        };
+
+        self.start_cap(first.0, first.1);
+
+        for window in points.windows(3) {
+            for section in self.join(window[0], window[1], window[2]) {
+                self.strip.push(section);
+            }
+        }
+
+        self.end_cap(last.0, last.1);
+        self.strip.end();
+    }
+
+    fn closed(&mut self, points: &[Vec2]) {
+        let count = points.len();
+        let first_join = self.join(points[count - 1], points[0], points[1]);
+
+        for &section in &first_join {
+            self.strip.push(section);
+        }
+
+        for i in 1..count {
+            let next = points[(i + 1) % count];
+            for section in self.join(points[i - 1], points[i], next) {
+                self.strip.push(section);
+            }
+        }
+
+        self.strip.push(first_join[0]);
+        self.strip.end();
+    }
+
+    fn start_cap(&mut self, point: Vec2, direction: Vec2) {
+        for section in self.cap(point, -direction).into_iter().rev() {
+            self.strip.push(section.flipped());
+        }
+    }
+
+    fn end_cap(&mut self, point: Vec2, direction: Vec2) {
+        for section in self.cap(point, direction) {
+            self.strip.push(section);
+        }
+    }
+
+    /// The sections of a cap facing `outward`, ordered from the end of the
+    /// line outwards. The sections face as if the line was heading outward.
+    fn cap(&self, point: Vec2, outward: Vec2) -> Vec<Section> {
+        let half_width = self.half_width;
+        let normal = outward.perp();
+
+        match self.style.cap {
+            LineCap::Butt | LineCap::Square => {
+                let end = if self.style.cap == LineCap::Square {
+                    point + outward * half_width
+                } else {
+                    point
+                };
+
+                if self.strip.feather > 0.0 {
+                    // The end of the line fades out just like its sides.
+                    let offset = outward * self.strip.feather / 2.0;
+                    vec![
+                        Section::new(end - offset, normal, half_width),
+                        Section::new(end + offset, normal, half_width).alpha(0.0),
+                    ]
+                } else {
+                    vec![Section::new(end, normal, half_width)]
+                }
+            }
+
+            LineCap::Round => {
+                let count = arc_segments(half_width, FRAC_PI_2, self.tolerance);
+
+                // Each section is a chord of the half circle, going from the
+                // widest one to the tip.
+                (0..=count)
+                    .map(|i| {
+                        let angle = FRAC_PI_2 * i as f32 / count as f32;
+                        let (sin, cos) = angle.sin_cos();
+                        let left = normal * cos + outward * sin;
+                        let right = -normal * cos + outward * sin;
+
+                        Section {
+                            left: point + left * half_width,
+                            left_normal: left,
+                            right: point + right * half_width,
+                            right_normal: right,
+                            alpha: 1.0,
+                        }
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// The sections where the line from `before` to `point` turns towards
+    /// `after`.
+    fn join(&self, before: Vec2, point: Vec2, after: Vec2) -> Vec<Section> {
+        let half_width = self.half_width;
+
+        let (incoming, outgoing) = (point - before, after - point);
+        let (length_in, length_out) = (incoming.length(), outgoing.length());
+        let (incoming, outgoing) = (incoming / length_in, outgoing / length_out);
+        let (normal_in, normal_out) = (incoming.perp(), outgoing.perp());
+
+        let cross = incoming.perp_dot(outgoing);
+        let dot = incoming.dot(outgoing);
+
+        if cross.abs() < 1e-6 && dot > 0.0 {
+            return vec![Section::new(point, normal_in, half_width)];
+        }
+
+        // Turning all the way around leaves no corner to fill in.
+        if dot < -0.9999 {
+            return vec![
+                Section::new(point, normal_in, half_width),
+                Section::new(point, normal_out, half_width),
+            ];
+        }
+
+        // The stroke turns towards its left side if `cross` is positive, which
+        // makes the left side the inside of the corner.
+        let inner_side = cross.signum();
+        let miter = (normal_in + normal_out) / (1.0 + dot);
+
+        // On short segments, the inner corner could reach past the other end of
+        // the segments, so it's kept within them.
+        let max_inner = (half_width * half_width + length_in.min(length_out).powi(2)).sqrt();
+        let inner_offset = (miter.length() * half_width).min(max_inner);
+        let inner_normal = miter.normalize() * inner_side;
+        let inner = point + inner_normal * inner_offset;
+
+        let section = |outer: Vec2, outer_normal: Vec2| {
+            if inner_side > 0.0 {
+                Section {
+                    left: inner,
+                    left_normal: inner_normal,
+                    right: outer,
+                    right_normal: outer_normal,
+                    alpha: 1.0,
+                }
+            } else {
+                Section {
+                    left: outer,
+                    left_normal: outer_normal,
+                    right: inner,
+                    right_normal: inner_normal,
+                    alpha: 1.0,
+                }
+            }
+        };
+
+        let outer_in = -normal_in * inner_side;
+        let outer_out = -normal_out * inner_side;
+
+        match self.style.join {
+            LineJoin::Miter if miter.length() <= self.style.miter_limit => {
+                let outer_normal = -inner_normal;
+                vec![section(
+                    point + miter * half_width * -inner_side,
+                    outer_normal,
+                )]
+            }
+
+            LineJoin::Round => {
+                let angle = dot.clamp(-1.0, 1.0).acos();
+                let count = arc_segments(half_width, angle, self.tolerance);
+                let turn = outer_in.perp_dot(outer_out).signum();
+
+                (0..=count)
+                    .map(|i| {
+                        let rotation = Vec2::from_angle(turn * angle * i as f32 / count as f32);
+                        let normal = rotation.rotate(outer_in);
+                        section(point + normal * half_width, normal)
+                    })
+                    .collect()
+            }
+
+            LineJoin::Miter | LineJoin::Bevel => vec![
+                section(point + outer_in * half_width, outer_in),
+                section(point + outer_out * half_width, outer_out),
+            ],
+        }
+    }
+}
+
+/// Normalizes a dash pattern, returning `None` if the line shouldn't be
+/// dashed.
+fn dash_pattern(dashes: &[f32]) -> Option<Vec<f32>> {
+    let total: f32 = dashes.iter().sum();
+    if dashes.is_empty() || total <= 0.0 || dashes.iter().any(|&dash| dash < 0.0) {
+        return None;
+    }
+
+    let mut pattern = dashes.to_vec();
+    if pattern.len() % 2 == 1 {
+        pattern.extend_from_slice(dashes);
+    }
+
+    Some(pattern)
+}
+
+/// Splits a polyline into the pieces that are drawn by a dash pattern.
+fn dash(polyline: &Polyline, dashes: &[f32], offset: f32) -> Vec<Vec<Vec2>> {
+    let mut points = polyline.points.clone();
+    if polyline.closed {
+        points.push(points[0]);
+    }
+
+    // Find where in the pattern the line starts.
+    let total: f32 = dashes.iter().sum();
+    let mut offset = offset.rem_euclid(total);
+    let mut index = 0;
+    for _ in 0..dashes.len() {
+        if offset < dashes[index] {
+            break;
+        }
+
+        offset -= dashes[index];
+        index = (index + 1) % dashes.len();
+    }
+    let mut remaining = dashes[index] - offset;
+
+    let mut pieces = Vec::new();
+    let mut current = Vec::new();
+    if index % 2 == 0 {
+        current.push(points[0]);
+    }
+
+    for segment in points.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+        let length = start.distance(end);
+        let mut travelled = 0.0;
+
+        while length - travelled >= remaining {
+            travelled += remaining;
+            let point = start.lerp(end, travelled / length);
+
+            if index % 2 == 0 {
+                current.push(point);
+                pieces.push(std::mem::take(&mut current));
+            } else {
+                current.push(point);
+            }
+
+            index = (index + 1) % dashes.len();
+            remaining = dashes[index];
+        }
+
+        remaining -= length - travelled;
+        if index % 2 == 0 {
+            current.push(end);
+        }
+    }
+
+    if index % 2 == 0 && current.len() > 1 {
+        pieces.push(current);
+    }
+
+    for piece in &mut pieces {
+        piece.dedup_by(|a, b| a.distance_squared(*b) < MIN_DISTANCE_SQUARED);
+    }
+
+    pieces
+}
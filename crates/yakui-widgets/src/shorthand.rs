@@ -4,19 +4,23 @@
 //! widget if its defaults don't work for you.
 
 use std::borrow::Cow;
+use std::hash::Hash;
 
 use yakui_core::geometry::{Color, Constraints, Dim2, Vec2};
+use yakui_core::paint::Fill;
 use yakui_core::widget::PaintContext;
-use yakui_core::{Alignment, ManagedTextureId, Pivot, Response, TextureId};
+use yakui_core::{Alignment, LayoutDirection, ManagedTextureId, Pivot, Response, TextureId};
 
 use crate::widgets::{
-    Align, AlignResponse, Button, ButtonResponse, Canvas, CanvasResponse, Checkbox,
-    CheckboxResponse, Circle, CircleResponse, ColoredBox, ColoredBoxResponse, ConstrainedBox,
-    ConstrainedBoxResponse, CountGrid, Divider, DividerResponse, Draggable, DraggableResponse,
-    Flexible, FlexibleResponse, Image, ImageResponse, List, ListResponse, MaxWidth,
-    MaxWidthResponse, NineSlice, Offset, OffsetResponse, Opaque, OpaqueResponse, Pad, PadResponse,
-    Reflow, ReflowResponse, Scrollable, ScrollableResponse, Slider, SliderResponse, Spacer, Stack,
-    StackResponse, State, StateResponse, Text, TextBox, TextBoxResponse, TextResponse,
+    Align, AlignResponse, AspectRatio, AspectRatioResponse, Button, ButtonResponse, Cached,
+    CachedResponse, Canvas, CanvasResponse, Checkbox, CheckboxResponse, Circle, CircleResponse,
+    ColoredBox, ColoredBoxResponse, ConstrainedBox, ConstrainedBoxResponse, CountGrid, Divider,
+    DividerResponse, Draggable, DraggableResponse, Flexible, FlexibleResponse, FractionallySized,
+    FractionallySizedResponse, Image, ImageResponse, List, ListResponse, MaxWidth,
+    MaxWidthResponse, NineSlice, Offset, OffsetResponse, Opacity, OpacityResponse, Opaque,
+    OpaqueResponse, Pad, PadResponse, Reflow, ReflowResponse, Scope, ScopeResponse, Scrollable,
+    ScrollableResponse, Slider, SliderResponse, Spacer, Stack, StackResponse, State, StateResponse,
+    Text, TextBox, TextBoxResponse, TextResponse, Wrap, WrapResponse,
 };
 
 /// See [List].
@@ -31,6 +35,12 @@ pub fn row<F: FnOnce()>(children: F) -> Response<ListResponse> {
     List::row().show(children)
 }
 
+/// See [Wrap].
+#[track_caller]
+pub fn wrap<F: FnOnce()>(children: F) -> Response<WrapResponse> {
+    Wrap::row().show(children)
+}
+
 /// See [CountGrid].
 #[track_caller]
 pub fn countgrid_column<F: FnOnce()>(n_columns: usize, children: F) -> Response<ListResponse> {
@@ -69,14 +79,17 @@ pub fn colored_circle(color: Color, size: f32) -> Response<CircleResponse> {
 
 /// See [ColoredBox].
 #[track_caller]
-pub fn colored_box<S: Into<Vec2>>(color: Color, size: S) -> Response<ColoredBoxResponse> {
+pub fn colored_box<C: Into<Fill>, S: Into<Vec2>>(
+    color: C,
+    size: S,
+) -> Response<ColoredBoxResponse> {
     ColoredBox::sized(color, size.into()).show()
 }
 
 /// See [ColoredBox].
 #[track_caller]
-pub fn colored_box_container<F: FnOnce()>(
-    color: Color,
+pub fn colored_box_container<C: Into<Fill>, F: FnOnce()>(
+    color: C,
     children: F,
 ) -> Response<ColoredBoxResponse> {
     ColoredBox::container(color).show_children(children)
@@ -137,6 +150,21 @@ pub fn constrained<F: FnOnce()>(
     ConstrainedBox::new(constraints).show(children)
 }
 
+/// See [AspectRatio].
+#[track_caller]
+pub fn aspect_ratio<F: FnOnce()>(aspect_ratio: f32, children: F) -> Response<AspectRatioResponse> {
+    AspectRatio::new(aspect_ratio).show(children)
+}
+
+/// See [FractionallySized].
+#[track_caller]
+pub fn fractionally_sized<F: FnOnce()>(
+    size: Dim2,
+    children: F,
+) -> Response<FractionallySizedResponse> {
+    FractionallySized::new(size).show(children)
+}
+
 /// See [Checkbox].
 #[track_caller]
 pub fn checkbox(checked: bool) -> Response<CheckboxResponse> {
@@ -149,6 +177,18 @@ pub fn offset<F: FnOnce()>(offset: Vec2, children: F) -> Response<OffsetResponse
     Offset::new(offset).show(children)
 }
 
+/// See [Cached].
+#[track_caller]
+pub fn cached<K: Hash, F: FnOnce()>(key: K, children: F) -> Response<CachedResponse> {
+    Cached::new(key).show(children)
+}
+
+/// See [Opacity].
+#[track_caller]
+pub fn opacity<F: FnOnce()>(opacity: f32, children: F) -> Response<OpacityResponse> {
+    Opacity::new(opacity).show(children)
+}
+
 /// See [Draggable].
 #[track_caller]
 pub fn draggable<F: FnOnce()>(children: F) -> Response<DraggableResponse> {
@@ -225,6 +265,15 @@ pub fn stack(children: impl FnOnce()) -> Response<StackResponse> {
     Stack::new().show(children)
 }
 
+/// Lays out the given children in the given [LayoutDirection]. See [Scope].
+#[track_caller]
+pub fn layout_direction(
+    direction: LayoutDirection,
+    children: impl FnOnce(),
+) -> Response<ScopeResponse> {
+    Scope::new(direction).show(children)
+}
+
 #[track_caller]
 pub fn use_state<F, T: 'static>(default: F) -> Response<StateResponse<T>>
 where
@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 
 use yakui_core::geometry::Color;
+use yakui_core::LayoutDirection;
 
 use crate::auto_builders;
 
@@ -203,12 +204,26 @@ pub enum TextAlignment {
     End,
 }
 
+impl TextAlignment {
+    /// Converts this alignment into the alignment used by cosmic-text when
+    /// laying out text in the given direction.
+    ///
+    /// When laying out left to right, `Start` and `End` follow the bidi
+    /// direction of each paragraph, so right-to-left paragraphs start on the
+    /// right. When laying out right to left, all text starts on the right.
+    pub fn to_cosmic(self, direction: LayoutDirection) -> Option<cosmic_text::Align> {
+        match (self, direction) {
+            (TextAlignment::Start, LayoutDirection::LeftToRight) => None,
+            (TextAlignment::Start, LayoutDirection::RightToLeft) => Some(cosmic_text::Align::Right),
+            (TextAlignment::Center, _) => Some(cosmic_text::Align::Center),
+            (TextAlignment::End, LayoutDirection::LeftToRight) => Some(cosmic_text::Align::End),
+            (TextAlignment::End, LayoutDirection::RightToLeft) => Some(cosmic_text::Align::Left),
+        }
+    }
+}
+
 impl From<TextAlignment> for Option<cosmic_text::Align> {
     fn from(value: TextAlignment) -> Self {
-        match value {
-            TextAlignment::Start => None,
-            TextAlignment::Center => Some(cosmic_text::Align::Center),
-            TextAlignment::End => Some(cosmic_text::Align::End),
-        }
+        value.to_cosmic(LayoutDirection::LeftToRight)
     }
 }
@@ -2,7 +2,7 @@ use std::rc::Rc;
 
 use yakui_core::context;
 use yakui_core::widget::Widget;
-use yakui_core::Response;
+use yakui_core::{LayoutDirection, Response};
 
 /// Show a widget with the given children and props.
 #[track_caller]
@@ -34,6 +34,115 @@ pub fn read_scope<T: 'static>() -> Option<Rc<T>> {
     dom.dynamic_scope().get(current)
 }
 
+/// Returns the [`LayoutDirection`] set by the closest enclosing
+/// [`Scope`][crate::widgets::Scope], or left-to-right if there isn't one.
+///
+/// Like [`read_scope`], this must be called while widgets are being built.
+pub fn current_layout_direction() -> LayoutDirection {
+    read_scope::<LayoutDirection>()
+        .map(|direction| *direction)
+        .unwrap_or_default()
+}
+
+/// Returns how far a scrolling viewport spanning `viewport_start` to
+/// `viewport_end` needs to scroll along one axis to show the span from `start`
+/// to `end`. If the span doesn't fit, its start is shown.
+pub(crate) fn scroll_into_view(
+    viewport_start: f32,
+    viewport_end: f32,
+    start: f32,
+    end: f32,
+) -> f32 {
+    if start < viewport_start {
+        start - viewport_start
+    } else if end > viewport_end {
+        (end - viewport_end).min(start - viewport_start)
+    } else {
+        0.0
+    }
+}
+
+/// The longest time between two clicks, in seconds, for them to count as a
+/// double click.
+const DOUBLE_CLICK_TIME: f32 = 0.5;
+
+/// Detects double clicks for widgets. [`DoubleClick::advance`] must be called
+/// every frame with [`Dom::delta_time`][yakui_core::dom::Dom::delta_time].
+#[derive(Debug, Default)]
+pub(crate) struct DoubleClick {
+    since_last_click: Option<f32>,
+}
+
+impl DoubleClick {
+    pub fn advance(&mut self, delta_time: f32) {
+        if let Some(time) = &mut self.since_last_click {
+            *time += delta_time;
+        }
+    }
+
+    /// Forgets about the previous click, like when it turned into a drag.
+    pub fn cancel(&mut self) {
+        self.since_last_click = None;
+    }
+
+    /// Records a click, returning whether it completed a double click.
+    pub fn click(&mut self) -> bool {
+        match self.since_last_click.take() {
+            Some(time) if time <= DOUBLE_CLICK_TIME => true,
+            _ => {
+                self.since_last_click = Some(0.0);
+                false
+            }
+        }
+    }
+}
+
+/// How two panes laid out along one axis share the space left over by the
+/// divider between them, as used by [`Splitter`][crate::widgets::Splitter] and
+/// [`DockArea`][crate::widgets::DockArea].
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct SplitSizes {
+    /// The space available to both panes, excluding the divider.
+    pub available: f32,
+    /// The size of the first pane, rounded to whole pixels.
+    pub first: f32,
+}
+
+impl SplitSizes {
+    /// Splits `main` into a divider `thickness` thick and two panes, giving the
+    /// first pane `ratio` of the space. Neither pane is made smaller than
+    /// `min_size`, unless there isn't enough space for both. A NaN ratio
+    /// splits the space evenly.
+    pub fn new(main: f32, thickness: f32, ratio: f32, min_size: f32) -> Self {
+        let ratio = if ratio.is_nan() { 0.5 } else { ratio };
+
+        let available = (main - thickness).max(0.0);
+        let mut first = available * ratio.clamp(0.0, 1.0);
+        if available >= min_size * 2.0 {
+            first = first.clamp(min_size, available - min_size);
+        }
+
+        Self {
+            available,
+            first: first.round(),
+        }
+    }
+
+    pub fn second(&self) -> f32 {
+        self.available - self.first
+    }
+
+    /// Returns the ratio that gives the first pane the given size, like when
+    /// the divider is dragged, or `None` if there's no space to divide.
+    pub fn ratio_for(&self, first: f32) -> Option<f32> {
+        if self.available > 0.0 {
+            Some((first / self.available).clamp(0.0, 1.0))
+        } else {
+            None
+        }
+    }
+}
+
 /// See also: https://github.com/rust-lang/rust/issues/154024
 #[macro_export]
 macro_rules! builtin_auto_builder {
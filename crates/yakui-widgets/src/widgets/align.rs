@@ -2,7 +2,7 @@ use yakui_core::geometry::{Constraints, Vec2};
 use yakui_core::widget::{LayoutContext, PaintContext, Widget};
 use yakui_core::{Alignment, Response};
 
-use crate::util::widget_children;
+use crate::util::{current_layout_direction, widget_children};
 
 /**
 Aligns its child according to the given alignment. Align should contain only one
@@ -73,7 +73,9 @@ impl Widget for AlignWidget {
     }
 
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
-        self.props = props;
+        self.props = Align {
+            alignment: props.alignment.resolve(current_layout_direction()),
+        };
     }
 
     fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
@@ -99,6 +101,7 @@ impl Widget for AlignWidget {
                 .set_pos(child, align * self_size - align * child_size);
         }
 
+        ctx.layout.propagate_baseline(ctx.dom, &node.children);
         self_size
     }
 
@@ -0,0 +1,138 @@
+use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::widget::{IntrinsicContext, LayoutContext, Widget};
+use yakui_core::{IntrinsicSize, Response};
+
+use crate::util::widget_children;
+
+/**
+A box that sizes itself to keep a specific aspect ratio, expressed as width
+divided by height, while fitting within the incoming constraints. Its children
+are given tight constraints matching that size.
+
+If both axes are unbounded, the box uses its children's preferred width.
+
+Responds with [AspectRatioResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+# use yakui::Color;
+yakui::aspect_ratio(16.0 / 9.0, || {
+    yakui::colored_box_container(Color::RED, || {});
+});
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct AspectRatio {
+    pub aspect_ratio: f32,
+}
+
+impl AspectRatio {
+    pub fn new(aspect_ratio: f32) -> Self {
+        Self { aspect_ratio }
+    }
+
+    #[track_caller]
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<AspectRatioResponse> {
+        widget_children::<AspectRatioWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct AspectRatioWidget {
+    props: AspectRatio,
+}
+
+pub type AspectRatioResponse = ();
+
+impl AspectRatioWidget {
+    fn aspect_ratio(&self) -> f32 {
+        let aspect_ratio = self.props.aspect_ratio;
+        if aspect_ratio.is_normal() && aspect_ratio > 0.0 {
+            aspect_ratio
+        } else {
+            1.0
+        }
+    }
+}
+
+impl Widget for AspectRatioWidget {
+    type Props<'a> = AspectRatio;
+    type Response = AspectRatioResponse;
+
+    fn new() -> Self {
+        Self {
+            props: AspectRatio::new(1.0),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    // This approach to layout is based on Flutter's RenderAspectRatio.
+    fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        let aspect_ratio = self.aspect_ratio();
+
+        let size = if input.is_tight() {
+            input.min
+        } else {
+            let (mut width, mut height) = if input.max.x.is_finite() {
+                (input.max.x, input.max.x / aspect_ratio)
+            } else if input.max.y.is_finite() {
+                (input.max.y * aspect_ratio, input.max.y)
+            } else {
+                let width = node
+                    .children
+                    .iter()
+                    .map(|&child| ctx.intrinsic_size(child, IntrinsicSize::MaxWidth, f32::INFINITY))
+                    .fold(0.0, f32::max);
+
+                (width, width / aspect_ratio)
+            };
+
+            if width > input.max.x {
+                width = input.max.x;
+                height = width / aspect_ratio;
+            }
+
+            if height > input.max.y {
+                height = input.max.y;
+                width = height * aspect_ratio;
+            }
+
+            if width < input.min.x {
+                width = input.min.x;
+                height = width / aspect_ratio;
+            }
+
+            if height < input.min.y {
+                height = input.min.y;
+                width = height * aspect_ratio;
+            }
+
+            input.constrain(Vec2::new(width, height))
+        };
+
+        for &child in &node.children {
+            ctx.calculate_layout(child, Constraints::tight(size));
+        }
+
+        ctx.layout.propagate_baseline(ctx.dom, &node.children);
+        size
+    }
+
+    fn intrinsic_size(&self, ctx: IntrinsicContext<'_>, kind: IntrinsicSize, extent: f32) -> f32 {
+        if extent.is_finite() {
+            if kind.is_width() {
+                extent * self.aspect_ratio()
+            } else {
+                extent / self.aspect_ratio()
+            }
+        } else {
+            self.default_intrinsic_size(ctx, kind, extent)
+        }
+    }
+}
@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
 use yakui_core::geometry::Color;
 use yakui_core::input::MouseButton;
+use yakui_core::paint::Fill;
 use yakui_core::widget::{EventContext, Widget};
 use yakui_core::{Alignment, Response};
 
@@ -53,13 +54,13 @@ auto_builders!(Button {
 #[derive(Debug, Clone)]
 pub struct DynamicButtonStyle {
     pub text: TextStyle,
-    pub fill: Color,
+    pub fill: Fill,
     pub border: Option<Border>,
 }
 
 auto_builders!(DynamicButtonStyle {
     text: TextStyle,
-    fill: Color,
+    fill: Fill,
     border: Option<Border>,
 });
 
@@ -67,7 +68,7 @@ impl Default for DynamicButtonStyle {
     fn default() -> Self {
         Self {
             text: TextStyle::label().align(TextAlignment::Center),
-            fill: Color::GRAY,
+            fill: Color::GRAY.into(),
             border: None,
         }
     }
@@ -88,7 +89,7 @@ impl Button {
 
     pub fn styled(text: impl Into<Cow<'static, str>>) -> Self {
         let style = DynamicButtonStyle {
-            fill: colors::BACKGROUND_3,
+            fill: colors::BACKGROUND_3.into(),
             text: TextStyle::label()
                 .color(Color::WHITE.adjust(0.6))
                 .align(TextAlignment::Center),
@@ -96,13 +97,13 @@ impl Button {
         };
 
         let hover_style = DynamicButtonStyle {
-            fill: colors::BACKGROUND_3.adjust(1.2),
+            fill: colors::BACKGROUND_3.adjust(1.2).into(),
             border: Some(Border::new(Color::WHITE.adjust(0.75), 1.0)),
             ..Default::default()
         };
 
         let down_style = DynamicButtonStyle {
-            fill: colors::BACKGROUND_3.adjust(0.8),
+            fill: colors::BACKGROUND_3.adjust(0.8).into(),
             border: Some(Border::new(Color::WHITE, 1.0)),
             ..Default::default()
         };
@@ -154,18 +155,18 @@ impl Widget for ButtonWidget {
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
         self.props = props;
 
-        let mut color = self.props.style.fill;
+        let mut color = &self.props.style.fill;
         let mut border = self.props.style.border;
         let mut text_style = &self.props.style.text;
 
         if self.mouse_down {
             let style = &self.props.down_style;
-            color = style.fill;
+            color = &style.fill;
             text_style = &style.text;
             border = style.border;
         } else if self.hovering {
             let style = &self.props.hover_style;
-            color = style.fill;
+            color = &style.fill;
             text_style = &style.text;
             border = style.border;
         }
@@ -178,14 +179,14 @@ impl Widget for ButtonWidget {
 
         let container = RoundRect::new(self.props.border_radius)
             .border(border)
-            .color(color);
+            .color(color.clone());
         container.show_children(|| {
             crate::pad(self.props.padding, || {
                 crate::align(align, || {
                     RenderText::new()
                         .inline(true)
                         .min_width(self.props.text_min_width)
-                        .show(&self.props.text, &text_style);
+                        .show(&self.props.text, text_style);
                 });
             });
         });
@@ -0,0 +1,152 @@
+use std::cell::Cell;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use yakui_core::dom::Dom;
+use yakui_core::layout::LayoutDom;
+use yakui_core::widget::{PaintContext, Widget};
+use yakui_core::{Response, WidgetId};
+
+use crate::util::widget_children;
+
+/**
+Paints its children into an offscreen texture once, then draws that texture in
+later frames instead of painting them again, which saves the work of painting
+complex panels that rarely change.
+
+The children are painted again whenever `key` changes, or the layout of any of
+them changes. Nothing else about them is compared, so everything else that
+affects how they look, like their colors, their text or whether a button among
+them is hovered, has to be part of the key. Children that animate on their own
+won't update while cached.
+
+Caching needs a renderer that supports render targets; otherwise, the children
+are painted directly every frame.
+
+Responds with [CachedResponse].
+
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::Cached;
+
+let items = ["Apples", "Oranges", "Pears"];
+
+Cached::new(items).show(|| {
+    yakui::column(|| {
+        for item in items {
+            yakui::text(16.0, item);
+        }
+    });
+});
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Cached {
+    /// A hash of everything that affects how the children look.
+    pub key: u64,
+}
+
+impl Cached {
+    /// Creates a `Cached` that paints its children again whenever the given
+    /// key hashes differently.
+    pub fn new<K: Hash>(key: K) -> Self {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+
+        Self {
+            key: hasher.finish(),
+        }
+    }
+
+    #[track_caller]
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<CachedResponse> {
+        widget_children::<CachedWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct CachedWidget {
+    props: Cached,
+
+    /// A hash of the key and the layout of the children that were last painted
+    /// into the texture, if they were.
+    painted: Cell<Option<u64>>,
+}
+
+pub type CachedResponse = ();
+
+impl Widget for CachedWidget {
+    type Props<'a> = Cached;
+    type Response = CachedResponse;
+
+    fn new() -> Self {
+        Self {
+            props: Cached { key: 0 },
+            painted: Cell::new(None),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        if !ctx.paint.render_targets_supported() {
+            self.painted.set(None);
+            self.default_paint(ctx);
+            return;
+        }
+
+        let id = ctx.dom.current();
+
+        let mut hasher = DefaultHasher::new();
+        self.props.key.hash(&mut hasher);
+        ctx.paint.scale_factor().to_bits().hash(&mut hasher);
+        hash_layout(ctx.dom, ctx.layout, id, &mut hasher);
+        let state = hasher.finish();
+
+        if self.painted.get() == Some(state) && ctx.paint.reuse_render_target(id, 1.0) {
+            return;
+        }
+
+        ctx.paint.push_render_target(id);
+
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            ctx.paint(child);
+        }
+
+        ctx.paint.pop_render_target(1.0);
+        self.painted.set(Some(state));
+    }
+}
+
+/// Hashes the layout of a widget and all of its descendants.
+fn hash_layout(dom: &Dom, layout: &LayoutDom, id: WidgetId, hasher: &mut DefaultHasher) {
+    id.hash(hasher);
+
+    if let Some(node) = layout.get(id) {
+        let rects = [
+            node.rect.pos(),
+            node.rect.size(),
+            node.clip.pos(),
+            node.clip.size(),
+        ];
+        let transform = node.transform.map(|transform| transform.to_cols_array());
+
+        for value in rects.iter().flat_map(|v| v.to_array()) {
+            value.to_bits().hash(hasher);
+        }
+
+        for value in transform.iter().flatten() {
+            value.to_bits().hash(hasher);
+        }
+    }
+
+    if let Some(node) = dom.get(id) {
+        for &child in &node.children {
+            hash_layout(dom, layout, child, hasher);
+        }
+    }
+}
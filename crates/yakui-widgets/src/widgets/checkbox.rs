@@ -1,8 +1,8 @@
 use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
 use yakui_core::geometry::{Constraints, Vec2};
 use yakui_core::input::MouseButton;
-use yakui_core::widget::{EventContext, LayoutContext, PaintContext, Widget};
-use yakui_core::Response;
+use yakui_core::widget::{EventContext, IntrinsicContext, LayoutContext, PaintContext, Widget};
+use yakui_core::{IntrinsicSize, Response};
 
 use crate::shapes::RoundedRectangle;
 use crate::{colors, shapes};
@@ -98,6 +98,15 @@ impl Widget for CheckboxWidget {
         constraints.constrain_min(Vec2::splat(OUTER_SIZE))
     }
 
+    fn intrinsic_size(
+        &self,
+        _ctx: IntrinsicContext<'_>,
+        _kind: IntrinsicSize,
+        _extent: f32,
+    ) -> f32 {
+        OUTER_SIZE
+    }
+
     fn event_interest(&self) -> EventInterest {
         EventInterest::MOUSE_INSIDE | EventInterest::MOUSE_OUTSIDE
     }
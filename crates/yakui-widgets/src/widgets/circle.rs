@@ -1,6 +1,7 @@
 use yakui_core::geometry::{Color, Constraints, Vec2};
-use yakui_core::widget::{LayoutContext, PaintContext, Widget};
-use yakui_core::Response;
+use yakui_core::paint::ClipShape;
+use yakui_core::widget::{IntrinsicContext, LayoutContext, PaintContext, Widget};
+use yakui_core::{IntrinsicSize, Response};
 
 use crate::util::{widget, widget_children};
 use crate::{auto_builders, shapes};
@@ -8,6 +9,10 @@ use crate::{auto_builders, shapes};
 /**
 A colored circle that can contain children.
 
+Setting `clip` clips the children to the circle, which is useful for things
+like round avatars. Setting `anti_alias` smooths out the edge of the circle without
+multisampling.
+
 Responds with [CircleResponse].
 */
 #[derive(Debug, Clone)]
@@ -15,11 +20,15 @@ Responds with [CircleResponse].
 pub struct Circle {
     pub color: Color,
     pub min_radius: f32,
+    pub clip: bool,
+    pub anti_alias: bool,
 }
 
 auto_builders!(Circle {
     color: Color,
     min_radius: f32,
+    clip: bool,
+    anti_alias: bool,
 });
 
 impl Circle {
@@ -27,6 +36,8 @@ impl Circle {
         Self {
             color: Color::WHITE,
             min_radius: 0.0,
+            clip: false,
+            anti_alias: false,
         }
     }
 
@@ -63,6 +74,10 @@ impl Widget for CircleWidget {
     }
 
     fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        if self.props.clip {
+            ctx.layout.enable_clipping(ctx.dom);
+        }
+
         let node = ctx.dom.get_current();
         let mut size = Vec2::splat(self.props.min_radius);
 
@@ -74,6 +89,11 @@ impl Widget for CircleWidget {
         input.constrain_min(size)
     }
 
+    fn intrinsic_size(&self, ctx: IntrinsicContext<'_>, kind: IntrinsicSize, extent: f32) -> f32 {
+        let size = self.default_intrinsic_size(ctx, kind, extent);
+        size.max(self.props.min_radius)
+    }
+
     fn paint(&self, mut ctx: PaintContext<'_>) {
         let node = ctx.dom.get_current();
         let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
@@ -83,10 +103,19 @@ impl Widget for CircleWidget {
 
         shapes::Circle::new(center, radius)
             .color(self.props.color)
+            .anti_alias(self.props.anti_alias)
             .add(ctx.paint);
 
+        if self.props.clip {
+            ctx.paint.push_clip_shape(ClipShape::circle(center, radius));
+        }
+
         for &child in &node.children {
             ctx.paint(child);
         }
+
+        if self.props.clip {
+            ctx.paint.pop_clip_shape();
+        }
     }
 }
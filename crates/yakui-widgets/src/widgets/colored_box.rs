@@ -1,47 +1,56 @@
 use yakui_core::geometry::{Color, Constraints, Vec2};
-use yakui_core::paint::PaintRect;
-use yakui_core::widget::{LayoutContext, PaintContext, Widget};
-use yakui_core::Response;
+use yakui_core::paint::Fill;
+use yakui_core::widget::{IntrinsicContext, LayoutContext, PaintContext, Widget};
+use yakui_core::{IntrinsicSize, Response};
 
-use crate::auto_builders;
+use crate::shadow::BoxShadow;
 use crate::util::{widget, widget_children};
+use crate::{auto_builders, shapes};
 
 /**
 A colored box that can contain children.
 
+The box can be filled with a gradient as well as a single color, and can cast
+a [BoxShadow].
+
 Responds with [ColoredBoxResponse].
 */
 #[derive(Debug, Clone)]
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct ColoredBox {
-    pub color: Color,
+    pub color: Fill,
     pub min_size: Vec2,
+    pub shadow: Option<BoxShadow>,
 }
 
 auto_builders!(ColoredBox {
-    color: Color,
+    color: Fill,
     min_size: Vec2,
+    shadow: Option<BoxShadow>,
 });
 
 impl ColoredBox {
     pub fn empty() -> Self {
         Self {
-            color: Color::WHITE,
+            color: Color::WHITE.into(),
             min_size: Vec2::ZERO,
+            shadow: None,
         }
     }
 
-    pub fn sized(color: Color, size: Vec2) -> Self {
+    pub fn sized<T: Into<Fill>>(color: T, size: Vec2) -> Self {
         Self {
-            color,
+            color: color.into(),
             min_size: size,
+            shadow: None,
         }
     }
 
-    pub fn container(color: Color) -> Self {
+    pub fn container<T: Into<Fill>>(color: T) -> Self {
         Self {
-            color,
+            color: color.into(),
             min_size: Vec2::ZERO,
+            shadow: None,
         }
     }
 
@@ -86,16 +95,26 @@ impl Widget for ColoredBoxWidget {
             size = size.max(child_size);
         }
 
+        ctx.layout.propagate_baseline(ctx.dom, &node.children);
         input.constrain_min(size)
     }
 
+    fn intrinsic_size(&self, ctx: IntrinsicContext<'_>, kind: IntrinsicSize, extent: f32) -> f32 {
+        let size = self.default_intrinsic_size(ctx, kind, extent);
+        size.max(kind.get(self.props.min_size))
+    }
+
     fn paint(&self, mut ctx: PaintContext<'_>) {
         let node = ctx.dom.get_current();
         let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
 
-        let mut rect = PaintRect::new(layout_node.rect);
-        rect.color = self.props.color;
-        rect.add(ctx.paint);
+        if let Some(shadow) = self.props.shadow {
+            shapes::Shadow::new(layout_node.rect, 0.0, shadow).add(ctx.paint);
+        }
+
+        shapes::RoundedRectangle::new(layout_node.rect, 0.0)
+            .color(self.props.color.clone())
+            .add(ctx.paint);
 
         for &child in &node.children {
             ctx.paint(child);
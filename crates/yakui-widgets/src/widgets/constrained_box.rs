@@ -1,6 +1,6 @@
 use yakui_core::geometry::{Constraints, Vec2};
-use yakui_core::widget::{LayoutContext, Widget};
-use yakui_core::Response;
+use yakui_core::widget::{IntrinsicContext, LayoutContext, Widget};
+use yakui_core::{IntrinsicSize, Response};
 
 use crate::util::widget_children;
 
@@ -63,6 +63,17 @@ impl Widget for ConstrainedBoxWidget {
             size = size.max(child_size);
         }
 
+        ctx.layout.propagate_baseline(ctx.dom, &node.children);
         input.constrain(constraints.constrain(size))
     }
+
+    fn intrinsic_size(&self, ctx: IntrinsicContext<'_>, kind: IntrinsicSize, extent: f32) -> f32 {
+        let min = self.props.constraints.min;
+        let max = self.props.constraints.max;
+
+        let extent = extent.max(kind.get_extent(min)).min(kind.get_extent(max));
+        let size = self.default_intrinsic_size(ctx, kind, extent);
+
+        size.max(kind.get(min)).min(kind.get(max))
+    }
 }
@@ -174,6 +174,33 @@ impl Widget for CountGridWidget {
             max_sizes[cross_id] = max_sizes[cross_id].max(cross_size);
         }
 
+        // When aligning by baseline, each row is made tall enough to fit its
+        // children once their baselines have been lined up. Only grids laid
+        // out left to right have rows along the cross axis.
+        let align_baselines = direction == Direction::Right
+            && self.props.cross_axis_alignment == CrossAxisAlignment::Baseline;
+        let mut ascents = Vec::new();
+        if align_baselines {
+            let mut descents = vec![0.0f32; n_cross];
+            ascents.resize(n_cross, 0.0f32);
+
+            for (i, &child_id) in node.children.iter().enumerate() {
+                let Some(baseline) = ctx.layout.baseline(child_id) else {
+                    continue;
+                };
+
+                let height = ctx.layout.get(child_id).unwrap().rect.size().y;
+                let cross_id = i % n_cross;
+                ascents[cross_id] = ascents[cross_id].max(baseline);
+                descents[cross_id] = descents[cross_id].max(height - baseline);
+            }
+
+            for cross_id in 0..n_cross {
+                let baseline_size = ascents[cross_id] + descents[cross_id];
+                max_sizes[cross_id] = max_sizes[cross_id].max(baseline_size);
+            }
+        }
+
         // We keep track of the final size of each axis to apply alignment later + total grid size
         // + set the positions without more allocations
         let mut total_main_size: f32 = 0.0;
@@ -218,7 +245,9 @@ impl Widget for CountGridWidget {
 
         // only used in case the widget total cross is less than the minimum cross axis
         let offset_cross_global = match self.props.cross_axis_alignment {
-            CrossAxisAlignment::Start | CrossAxisAlignment::Stretch => 0.0,
+            CrossAxisAlignment::Start
+            | CrossAxisAlignment::Stretch
+            | CrossAxisAlignment::Baseline => 0.0,
             CrossAxisAlignment::Center => {
                 ((direction.get_cross_axis(input.min) - max_total_cross_size) / 2.0).max(0.0)
             }
@@ -232,6 +261,7 @@ impl Widget for CountGridWidget {
             let cross_id = i % n_cross;
             let main_id = i / n_cross;
 
+            let child_baseline = ctx.layout.baseline(child_id);
             let layout = ctx.layout.get_mut(child_id).unwrap();
 
             let child_cross_size = direction.get_cross_axis(layout.rect.size());
@@ -243,6 +273,10 @@ impl Widget for CountGridWidget {
                 CrossAxisAlignment::Start | CrossAxisAlignment::Stretch => 0.0,
                 CrossAxisAlignment::Center => ((cell_cross_size - child_cross_size) / 2.0).max(0.0),
                 CrossAxisAlignment::End => (cell_cross_size - child_cross_size).max(0.0),
+                CrossAxisAlignment::Baseline => match child_baseline {
+                    Some(baseline) if align_baselines => ascents[cross_id] - baseline,
+                    _ => 0.0,
+                },
             };
 
             let child_main_size = direction.get_main_axis(layout.rect.size());
@@ -264,6 +298,8 @@ impl Widget for CountGridWidget {
             layout.rect.set_pos(offset_pos);
         }
 
+        ctx.layout.propagate_baseline(ctx.dom, &node.children);
+
         // Put max_sizes back to be reused
         max_sizes.clear();
         let _ = std::mem::replace(&mut *self.max_sizes.borrow_mut(), max_sizes);
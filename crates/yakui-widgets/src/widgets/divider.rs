@@ -1,7 +1,7 @@
 use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
 use yakui_core::paint::PaintRect;
-use yakui_core::widget::{LayoutContext, PaintContext, Widget};
-use yakui_core::Response;
+use yakui_core::widget::{IntrinsicContext, LayoutContext, PaintContext, Widget};
+use yakui_core::{IntrinsicSize, Response};
 
 use crate::auto_builders;
 
@@ -77,6 +77,16 @@ impl Widget for DividerWidget {
         )
     }
 
+    fn intrinsic_size(&self, _ctx: IntrinsicContext<'_>, kind: IntrinsicSize, _extent: f32) -> f32 {
+        // The divider stretches to its parent's width, so it has no opinion on
+        // how wide it should be.
+        if kind.is_width() {
+            0.0
+        } else {
+            self.props.height
+        }
+    }
+
     fn paint(&self, ctx: PaintContext<'_>) {
         // We get the parent's width during the paint phase because
         // using constraints.max.x is often useless as it is often infinite.
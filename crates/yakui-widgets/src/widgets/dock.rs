@@ -0,0 +1,986 @@
+use std::cell::RefCell;
+use std::mem;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
+use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
+use yakui_core::input::MouseButton;
+use yakui_core::layout::LayoutDom;
+use yakui_core::paint::PaintRect;
+use yakui_core::widget::{EventContext, LayoutContext, PaintContext, Widget};
+use yakui_core::{context, Direction, Response, WidgetId};
+
+use crate::util::SplitSizes;
+use crate::{auto_builders, colors};
+
+use super::{List, Pad, Stack, Text};
+
+/// How thick the draggable dividers between docked areas are.
+const DIVIDER_THICKNESS: f32 = 4.0;
+
+/// The smallest size dividers can be dragged to make an area, unless there
+/// isn't enough space.
+const MIN_AREA_SIZE: f32 = 40.0;
+
+/// How far a tab needs to be dragged before it starts being moved.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// How close to the edge of an area, as a fraction of its size, a tab needs
+/// to be dropped to split that area instead of joining its tabs.
+const EDGE_DROP_FRACTION: f32 = 0.25;
+
+/**
+Describes how tabs are arranged in a [DockArea]: a tree of splits whose leaves
+are stacks of tabs.
+
+Tabs are identified by their names, which are also shown in their tab bars, so
+names must be unique within a layout.
+
+With the `serde` feature enabled, layouts can be serialized so that users'
+layouts can be restored later.
+
+```rust
+use yakui::widgets::{DockLayout, DockNode};
+
+let layout = DockLayout::new(DockNode::horizontal(
+    0.25,
+    DockNode::tabs(["Outliner"]),
+    DockNode::vertical(
+        0.7,
+        DockNode::tabs(["Viewport"]),
+        DockNode::tabs(["Console", "Output"]),
+    ),
+));
+
+assert!(layout.contains("Console"));
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DockLayout {
+    pub root: DockNode,
+}
+
+/// A node in a [DockLayout].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DockNode {
+    /// Two nodes next to each other, separated by a divider.
+    Split {
+        axis: DockAxis,
+        /// The fraction of the space given to `first`. A NaN ratio, like from
+        /// a corrupted saved layout, splits the space evenly.
+        ratio: f32,
+        first: Box<DockNode>,
+        second: Box<DockNode>,
+    },
+
+    /// A stack of tabs, of which only the active one is shown.
+    Tabs { tabs: Vec<String>, active: usize },
+}
+
+/// The axis along which a [DockNode::Split] places its nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DockAxis {
+    /// Side by side, from left to right.
+    Horizontal,
+    /// On top of each other, from top to bottom.
+    Vertical,
+}
+
+/// Where a tab is docked relative to the tabs it's moved next to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DockSide {
+    /// In the same stack of tabs.
+    Center,
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl DockAxis {
+    fn direction(self) -> Direction {
+        match self {
+            DockAxis::Horizontal => Direction::Right,
+            DockAxis::Vertical => Direction::Down,
+        }
+    }
+}
+
+impl DockNode {
+    /// Creates a stack of tabs with the first tab active.
+    pub fn tabs<I, S>(tabs: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::Tabs {
+            tabs: tabs.into_iter().map(Into::into).collect(),
+            active: 0,
+        }
+    }
+
+    /// Creates a split placing `first` to the left of `second`.
+    pub fn horizontal(ratio: f32, first: DockNode, second: DockNode) -> Self {
+        Self::Split {
+            axis: DockAxis::Horizontal,
+            ratio,
+            first: Box::new(first),
+            second: Box::new(second),
+        }
+    }
+
+    /// Creates a split placing `first` above `second`.
+    pub fn vertical(ratio: f32, first: DockNode, second: DockNode) -> Self {
+        Self::Split {
+            axis: DockAxis::Vertical,
+            ratio,
+            first: Box::new(first),
+            second: Box::new(second),
+        }
+    }
+
+    fn empty() -> Self {
+        Self::Tabs {
+            tabs: Vec::new(),
+            active: 0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        matches!(self, DockNode::Tabs { tabs, .. } if tabs.is_empty())
+    }
+
+    fn contains(&self, tab: &str) -> bool {
+        match self {
+            DockNode::Split { first, second, .. } => first.contains(tab) || second.contains(tab),
+            DockNode::Tabs { tabs, .. } => tabs.iter().any(|name| name == tab),
+        }
+    }
+
+    /// Collects every stack of tabs and the index of its active tab in depth
+    /// first order.
+    fn collect_stacks<'a>(&'a self, output: &mut Vec<(&'a [String], usize)>) {
+        match self {
+            DockNode::Split { first, second, .. } => {
+                first.collect_stacks(output);
+                second.collect_stacks(output);
+            }
+            DockNode::Tabs { tabs, active } => {
+                output.push((tabs, (*active).min(tabs.len().saturating_sub(1))));
+            }
+        }
+    }
+
+    fn first_stack_mut(&mut self) -> &mut DockNode {
+        match self {
+            DockNode::Split { first, .. } => first.first_stack_mut(),
+            DockNode::Tabs { .. } => self,
+        }
+    }
+
+    fn stack_with_tab_mut(&mut self, tab: &str) -> Option<&mut DockNode> {
+        match self {
+            DockNode::Split { first, second, .. } => match first.stack_with_tab_mut(tab) {
+                Some(stack) => Some(stack),
+                None => second.stack_with_tab_mut(tab),
+            },
+            DockNode::Tabs { .. } => {
+                if self.contains(tab) {
+                    Some(self)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Finds the ratio of the split with the given index in depth first
+    /// order, counting down `index` as splits are passed.
+    fn split_ratio_mut(&mut self, index: &mut usize) -> Option<&mut f32> {
+        match self {
+            DockNode::Split {
+                ratio,
+                first,
+                second,
+                ..
+            } => {
+                if *index == 0 {
+                    return Some(ratio);
+                }
+
+                *index -= 1;
+                match first.split_ratio_mut(index) {
+                    Some(ratio) => Some(ratio),
+                    None => second.split_ratio_mut(index),
+                }
+            }
+            DockNode::Tabs { .. } => None,
+        }
+    }
+
+    /// Removes the given tab, collapsing splits that are left with an empty
+    /// side.
+    fn remove_tab(&mut self, tab: &str) -> bool {
+        match self {
+            DockNode::Split { first, second, .. } => {
+                let removed = first.remove_tab(tab) || second.remove_tab(tab);
+
+                if removed {
+                    if first.is_empty() {
+                        let remaining = mem::replace(second.as_mut(), DockNode::empty());
+                        *self = remaining;
+                    } else if second.is_empty() {
+                        let remaining = mem::replace(first.as_mut(), DockNode::empty());
+                        *self = remaining;
+                    }
+                }
+
+                removed
+            }
+            DockNode::Tabs { tabs, active } => {
+                let Some(index) = tabs.iter().position(|name| name == tab) else {
+                    return false;
+                };
+
+                tabs.remove(index);
+                if index < *active {
+                    *active -= 1;
+                }
+                *active = (*active).min(tabs.len().saturating_sub(1));
+
+                true
+            }
+        }
+    }
+}
+
+impl DockLayout {
+    pub fn new(root: DockNode) -> Self {
+        Self { root }
+    }
+
+    /// Returns whether the layout has a tab with the given name.
+    pub fn contains(&self, tab: &str) -> bool {
+        self.root.contains(tab)
+    }
+
+    /// Adds a tab to the first stack of tabs in the layout and makes it
+    /// active. Does nothing if the layout already has the tab.
+    pub fn add_tab<S: Into<String>>(&mut self, tab: S) {
+        let tab = tab.into();
+        if self.contains(&tab) {
+            return;
+        }
+
+        if let DockNode::Tabs { tabs, active } = self.root.first_stack_mut() {
+            tabs.push(tab);
+            *active = tabs.len() - 1;
+        }
+    }
+
+    /// Removes a tab from the layout, returning whether it was found.
+    pub fn remove_tab(&mut self, tab: &str) -> bool {
+        self.root.remove_tab(tab)
+    }
+
+    /// Makes a tab the active tab of its stack, returning whether it was
+    /// found.
+    pub fn activate(&mut self, tab: &str) -> bool {
+        match self.root.stack_with_tab_mut(tab) {
+            Some(DockNode::Tabs { tabs, active }) => {
+                *active = tabs.iter().position(|name| name == tab).unwrap();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Moves a tab into the stack containing `next_to`, or into a new stack
+    /// split off from one of its sides.
+    pub fn move_tab(&mut self, tab: &str, next_to: &str, side: DockSide) {
+        if tab == next_to || !self.contains(next_to) || !self.root.remove_tab(tab) {
+            return;
+        }
+
+        let Some(stack) = self.root.stack_with_tab_mut(next_to) else {
+            return;
+        };
+
+        let (axis, new_first) = match side {
+            DockSide::Center => {
+                if let DockNode::Tabs { tabs, active } = stack {
+                    tabs.push(tab.to_owned());
+                    *active = tabs.len() - 1;
+                }
+                return;
+            }
+            DockSide::Left => (DockAxis::Horizontal, true),
+            DockSide::Right => (DockAxis::Horizontal, false),
+            DockSide::Top => (DockAxis::Vertical, true),
+            DockSide::Bottom => (DockAxis::Vertical, false),
+        };
+
+        let existing = mem::replace(stack, DockNode::empty());
+        let new_stack = DockNode::tabs([tab]);
+        let (first, second) = if new_first {
+            (new_stack, existing)
+        } else {
+            (existing, new_stack)
+        };
+
+        *stack = DockNode::Split {
+            axis,
+            ratio: 0.5,
+            first: Box::new(first),
+            second: Box::new(second),
+        };
+    }
+
+    /// Returns every stack of tabs and the index of its active tab in depth
+    /// first order.
+    fn stacks(&self) -> Vec<(&[String], usize)> {
+        let mut stacks = Vec::new();
+        self.root.collect_stacks(&mut stacks);
+        stacks
+    }
+}
+
+/**
+An area containing tabs that can be rearranged by the user, like the tool
+windows of an editor.
+
+Tabs are arranged by a [DockLayout]. Each stack of tabs has a tab bar that can
+be clicked to switch tabs. Tabs can be dragged to another stack, or to the
+edge of a stack to split it, with a preview of where the tab will go. The
+dividers between stacks can be dragged to resize them.
+
+Only the active tab of each stack is shown. Widget state inside of a tab is
+lost when it's hidden or moved.
+
+Responds with [DockAreaResponse].
+
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::{DockArea, DockLayout, DockNode};
+
+let mut layout = DockLayout::new(DockNode::horizontal(
+    0.25,
+    DockNode::tabs(["Outliner"]),
+    DockNode::tabs(["Viewport", "Console"]),
+));
+
+let response = DockArea::new().show(&mut layout, |tab| {
+    yakui::label(tab.to_owned());
+});
+
+if response.changed {
+    // Save `layout` somewhere.
+}
+```
+*/
+#[derive(Debug)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct DockArea {
+    pub background_color: Color,
+    pub tab_bar_color: Color,
+    pub active_tab_color: Color,
+    pub divider_color: Color,
+    /// The color of a divider while it's hovered or dragged.
+    pub divider_active_color: Color,
+    /// The color of the area highlighted where a dragged tab would be docked.
+    pub drop_preview_color: Color,
+}
+
+auto_builders!(DockArea {
+    background_color: Color,
+    tab_bar_color: Color,
+    active_tab_color: Color,
+    divider_color: Color,
+    divider_active_color: Color,
+    drop_preview_color: Color,
+});
+
+impl DockArea {
+    pub fn new() -> Self {
+        Self {
+            background_color: colors::BACKGROUND_2,
+            tab_bar_color: colors::BACKGROUND_1,
+            active_tab_color: colors::BACKGROUND_3,
+            divider_color: colors::BACKGROUND_1,
+            divider_active_color: colors::TEXT_MUTED,
+            drop_preview_color: Color::CORNFLOWER_BLUE.with_alpha(0.3),
+        }
+    }
+
+    /// Shows the tabs of `layout`, calling `tab` with the name of each active
+    /// tab to show its contents. Changes made by the user are applied to
+    /// `layout`.
+    #[track_caller]
+    pub fn show(
+        self,
+        layout: &mut DockLayout,
+        mut tab: impl FnMut(&str),
+    ) -> Response<DockAreaResponse> {
+        let dom = context::dom();
+        let response = dom.begin_widget::<DockAreaWidget>((self, &mut *layout));
+
+        for (tabs, active) in layout.stacks() {
+            List::row().show(|| {
+                for name in tabs {
+                    Text::label(name.clone().into())
+                        .padding(Pad::balanced(10.0, 6.0))
+                        .show();
+                }
+            });
+
+            Stack::new().show(|| {
+                if let Some(name) = tabs.get(active) {
+                    tab(name);
+                }
+            });
+        }
+
+        dom.end_widget::<DockAreaWidget>(response.id);
+        response
+    }
+}
+
+#[derive(Debug)]
+pub struct DockAreaWidget {
+    props: DockArea,
+    /// The layout as of the last update.
+    layout: DockLayout,
+    /// Changes made by the user that haven't been applied to the layout yet.
+    actions: Vec<DockAction>,
+    drag: Option<DockDrag>,
+    drop_target: Option<DropTarget>,
+    hovered_divider: Option<usize>,
+
+    /// The areas taken up by each stack of tabs as of the last layout,
+    /// relative to the widget.
+    stacks: RefCell<Vec<StackGeometry>>,
+    /// The dividers of each split as of the last layout, relative to the
+    /// widget.
+    splits: RefCell<Vec<SplitGeometry>>,
+}
+
+#[derive(Debug)]
+pub struct DockAreaResponse {
+    /// Whether the user changed the layout since the last update.
+    pub changed: bool,
+}
+
+#[derive(Debug)]
+enum DockAction {
+    Activate(String),
+    SetRatio {
+        split: usize,
+        ratio: f32,
+    },
+    Move {
+        tab: String,
+        next_to: String,
+        side: DockSide,
+    },
+}
+
+#[derive(Debug)]
+enum DockDrag {
+    Divider {
+        split: usize,
+        start_mouse: f32,
+        start_size: f32,
+    },
+    Tab {
+        tab: String,
+        start_mouse: Vec2,
+        dragging: bool,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DropTarget {
+    stack: usize,
+    side: DockSide,
+}
+
+#[derive(Debug)]
+struct StackGeometry {
+    /// The widgets of each tab in the tab bar.
+    tabs: Vec<WidgetId>,
+    rect: Rect,
+    tab_bar_height: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SplitGeometry {
+    direction: Direction,
+    divider: Rect,
+    sizes: SplitSizes,
+}
+
+impl DockAction {
+    fn apply(self, layout: &mut DockLayout) {
+        match self {
+            DockAction::Activate(tab) => {
+                layout.activate(&tab);
+            }
+            DockAction::SetRatio { mut split, ratio } => {
+                if let Some(value) = layout.root.split_ratio_mut(&mut split) {
+                    *value = ratio;
+                }
+            }
+            DockAction::Move { tab, next_to, side } => {
+                layout.move_tab(&tab, &next_to, side);
+            }
+        }
+    }
+}
+
+impl StackGeometry {
+    fn content_rect(&self) -> Rect {
+        let size = self.rect.size();
+        let tab_bar_height = self.tab_bar_height.min(size.y);
+
+        Rect::from_pos_size(
+            self.rect.pos() + Vec2::new(0.0, tab_bar_height),
+            Vec2::new(size.x, size.y - tab_bar_height),
+        )
+    }
+}
+
+fn layout_node(
+    node: &DockNode,
+    rect: Rect,
+    stacks: &mut Vec<Rect>,
+    splits: &mut Vec<SplitGeometry>,
+) {
+    match node {
+        DockNode::Tabs { .. } => stacks.push(rect),
+
+        DockNode::Split {
+            axis,
+            ratio,
+            first,
+            second,
+        } => {
+            let direction = axis.direction();
+            let main = direction.get_main_axis(rect.size());
+            let cross = direction.get_cross_axis(rect.size());
+
+            let sizes = SplitSizes::new(main, DIVIDER_THICKNESS, *ratio, MIN_AREA_SIZE);
+
+            splits.push(SplitGeometry {
+                direction,
+                divider: Rect::from_pos_size(
+                    rect.pos() + direction.vec2(sizes.first, 0.0),
+                    direction.vec2(DIVIDER_THICKNESS, cross),
+                ),
+                sizes,
+            });
+
+            let first_rect = Rect::from_pos_size(rect.pos(), direction.vec2(sizes.first, cross));
+            let second_rect = Rect::from_pos_size(
+                rect.pos() + direction.vec2(sizes.first + DIVIDER_THICKNESS, 0.0),
+                direction.vec2(sizes.second(), cross),
+            );
+
+            layout_node(first, first_rect, stacks, splits);
+            layout_node(second, second_rect, stacks, splits);
+        }
+    }
+}
+
+impl DockAreaWidget {
+    fn divider_at(&self, position: Vec2) -> Option<usize> {
+        self.splits
+            .borrow()
+            .iter()
+            .position(|split| split.divider.contains_point(position))
+    }
+
+    /// Finds the stack and index of the tab whose header is under the given
+    /// absolute position.
+    fn tab_at(&self, layout: &LayoutDom, position: Vec2) -> Option<(usize, usize)> {
+        let stacks = self.stacks.borrow();
+
+        stacks.iter().enumerate().find_map(|(stack, geometry)| {
+            let tab = geometry.tabs.iter().position(|&tab| {
+                layout
+                    .get(tab)
+                    .is_some_and(|node| node.rect.contains_point(position))
+            })?;
+
+            Some((stack, tab))
+        })
+    }
+
+    fn drop_target_at(&self, position: Vec2) -> Option<DropTarget> {
+        let stacks = self.stacks.borrow();
+        let (stack, geometry) = stacks
+            .iter()
+            .enumerate()
+            .find(|(_, geometry)| geometry.rect.contains_point(position))?;
+
+        let content = geometry.content_rect();
+        if position.y < content.pos().y || content.size().cmple(Vec2::ZERO).any() {
+            return Some(DropTarget {
+                stack,
+                side: DockSide::Center,
+            });
+        }
+
+        let relative = (position - content.pos()) / content.size();
+        let edges = [
+            (DockSide::Left, relative.x),
+            (DockSide::Right, 1.0 - relative.x),
+            (DockSide::Top, relative.y),
+            (DockSide::Bottom, 1.0 - relative.y),
+        ];
+
+        let (side, distance) = edges
+            .into_iter()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap();
+
+        let side = if distance < EDGE_DROP_FRACTION {
+            side
+        } else {
+            DockSide::Center
+        };
+
+        Some(DropTarget { stack, side })
+    }
+
+    fn drop_preview_rect(&self, target: DropTarget) -> Option<Rect> {
+        let stacks = self.stacks.borrow();
+        let geometry = stacks.get(target.stack)?;
+
+        let rect = geometry.rect;
+        let pos = rect.pos();
+        let half = rect.size() / 2.0;
+
+        let preview = match target.side {
+            DockSide::Center => geometry.content_rect(),
+            DockSide::Left => Rect::from_pos_size(pos, Vec2::new(half.x, rect.size().y)),
+            DockSide::Right => Rect::from_pos_size(
+                pos + Vec2::new(half.x, 0.0),
+                Vec2::new(half.x, rect.size().y),
+            ),
+            DockSide::Top => Rect::from_pos_size(pos, Vec2::new(rect.size().x, half.y)),
+            DockSide::Bottom => Rect::from_pos_size(
+                pos + Vec2::new(0.0, half.y),
+                Vec2::new(rect.size().x, half.y),
+            ),
+        };
+
+        Some(preview)
+    }
+
+    /// Creates the action for dropping a tab onto the given target, if it
+    /// would change anything.
+    fn drop_action(&self, tab: String, target: DropTarget) -> Option<DockAction> {
+        let stacks = self.layout.stacks();
+        let (tabs, _) = stacks.get(target.stack)?;
+
+        if target.side == DockSide::Center && tabs.contains(&tab) {
+            return None;
+        }
+
+        let next_to = tabs.iter().find(|&name| *name != tab)?.clone();
+        Some(DockAction::Move {
+            tab,
+            next_to,
+            side: target.side,
+        })
+    }
+}
+
+impl Widget for DockAreaWidget {
+    type Props<'a> = (DockArea, &'a mut DockLayout);
+    type Response = DockAreaResponse;
+
+    fn new() -> Self {
+        Self {
+            props: DockArea::new(),
+            layout: DockLayout::new(DockNode::empty()),
+            actions: Vec::new(),
+            drag: None,
+            drop_target: None,
+            hovered_divider: None,
+            stacks: RefCell::new(Vec::new()),
+            splits: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn update(&mut self, (props, layout): Self::Props<'_>) -> Self::Response {
+        self.props = props;
+
+        let changed = !self.actions.is_empty();
+        for action in self.actions.drain(..) {
+            action.apply(layout);
+        }
+
+        self.layout = layout.clone();
+
+        DockAreaResponse { changed }
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+
+        // Dock areas fill all of the space they're given.
+        let size = Vec2::select(input.max.is_finite_mask(), input.max, input.min);
+
+        let mut stack_rects = Vec::new();
+        let mut splits = Vec::new();
+        layout_node(
+            &self.layout.root,
+            Rect::from_pos_size(Vec2::ZERO, size),
+            &mut stack_rects,
+            &mut splits,
+        );
+
+        let mut stacks = Vec::with_capacity(stack_rects.len());
+        for (children, rect) in node.children.chunks(2).zip(stack_rects) {
+            let &[tab_bar, content] = children else {
+                continue;
+            };
+
+            let tab_bar_constraints = Constraints {
+                min: Vec2::new(rect.size().x, 0.0),
+                max: rect.size(),
+            };
+            let tab_bar_size = ctx.calculate_layout(tab_bar, tab_bar_constraints);
+            ctx.layout.set_pos(tab_bar, rect.pos());
+
+            let geometry = StackGeometry {
+                tabs: ctx.dom.get(tab_bar).unwrap().children.to_vec(),
+                rect,
+                tab_bar_height: tab_bar_size.y,
+            };
+
+            let content_rect = geometry.content_rect();
+            ctx.calculate_layout(content, Constraints::tight(content_rect.size()));
+            ctx.layout.set_pos(content, content_rect.pos());
+
+            stacks.push(geometry);
+        }
+
+        *self.stacks.borrow_mut() = stacks;
+        *self.splits.borrow_mut() = splits;
+
+        size
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        let rect = ctx.layout.get(ctx.dom.current()).unwrap().rect;
+        let origin = rect.pos();
+
+        // The dividers are the gaps between stacks.
+        let mut background = PaintRect::new(rect);
+        background.color = self.props.divider_color;
+        background.add(ctx.paint);
+
+        let node = ctx.dom.get_current();
+        let stacks = self.stacks.borrow();
+        let active_tabs = self.layout.stacks();
+
+        for ((children, geometry), (_, active)) in
+            node.children.chunks(2).zip(stacks.iter()).zip(active_tabs)
+        {
+            let tab_bar_rect = Rect::from_pos_size(
+                origin + geometry.rect.pos(),
+                Vec2::new(geometry.rect.size().x, geometry.tab_bar_height),
+            );
+
+            let mut tab_bar = PaintRect::new(tab_bar_rect);
+            tab_bar.color = self.props.tab_bar_color;
+            tab_bar.add(ctx.paint);
+
+            let content_rect = geometry.content_rect();
+            let mut content = PaintRect::new(Rect::from_pos_size(
+                origin + content_rect.pos(),
+                content_rect.size(),
+            ));
+            content.color = self.props.background_color;
+            content.add(ctx.paint);
+
+            let active_tab = geometry
+                .tabs
+                .get(active)
+                .and_then(|&tab| ctx.layout.get(tab));
+
+            if let Some(active_tab) = active_tab {
+                let mut highlight = PaintRect::new(active_tab.rect);
+                highlight.color = self.props.active_tab_color;
+                highlight.add(ctx.paint);
+            }
+
+            for &child in children {
+                ctx.paint(child);
+            }
+        }
+
+        let active_divider = match self.drag {
+            Some(DockDrag::Divider { split, .. }) => Some(split),
+            _ => self.hovered_divider,
+        };
+
+        if let Some(split) =
+            active_divider.and_then(|index| self.splits.borrow().get(index).copied())
+        {
+            let mut divider = PaintRect::new(Rect::from_pos_size(
+                origin + split.divider.pos(),
+                split.divider.size(),
+            ));
+            divider.color = self.props.divider_active_color;
+            divider.add(ctx.paint);
+        }
+
+        if let Some(preview) = self
+            .drop_target
+            .and_then(|target| self.drop_preview_rect(target))
+        {
+            let mut preview =
+                PaintRect::new(Rect::from_pos_size(origin + preview.pos(), preview.size()));
+            preview.color = self.props.drop_preview_color;
+            preview.add(ctx.paint);
+        }
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::MOUSE_ALL
+    }
+
+    fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        let origin = ctx.layout.get(ctx.dom.current()).unwrap().rect.pos();
+
+        match *event {
+            WidgetEvent::MouseButtonChanged {
+                button: MouseButton::One,
+                down: true,
+                inside: true,
+                position,
+                ..
+            } => {
+                if let Some(split) = self.divider_at(position - origin) {
+                    let geometry = self.splits.borrow()[split];
+                    self.drag = Some(DockDrag::Divider {
+                        split,
+                        start_mouse: geometry.direction.get_main_axis(position),
+                        start_size: geometry.sizes.first,
+                    });
+
+                    return EventResponse::Sink;
+                }
+
+                let Some((stack, index)) = self.tab_at(ctx.layout, position) else {
+                    return EventResponse::Bubble;
+                };
+
+                let Some(tab) = self
+                    .layout
+                    .stacks()
+                    .get(stack)
+                    .and_then(|(tabs, _)| tabs.get(index).cloned())
+                else {
+                    return EventResponse::Bubble;
+                };
+
+                self.actions.push(DockAction::Activate(tab.clone()));
+                self.drag = Some(DockDrag::Tab {
+                    tab,
+                    start_mouse: position,
+                    dragging: false,
+                });
+
+                EventResponse::Sink
+            }
+
+            WidgetEvent::MouseButtonChanged {
+                button: MouseButton::One,
+                down: false,
+                ..
+            } => {
+                let target = self.drop_target.take();
+
+                match self.drag.take() {
+                    Some(DockDrag::Tab {
+                        tab,
+                        dragging: true,
+                        ..
+                    }) => {
+                        if let Some(action) =
+                            target.and_then(|target| self.drop_action(tab, target))
+                        {
+                            self.actions.push(action);
+                        }
+
+                        EventResponse::Sink
+                    }
+                    Some(_) => EventResponse::Sink,
+                    None => EventResponse::Bubble,
+                }
+            }
+
+            WidgetEvent::MouseMoved(Some(position)) => {
+                let local = position - origin;
+                self.hovered_divider = self.divider_at(local);
+
+                match &mut self.drag {
+                    Some(DockDrag::Divider {
+                        split,
+                        start_mouse,
+                        start_size,
+                    }) => {
+                        let split = *split;
+                        let Some(geometry) = self.splits.borrow().get(split).copied() else {
+                            return EventResponse::Sink;
+                        };
+
+                        let delta = geometry.direction.get_main_axis(position) - *start_mouse;
+                        if let Some(ratio) = geometry.sizes.ratio_for(*start_size + delta) {
+                            // Only the latest ratio of a divider matters.
+                            self.actions.retain(|action| {
+                                !matches!(action, DockAction::SetRatio { split: other, .. } if *other == split)
+                            });
+                            self.actions.push(DockAction::SetRatio { split, ratio });
+                        }
+
+                        EventResponse::Sink
+                    }
+
+                    Some(DockDrag::Tab {
+                        start_mouse,
+                        dragging,
+                        ..
+                    }) => {
+                        if !*dragging && position.distance(*start_mouse) >= DRAG_THRESHOLD {
+                            *dragging = true;
+                        }
+
+                        if *dragging {
+                            self.drop_target = self.drop_target_at(local);
+                        }
+
+                        EventResponse::Sink
+                    }
+
+                    None => EventResponse::Bubble,
+                }
+            }
+
+            WidgetEvent::MouseMoved(None) | WidgetEvent::MouseLeave => {
+                self.hovered_divider = None;
+                EventResponse::Bubble
+            }
+
+            _ => EventResponse::Bubble,
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use yakui_core::geometry::{Constraints, Dim, Dim2, Vec2};
+use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::Response;
+
+use crate::auto_builders;
+use crate::util::widget_children;
+
+/**
+A box that sizes itself relative to the incoming constraints. Percentages are
+resolved against the maximum size available on each axis.
+
+Axes without a size, or whose incoming constraints are unbounded, are sized to
+fit the box's children instead.
+
+Responds with [FractionallySizedResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+# use yakui::{Color, Dim, Dim2};
+yakui::fractionally_sized(Dim2::new(Dim::percent(0.4), Dim::percent(0.5)), || {
+    yakui::colored_box_container(Color::RED, || {});
+});
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct FractionallySized {
+    pub width: Option<Dim>,
+    pub height: Option<Dim>,
+}
+
+auto_builders!(FractionallySized {
+    width: Option<Dim>,
+    height: Option<Dim>,
+});
+
+impl FractionallySized {
+    pub fn new(size: Dim2) -> Self {
+        Self {
+            width: Some(size.x),
+            height: Some(size.y),
+        }
+    }
+
+    pub fn horizontal(width: Dim) -> Self {
+        Self {
+            width: Some(width),
+            height: None,
+        }
+    }
+
+    pub fn vertical(height: Dim) -> Self {
+        Self {
+            width: None,
+            height: Some(height),
+        }
+    }
+
+    #[track_caller]
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<FractionallySizedResponse> {
+        widget_children::<FractionallySizedWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct FractionallySizedWidget {
+    props: FractionallySized,
+}
+
+pub type FractionallySizedResponse = ();
+
+impl Widget for FractionallySizedWidget {
+    type Props<'a> = FractionallySized;
+    type Response = FractionallySizedResponse;
+
+    fn new() -> Self {
+        Self {
+            props: FractionallySized::new(Dim2::ZERO),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+
+        let resolve = |dim: Option<Dim>, min: f32, max: f32| match dim {
+            Some(dim) if max.is_finite() => {
+                let value = dim.resolve(max).max(min).min(max);
+                (value, value)
+            }
+            _ => (min, max),
+        };
+
+        let (min_x, max_x) = resolve(self.props.width, input.min.x, input.max.x);
+        let (min_y, max_y) = resolve(self.props.height, input.min.y, input.max.y);
+
+        let constraints = Constraints {
+            min: Vec2::new(min_x, min_y),
+            max: Vec2::new(max_x, max_y),
+        };
+
+        let mut size = Vec2::ZERO;
+        for &child in &node.children {
+            let child_size = ctx.calculate_layout(child, constraints);
+            size = size.max(child_size);
+        }
+
+        ctx.layout.propagate_baseline(ctx.dom, &node.children);
+        constraints.constrain_min(size)
+    }
+}
@@ -1,7 +1,7 @@
 use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
 use yakui_core::paint::PaintRect;
-use yakui_core::widget::{LayoutContext, PaintContext, Widget};
-use yakui_core::{Response, TextureId};
+use yakui_core::widget::{IntrinsicContext, LayoutContext, PaintContext, Widget};
+use yakui_core::{IntrinsicSize, Response, TextureId};
 
 use crate::auto_builders;
 use crate::util::widget;
@@ -106,6 +106,10 @@ impl Widget for ImageWidget {
         output_size
     }
 
+    fn intrinsic_size(&self, _ctx: IntrinsicContext<'_>, kind: IntrinsicSize, _extent: f32) -> f32 {
+        kind.get(self.props.size)
+    }
+
     fn paint(&self, ctx: PaintContext<'_>) {
         let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
 
@@ -8,8 +8,7 @@ use crate::util::widget_children;
 Creates a new layer that will take input priority and draw over items in the
 containing layer.
 
-In the future, this widget may be extended to support arbitrary transforms
-applied to layers.
+To apply a transform to a widget tree, see [`Transform`][super::Transform].
 */
 #[derive(Debug, Clone)]
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
@@ -1,15 +1,22 @@
-use yakui_core::geometry::{Constraints, FlexFit, Vec2};
-use yakui_core::widget::{LayoutContext, Widget};
-use yakui_core::{CrossAxisAlignment, Direction, Flow, MainAxisAlignment, MainAxisSize, Response};
+use yakui_core::geometry::{Constraints, FlexFit, Rect, Vec2};
+use yakui_core::layout::Overflow;
+use yakui_core::widget::{IntrinsicContext, LayoutContext, Widget};
+use yakui_core::{
+    CrossAxisAlignment, Direction, Flow, IntrinsicSize, LayoutDirection, MainAxisAlignment,
+    MainAxisSize, Response,
+};
 
 use crate::auto_builders;
-use crate::util::widget_children;
+use crate::util::{current_layout_direction, widget_children};
 
 /**
 Lays out children in a single direction. Supports flex sizing.
 
 This is one of the most common and useful layout widgets.
 
+When the [`LayoutDirection`] is right-to-left, the list is mirrored: rows start
+on the right and columns align their `Start` cross axis to the right.
+
 Responds with [ListResponse].
 
 Shorthand:
@@ -72,6 +79,7 @@ impl List {
 #[derive(Debug)]
 pub struct ListWidget {
     props: List,
+    layout_direction: LayoutDirection,
 }
 
 pub type ListResponse = ();
@@ -81,11 +89,15 @@ impl Widget for ListWidget {
     type Response = ListResponse;
 
     fn new() -> Self {
-        Self { props: List::row() }
+        Self {
+            props: List::row(),
+            layout_direction: LayoutDirection::default(),
+        }
     }
 
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
         self.props = props;
+        self.layout_direction = current_layout_direction();
     }
 
     fn flex(&self) -> (u32, FlexFit) {
@@ -98,6 +110,38 @@ impl Widget for ListWidget {
         (flex, FlexFit::Tight)
     }
 
+    fn intrinsic_size(&self, ctx: IntrinsicContext<'_>, kind: IntrinsicSize, extent: f32) -> f32 {
+        let node = ctx.dom.get_current();
+        let direction = self.props.direction;
+
+        let children = node
+            .children
+            .iter()
+            .copied()
+            .filter(|&child| ctx.dom.get(child).unwrap().widget.flow() == Flow::Inline);
+
+        if kind.direction() == direction {
+            // Along the main axis, children are placed end to end.
+            let total_item_spacing =
+                self.props.item_spacing * node.children.len().saturating_sub(1) as f32;
+
+            children
+                .map(|child| ctx.intrinsic_size(child, kind, extent))
+                .sum::<f32>()
+                + total_item_spacing
+        } else {
+            // Along the cross axis, each child gets as much main axis space as
+            // it would like, and the list is as big as its biggest child.
+            children
+                .map(|child| {
+                    let main =
+                        ctx.intrinsic_size(child, IntrinsicSize::max(direction), f32::INFINITY);
+                    ctx.intrinsic_size(child, kind, main)
+                })
+                .fold(0.0, f32::max)
+        }
+    }
+
     // This approach to layout is based on Flutter's Flex layout algorithm.
     //
     // https://api.flutter.dev/flutter/widgets/Flex-class.html#layout-algorithm
@@ -190,6 +234,31 @@ impl Widget for ListWidget {
             max_cross_axis_size = f32::max(max_cross_axis_size, direction.get_cross_axis(size));
         }
 
+        // When aligning by baseline, children are moved down so that their
+        // baselines line up with the lowest one, which can make the list
+        // taller than its tallest child.
+        let align_baselines = direction == Direction::Right
+            && self.props.cross_axis_alignment == CrossAxisAlignment::Baseline;
+        let mut max_ascent: f32 = 0.0;
+        if align_baselines {
+            let mut max_descent: f32 = 0.0;
+
+            for &child_index in &node.children {
+                let child = ctx.dom.get(child_index).unwrap();
+                if child.widget.flow() != Flow::Inline {
+                    continue;
+                }
+
+                if let Some(baseline) = ctx.layout.baseline(child_index) {
+                    let height = ctx.layout.get(child_index).unwrap().rect.size().y;
+                    max_ascent = max_ascent.max(baseline);
+                    max_descent = max_descent.max(height - baseline);
+                }
+            }
+
+            max_cross_axis_size = max_cross_axis_size.max(max_ascent + max_descent);
+        }
+
         let cross_size = max_cross_axis_size.max(direction.get_cross_axis(input.min));
 
         let main_axis_size = match self.props.main_axis_size {
@@ -264,12 +333,19 @@ impl Widget for ListWidget {
 
         let mut next_main = leading_space;
 
+        // A row's baseline is the highest of its children's baselines, while a
+        // column's baseline is the baseline of its first child that has one.
+        let mut list_baseline: Option<f32> = None;
+
+        let mut overflow = Overflow::NONE;
+
         for &child_index in &node.children {
             let child = ctx.dom.get(child_index).unwrap();
             if child.widget.flow() != Flow::Inline {
                 continue;
             }
 
+            let child_baseline = ctx.layout.baseline(child_index);
             let child_layout = ctx.layout.get_mut(child_index).unwrap();
             let child_size = child_layout.rect.size();
             let child_main = direction.get_main_axis(child_size);
@@ -279,13 +355,39 @@ impl Widget for ListWidget {
                 CrossAxisAlignment::Start | CrossAxisAlignment::Stretch => 0.0,
                 CrossAxisAlignment::Center => (cross_size - child_cross) / 2.0,
                 CrossAxisAlignment::End => cross_size - child_cross,
+                CrossAxisAlignment::Baseline => match child_baseline {
+                    Some(baseline) if align_baselines => max_ascent - baseline,
+                    _ => 0.0,
+                },
             };
-            child_layout.rect.set_pos(direction.vec2(next_main, cross));
+            let mut pos = direction.vec2(next_main, cross);
+            if self.layout_direction.is_rtl() {
+                pos.x = container_size.x - pos.x - child_size.x;
+            }
+            child_layout.rect.set_pos(pos);
+
+            let child_rect = Rect::from_pos_size(pos, child_size);
+            overflow = overflow.max(Overflow::of_child(container_size, child_rect));
+
+            if let Some(baseline) = child_baseline {
+                let baseline = pos.y + baseline;
+                list_baseline = match (direction, list_baseline) {
+                    (Direction::Right, Some(existing)) => Some(existing.min(baseline)),
+                    (_, None) => Some(baseline),
+                    (Direction::Down, existing) => existing,
+                };
+            }
 
             next_main += child_main;
             next_main += between_space;
         }
 
+        if let Some(baseline) = list_baseline {
+            ctx.layout.set_baseline(ctx.dom, baseline);
+        }
+
+        ctx.layout.report_overflow(ctx.dom, overflow);
+
         container_size
     }
 }
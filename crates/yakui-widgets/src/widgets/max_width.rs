@@ -1,6 +1,6 @@
 use yakui_core::geometry::{Constraints, Vec2};
-use yakui_core::widget::{LayoutContext, Widget};
-use yakui_core::Response;
+use yakui_core::widget::{IntrinsicContext, LayoutContext, Widget};
+use yakui_core::{IntrinsicSize, Response};
 
 use crate::util::widget_children;
 
@@ -59,6 +59,17 @@ impl Widget for MaxWidthWidget {
             size = size.max(child_size);
         }
 
+        ctx.layout.propagate_baseline(ctx.dom, &node.children);
         size
     }
+
+    fn intrinsic_size(&self, ctx: IntrinsicContext<'_>, kind: IntrinsicSize, extent: f32) -> f32 {
+        if kind.is_width() {
+            let size = self.default_intrinsic_size(ctx, kind, extent);
+            size.min(self.props.max_width)
+        } else {
+            let extent = extent.min(self.props.max_width);
+            self.default_intrinsic_size(ctx, kind, extent)
+        }
+    }
 }
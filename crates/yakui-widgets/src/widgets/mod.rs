@@ -1,5 +1,7 @@
 mod align;
+mod aspect_ratio;
 mod button;
+mod cached;
 mod canvas;
 mod checkbox;
 mod circle;
@@ -8,33 +10,45 @@ mod constrained_box;
 mod count_grid;
 mod cutout;
 mod divider;
+mod dock;
 mod draggable;
 mod flexible;
+mod fractionally_sized;
 mod image;
 mod layer;
 mod list;
 mod max_width;
 mod nineslice;
 mod offset;
+mod opacity;
 mod opaque;
 mod pad;
 mod panel;
+mod positioned;
 mod reflow;
 mod render_text;
 mod round_rect;
 mod scoped;
 mod scrollable;
+mod shadow;
 mod slider;
 mod spacer;
+mod splitter;
 mod stack;
 mod state;
+mod table;
 mod text;
 mod textbox;
+mod transform;
 mod unconstrained_box;
+mod virtual_list;
 mod window;
+mod wrap;
 
 pub use self::align::*;
+pub use self::aspect_ratio::*;
 pub use self::button::*;
+pub use self::cached::*;
 pub use self::canvas::*;
 pub use self::checkbox::*;
 pub use self::circle::*;
@@ -43,27 +57,37 @@ pub use self::constrained_box::*;
 pub use self::count_grid::*;
 pub use self::cutout::*;
 pub use self::divider::*;
+pub use self::dock::*;
 pub use self::draggable::*;
 pub use self::flexible::*;
+pub use self::fractionally_sized::*;
 pub use self::image::*;
 pub use self::layer::*;
 pub use self::list::*;
 pub use self::max_width::*;
 pub use self::nineslice::*;
 pub use self::offset::*;
+pub use self::opacity::*;
 pub use self::opaque::*;
 pub use self::pad::*;
 pub use self::panel::*;
+pub use self::positioned::*;
 pub use self::reflow::*;
 pub use self::render_text::*;
 pub use self::round_rect::*;
 pub use self::scoped::*;
 pub use self::scrollable::*;
+pub use self::shadow::*;
 pub use self::slider::*;
 pub use self::spacer::*;
+pub use self::splitter::*;
 pub use self::stack::*;
 pub use self::state::*;
+pub use self::table::*;
 pub use self::text::*;
 pub use self::textbox::*;
+pub use self::transform::*;
 pub use self::unconstrained_box::*;
+pub use self::virtual_list::*;
 pub use self::window::*;
+pub use self::wrap::*;
@@ -120,7 +120,7 @@ impl Widget for NineSliceWidget {
         });
 
         // Build rectangles between the vertices.
-        let indices = (0..3).flat_map(|i| {
+        let indices = (0u32..3).flat_map(|i| {
             (0..3).flat_map(move |j| {
                 let first = i * 4 + j;
                 [first, first + 5, first + 1, first, first + 4, first + 5]
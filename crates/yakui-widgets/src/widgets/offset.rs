@@ -66,6 +66,7 @@ impl Widget for OffsetWidget {
             ctx.layout.set_pos(child, self.props.offset);
         }
 
+        ctx.layout.propagate_baseline(ctx.dom, &node.children);
         self_size
     }
 }
@@ -0,0 +1,105 @@
+use yakui_core::widget::{PaintContext, Widget};
+use yakui_core::Response;
+
+use crate::auto_builders;
+use crate::util::widget_children;
+
+/**
+Fades out all of its children, including text, images and nested widgets, by
+multiplying their alpha by `opacity`.
+
+By default, each mesh painted by the children is faded on its own, so
+overlapping children show through each other. Setting `offscreen` paints the
+children into an offscreen texture first and fades that instead, which looks
+like a single faded layer. Offscreen painting needs a renderer that supports
+render targets; otherwise, the children are faded on their own.
+
+Fully transparent children are not painted at all. Colored glyphs, like emoji,
+and user paint calls are only faded with `offscreen`.
+
+Responds with [OpacityResponse].
+
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::Opacity;
+
+Opacity::new(0.5).offscreen(true).show(|| {
+    yakui::button("Faded");
+});
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Opacity {
+    /// How opaque the children are, from 0.0 to 1.0.
+    pub opacity: f32,
+    pub offscreen: bool,
+}
+
+auto_builders!(Opacity {
+    opacity: f32,
+    offscreen: bool,
+});
+
+impl Opacity {
+    pub fn new(opacity: f32) -> Self {
+        Self {
+            opacity,
+            offscreen: false,
+        }
+    }
+
+    #[track_caller]
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<OpacityResponse> {
+        widget_children::<OpacityWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct OpacityWidget {
+    props: Opacity,
+}
+
+pub type OpacityResponse = ();
+
+impl Widget for OpacityWidget {
+    type Props<'a> = Opacity;
+    type Response = OpacityResponse;
+
+    fn new() -> Self {
+        Self {
+            props: Opacity::new(1.0),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        let opacity = self.props.opacity.clamp(0.0, 1.0);
+        if opacity <= 0.0 {
+            return;
+        }
+
+        let offscreen =
+            self.props.offscreen && opacity < 1.0 && ctx.paint.render_targets_supported();
+
+        if offscreen {
+            ctx.paint.push_render_target(ctx.dom.current());
+        } else {
+            ctx.paint.push_opacity(opacity);
+        }
+
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            ctx.paint(child);
+        }
+
+        if offscreen {
+            ctx.paint.pop_render_target(opacity);
+        } else {
+            ctx.paint.pop_opacity();
+        }
+    }
+}
@@ -1,9 +1,9 @@
 use yakui_core::geometry::{Constraints, Vec2};
-use yakui_core::widget::{LayoutContext, Widget};
-use yakui_core::Response;
+use yakui_core::widget::{IntrinsicContext, LayoutContext, Widget};
+use yakui_core::{IntrinsicSize, LayoutDirection, Response};
 
 use crate::auto_builders;
-use crate::util::widget_children;
+use crate::util::{current_layout_direction, widget_children};
 
 /**
 Applies padding around a single child widget.
@@ -73,6 +73,19 @@ impl Pad {
         Vec2::new(self.left, self.top)
     }
 
+    /// Returns this padding with `left` and `right` swapped if `direction` is
+    /// right-to-left.
+    pub fn resolve(self, direction: LayoutDirection) -> Self {
+        match direction {
+            LayoutDirection::LeftToRight => self,
+            LayoutDirection::RightToLeft => Self {
+                left: self.right,
+                right: self.left,
+                ..self
+            },
+        }
+    }
+
     #[track_caller]
     pub fn show<F: FnOnce()>(self, children: F) -> Response<PadResponse> {
         widget_children::<PadWidget, F>(children, self)
@@ -95,7 +108,7 @@ impl Widget for PadWidget {
     }
 
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
-        self.props = props;
+        self.props = props.resolve(current_layout_direction());
     }
 
     fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
@@ -120,6 +133,19 @@ impl Widget for PadWidget {
         }
 
         self_size = self_size.max(total_padding);
+        ctx.layout.propagate_baseline(ctx.dom, &node.children);
         input.constrain_min(self_size)
     }
+
+    fn intrinsic_size(&self, ctx: IntrinsicContext<'_>, kind: IntrinsicSize, extent: f32) -> f32 {
+        let total_padding = Vec2::new(
+            self.props.left + self.props.right,
+            self.props.top + self.props.bottom,
+        );
+
+        let padding = kind.get(total_padding);
+        let extent = (extent - kind.get_extent(total_padding)).max(0.0);
+
+        self.default_intrinsic_size(ctx, kind, extent) + padding
+    }
 }
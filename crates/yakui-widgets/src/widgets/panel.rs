@@ -1,43 +1,141 @@
-use std::cell::RefCell;
-
 use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
-use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
+use yakui_core::input::MouseButton;
 use yakui_core::paint::PaintRect;
 use yakui_core::widget::{EventContext, LayoutContext, PaintContext, Widget};
-use yakui_core::Response;
+use yakui_core::{context, Direction, Response};
+
+use crate::util::{widget_children, DoubleClick};
+use crate::{auto_builders, colors};
+
+/// How thick the area along a panel's inner edge that can be dragged to resize
+/// it is.
+const RESIZE_HANDLE_WIDTH: f32 = 6.0;
+
+/**
+A resizable panel on the left, right, top, or bottom of an area, like the
+sidebars of an editor.
+
+The edge of the panel facing the rest of the area can be dragged to resize the
+panel between `min_size` and `max_size`. Double clicking that edge resets the
+panel to `default_size`. Collapsible panels collapse when they are dragged
+below half of their minimum size, hiding their children until they are
+dragged open again.
+
+The panel fills the available space on the other axis.
 
-use crate::colors;
-use crate::util::widget_children;
+Responds with [PanelResponse].
 
-const _RESIZE_HANDLE_WIDTH: f32 = 6.0;
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::Panel;
 
-/// Incomplete widget: represents a resizable panel on the sides, top, or bottom
-/// of an area. Currently blocked on figuring out the best way to detect input
-/// for resizing: add a child widget for this purpose, or handle the hit
-/// detection and movement within a single widget?
+yakui::row(|| {
+    Panel::left().collapsible(true).show(|| {
+        yakui::label("Outliner");
+    });
+
+    yakui::expanded(|| {
+        yakui::label("Viewport");
+    });
+});
+```
+*/
 #[derive(Debug)]
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct Panel {
     pub kind: PanelKind,
+    /// The size of the panel along its resizable axis until the user resizes
+    /// it.
+    pub default_size: f32,
+    pub min_size: f32,
+    pub max_size: f32,
+    pub resizable: bool,
+    /// Whether the panel collapses when it's dragged below half of
+    /// `min_size`. Collapsed panels still take up the width of their resize
+    /// handle, 6px, so that they can be dragged open again.
+    pub collapsible: bool,
+    pub color: Color,
+    pub handle_color: Color,
+    /// The color of the resize handle while it's hovered or dragged.
+    pub handle_active_color: Color,
 }
 
-#[derive(Debug)]
+auto_builders!(Panel {
+    default_size: f32,
+    min_size: f32,
+    max_size: f32,
+    resizable: bool,
+    collapsible: bool,
+    color: Color,
+    handle_color: Color,
+    handle_active_color: Color,
+});
+
+/// Which edge of an area a [Panel] is attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PanelKind {
-    Side,
-    TopBottom,
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl PanelKind {
+    /// The axis along which the panel can be resized.
+    fn axis(self) -> Direction {
+        match self {
+            PanelKind::Left | PanelKind::Right => Direction::Right,
+            PanelKind::Top | PanelKind::Bottom => Direction::Down,
+        }
+    }
+
+    /// Whether the resize handle is at the far end of the panel's axis, which
+    /// means dragging towards the end grows the panel.
+    fn handle_at_end(self) -> bool {
+        matches!(self, PanelKind::Left | PanelKind::Top)
+    }
 }
 
 impl Panel {
-    pub fn side() -> Self {
+    pub fn new(kind: PanelKind) -> Self {
         Self {
-            kind: PanelKind::Side,
+            kind,
+            default_size: 200.0,
+            min_size: 50.0,
+            max_size: f32::INFINITY,
+            resizable: true,
+            collapsible: false,
+            color: colors::BACKGROUND_2,
+            handle_color: colors::BACKGROUND_3,
+            handle_active_color: colors::TEXT_MUTED,
         }
     }
 
+    pub fn left() -> Self {
+        Self::new(PanelKind::Left)
+    }
+
+    pub fn right() -> Self {
+        Self::new(PanelKind::Right)
+    }
+
+    pub fn top() -> Self {
+        Self::new(PanelKind::Top)
+    }
+
+    pub fn bottom() -> Self {
+        Self::new(PanelKind::Bottom)
+    }
+
+    /// A panel on the side of an area. Same as [`Panel::left`].
+    pub fn side() -> Self {
+        Self::left()
+    }
+
+    /// A panel on the top or bottom of an area. Same as [`Panel::top`].
     pub fn top_bottom() -> Self {
-        Self {
-            kind: PanelKind::TopBottom,
-        }
+        Self::top()
     }
 
     #[track_caller]
@@ -49,10 +147,76 @@ impl Panel {
 #[derive(Debug)]
 pub struct PanelWidget {
     props: Panel,
-    size: RefCell<Vec2>,
+    /// The size the user resized the panel to, if they have.
+    size: Option<f32>,
+    collapsed: bool,
+    drag: Option<PanelDrag>,
+    handle_hovered: bool,
+    double_click: DoubleClick,
 }
 
-pub type PanelResponse = ();
+#[derive(Debug, Clone, Copy)]
+struct PanelDrag {
+    start_mouse: f32,
+    start_size: f32,
+}
+
+#[derive(Debug)]
+pub struct PanelResponse {
+    /// The size of the panel along its resizable axis, ignoring whether it's
+    /// collapsed.
+    pub size: f32,
+    /// Whether the panel is collapsed, in which case it only takes up the
+    /// width of its resize handle.
+    pub collapsed: bool,
+}
+
+impl PanelWidget {
+    fn size(&self) -> f32 {
+        self.size
+            .unwrap_or(self.props.default_size)
+            .min(self.props.max_size)
+            .max(self.props.min_size)
+    }
+
+    fn handle_rect(&self, rect: Rect) -> Rect {
+        let axis = self.props.kind.axis();
+        let main = axis.get_main_axis(rect.size());
+        let cross = axis.get_cross_axis(rect.size());
+        let width = RESIZE_HANDLE_WIDTH.min(main);
+
+        let start = if self.props.kind.handle_at_end() {
+            main - width
+        } else {
+            0.0
+        };
+
+        Rect::from_pos_size(rect.pos() + axis.vec2(start, 0.0), axis.vec2(width, cross))
+    }
+
+    fn drag_to(&mut self, mouse: f32) {
+        let Some(drag) = self.drag else {
+            return;
+        };
+
+        let mut delta = mouse - drag.start_mouse;
+        if delta != 0.0 {
+            self.double_click.cancel();
+        }
+
+        if !self.props.kind.handle_at_end() {
+            delta = -delta;
+        }
+
+        let size = drag.start_size + delta;
+        if self.props.collapsible && size < self.props.min_size / 2.0 {
+            self.collapsed = true;
+        } else {
+            self.collapsed = false;
+            self.size = Some(size.min(self.props.max_size).max(self.props.min_size));
+        }
+    }
+}
 
 impl Widget for PanelWidget {
     type Props<'a> = Panel;
@@ -61,71 +225,170 @@ impl Widget for PanelWidget {
     fn new() -> Self {
         Self {
             props: Panel::side(),
-            size: RefCell::new(Vec2::ZERO),
+            size: None,
+            collapsed: false,
+            drag: None,
+            handle_hovered: false,
+            double_click: DoubleClick::default(),
         }
     }
 
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
         self.props = props;
+        self.double_click.advance(context::dom().delta_time());
+
+        if !self.props.collapsible {
+            self.collapsed = false;
+        }
+
+        PanelResponse {
+            size: self.size(),
+            collapsed: self.collapsed,
+        }
     }
 
     fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        ctx.layout.enable_clipping(ctx.dom);
+
         let node = ctx.dom.get_current();
-        let mut size = input.constrain(*self.size.borrow());
+        let axis = self.props.kind.axis();
 
-        match self.props.kind {
-            PanelKind::Side => {
-                if input.max.y.is_finite() {
-                    size.y = input.max.y;
-                }
-            }
+        let main = if self.collapsed {
+            RESIZE_HANDLE_WIDTH
+        } else {
+            self.size()
+        };
+        let main = main
+            .min(axis.get_main_axis(input.max))
+            .max(axis.get_main_axis(input.min));
 
-            PanelKind::TopBottom => {
-                if input.max.x.is_finite() {
-                    size.x = input.max.x;
-                }
-            }
-        }
+        // Panels fill the available space on their other axis.
+        let max_cross = axis.get_cross_axis(input.max);
+        let min_cross = if max_cross.is_finite() {
+            max_cross
+        } else {
+            axis.get_cross_axis(input.min)
+        };
 
-        let child_constraints = Constraints::tight(size);
+        let mut cross = min_cross;
+
+        // Collapsed panels still lay out their children so that they don't
+        // keep stale layouts, but give them no space and don't paint them.
+        let child_main = if self.collapsed { 0.0 } else { main };
+        let child_constraints = Constraints {
+            min: axis.vec2(child_main, min_cross),
+            max: axis.vec2(child_main, max_cross),
+        };
 
         for &child in &node.children {
             let child_size = ctx.calculate_layout(child, child_constraints);
-            size = size.max(child_size);
+            cross = cross.max(axis.get_cross_axis(child_size));
         }
 
-        // TODO: If our children overflowed the size set in the panel, we should
-        // recompute the layout of our children. If any of our children depend
-        // on our size, their layout will change next frame.
-
-        *self.size.borrow_mut() = size;
-
-        input.constrain(size)
+        axis.vec2(main, cross)
     }
 
     fn paint(&self, mut ctx: PaintContext<'_>) {
-        let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
-        let mut rect = PaintRect::new(layout_node.rect);
-        rect.color = colors::BACKGROUND_2;
-        rect.add(ctx.paint);
+        let rect = ctx.layout.get(ctx.dom.current()).unwrap().rect;
+        let mut background = PaintRect::new(rect);
+        background.color = self.props.color;
+        background.add(ctx.paint);
 
-        let node = ctx.dom.get_current();
-        for &child in &node.children {
-            ctx.paint(child);
+        if !self.collapsed {
+            let node = ctx.dom.get_current();
+            for &child in &node.children {
+                ctx.paint(child);
+            }
+        }
+
+        if self.props.resizable {
+            let handle = self.handle_rect(rect);
+
+            let (handle, color) = if self.handle_hovered || self.drag.is_some() {
+                (handle, self.props.handle_active_color)
+            } else {
+                // Draw a thin line along the edge of the panel.
+                let axis = self.props.kind.axis();
+                let offset = if self.props.kind.handle_at_end() {
+                    axis.get_main_axis(handle.size()) - 1.0
+                } else {
+                    0.0
+                };
+
+                let line = Rect::from_pos_size(
+                    handle.pos() + axis.vec2(offset, 0.0),
+                    axis.vec2(1.0, axis.get_cross_axis(handle.size())),
+                );
+                (line, self.props.handle_color)
+            };
+
+            let mut paint_rect = PaintRect::new(handle);
+            paint_rect.color = color;
+            paint_rect.add(ctx.paint);
         }
     }
 
     fn event_interest(&self) -> EventInterest {
-        EventInterest::MOUSE_INSIDE | EventInterest::MOUSE_OUTSIDE
+        EventInterest::MOUSE_ALL
     }
 
-    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
-        match event {
-            WidgetEvent::MouseMoved(Some(_pos)) => {
-                // TODO: How do we know where the mouse is relative to our
-                // widget? We don't have access to the LayoutDom here.
+    fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        if !self.props.resizable {
+            return EventResponse::Bubble;
+        }
+
+        let rect = ctx.layout.get(ctx.dom.current()).unwrap().rect;
+        let axis = self.props.kind.axis();
+
+        match *event {
+            WidgetEvent::MouseButtonChanged {
+                button: MouseButton::One,
+                down: true,
+                inside: true,
+                position,
+                ..
+            } if self.handle_rect(rect).contains_point(position) => {
+                if self.double_click.click() {
+                    self.size = None;
+                    self.collapsed = false;
+                    self.drag = None;
+                } else {
+                    let start_size = if self.collapsed { 0.0 } else { self.size() };
+
+                    self.drag = Some(PanelDrag {
+                        start_mouse: axis.get_main_axis(position),
+                        start_size,
+                    });
+                }
+
+                EventResponse::Sink
+            }
+
+            WidgetEvent::MouseButtonChanged {
+                button: MouseButton::One,
+                down: false,
+                ..
+            } => match self.drag.take() {
+                Some(_) => EventResponse::Sink,
+                None => EventResponse::Bubble,
+            },
+
+            WidgetEvent::MouseMoved(Some(position)) => {
+                self.handle_hovered = self.handle_rect(rect).contains_point(position);
+
+                if self.drag.is_some() {
+                    self.drag_to(axis.get_main_axis(position));
+                    EventResponse::Sink
+                } else {
+                    EventResponse::Bubble
+                }
+            }
+
+            WidgetEvent::MouseMoved(None) | WidgetEvent::MouseLeave => {
+                self.handle_hovered = false;
                 EventResponse::Bubble
             }
+
             _ => EventResponse::Bubble,
         }
     }
@@ -0,0 +1,172 @@
+use yakui_core::geometry::{Constraints, Dim, Vec2};
+use yakui_core::widget::Widget;
+use yakui_core::Response;
+
+use crate::auto_builders;
+use crate::util::widget_children;
+
+/**
+Positions its child inside of a [Stack][super::Stack] using insets from the
+edges of the stack.
+
+Insets and sizes are expressed as [`Dim`] values, so they can be in pixels, a
+percentage of the stack's size, or both. Setting two opposing insets stretches
+the child between those edges, ignoring the size on that axis. Axes without any
+constraints let the child pick its own size, starting from the left or top edge.
+
+Positioned children do not affect the size of the stack. Outside of a stack,
+this widget lays out its children like a regular container.
+
+Responds with [PositionedResponse].
+
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::Positioned;
+use yakui::Dim;
+
+yakui::stack(|| {
+    yakui::colored_box(yakui::Color::BLUE, [200.0, 100.0]);
+
+    // Pinned to the bottom right corner with a 10% margin.
+    Positioned::new()
+        .right(Dim::percent(0.1))
+        .bottom(Dim::percent(0.1))
+        .show(|| {
+            yakui::label("Corner");
+        });
+
+    // Stretched along the top edge.
+    Positioned::new()
+        .left(Dim::ZERO)
+        .right(Dim::ZERO)
+        .height(Dim::pixels(4.0))
+        .show(|| {
+            yakui::colored_box(yakui::Color::RED, [0.0, 0.0]);
+        });
+});
+```
+*/
+#[derive(Debug, Clone, Default)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Positioned {
+    pub left: Option<Dim>,
+    pub top: Option<Dim>,
+    pub right: Option<Dim>,
+    pub bottom: Option<Dim>,
+    pub width: Option<Dim>,
+    pub height: Option<Dim>,
+}
+
+auto_builders!(Positioned {
+    left: Option<Dim>,
+    top: Option<Dim>,
+    right: Option<Dim>,
+    bottom: Option<Dim>,
+    width: Option<Dim>,
+    height: Option<Dim>,
+});
+
+impl Positioned {
+    /// Creates a `Positioned` with no insets, placing its child in the top
+    /// left corner of the stack at its natural size.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a `Positioned` that stretches its child to fill the stack.
+    pub fn fill() -> Self {
+        Self {
+            left: Some(Dim::ZERO),
+            top: Some(Dim::ZERO),
+            right: Some(Dim::ZERO),
+            bottom: Some(Dim::ZERO),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the constraints that a positioned child should be laid out with
+    /// inside of a stack of the given size.
+    pub fn constraints(&self, stack_size: Vec2) -> Constraints {
+        let (min_x, max_x) = axis_constraints(self.left, self.right, self.width, stack_size.x);
+        let (min_y, max_y) = axis_constraints(self.top, self.bottom, self.height, stack_size.y);
+
+        Constraints {
+            min: Vec2::new(min_x, min_y),
+            max: Vec2::new(max_x, max_y),
+        }
+    }
+
+    /// Returns the position of a positioned child with the given size inside
+    /// of a stack of the given size.
+    pub fn position(&self, stack_size: Vec2, child_size: Vec2) -> Vec2 {
+        Vec2::new(
+            axis_position(self.left, self.right, stack_size.x, child_size.x),
+            axis_position(self.top, self.bottom, stack_size.y, child_size.y),
+        )
+    }
+
+    #[track_caller]
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<PositionedResponse> {
+        widget_children::<PositionedWidget, F>(children, self)
+    }
+}
+
+fn axis_constraints(
+    start: Option<Dim>,
+    end: Option<Dim>,
+    size: Option<Dim>,
+    stack_size: f32,
+) -> (f32, f32) {
+    let length = match (start, end, size) {
+        (Some(start), Some(end), _) => {
+            Some(stack_size - start.resolve(stack_size) - end.resolve(stack_size))
+        }
+        (_, _, Some(size)) => Some(size.resolve(stack_size)),
+        _ => None,
+    };
+
+    match length {
+        Some(length) => {
+            let length = length.max(0.0);
+            (length, length)
+        }
+        None => (0.0, f32::INFINITY),
+    }
+}
+
+fn axis_position(start: Option<Dim>, end: Option<Dim>, stack_size: f32, child_size: f32) -> f32 {
+    match (start, end) {
+        (Some(start), _) => start.resolve(stack_size),
+        (None, Some(end)) => stack_size - end.resolve(stack_size) - child_size,
+        (None, None) => 0.0,
+    }
+}
+
+#[derive(Debug)]
+pub struct PositionedWidget {
+    props: Positioned,
+}
+
+impl PositionedWidget {
+    /// The props that this widget was last updated with.
+    pub fn props(&self) -> &Positioned {
+        &self.props
+    }
+}
+
+pub type PositionedResponse = ();
+
+impl Widget for PositionedWidget {
+    type Props<'a> = Positioned;
+    type Response = PositionedResponse;
+
+    fn new() -> Self {
+        Self {
+            props: Positioned::new(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+}
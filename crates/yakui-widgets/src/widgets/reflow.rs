@@ -2,7 +2,7 @@ use yakui_core::geometry::{Constraints, Dim2, Vec2};
 use yakui_core::widget::{LayoutContext, Widget};
 use yakui_core::{Alignment, Flow, Pivot, Response};
 
-use crate::util::widget_children;
+use crate::util::{current_layout_direction, widget_children};
 
 /**
 Changes the flow behavior a widget tree, allowing it to break out of list, grid,
@@ -53,7 +53,12 @@ impl Widget for ReflowWidget {
     }
 
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
-        self.props = props;
+        let direction = current_layout_direction();
+        self.props = Reflow {
+            anchor: props.anchor.resolve(direction),
+            pivot: props.pivot.resolve(direction),
+            ..props
+        };
     }
 
     fn flow(&self) -> Flow {
@@ -2,14 +2,14 @@ use std::cell::{Cell, RefCell};
 
 use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
 use yakui_core::paint::{PaintDom, PaintRect, Pipeline};
-use yakui_core::widget::{LayoutContext, PaintContext, Widget};
-use yakui_core::{Response, TextureId};
+use yakui_core::widget::{IntrinsicContext, LayoutContext, PaintContext, Widget};
+use yakui_core::{IntrinsicSize, LayoutDirection, Response, TextureId};
 
 use crate::auto_builders;
 use crate::font::{FontSelection, Fonts};
 use crate::style::{TextAlignment, TextStyle};
 use crate::text_renderer::{GlyphRender, Kind, TextGlobalState};
-use crate::util::widget;
+use crate::util::{current_layout_direction, widget};
 
 /**
 Renders text. You probably want to use [Text][super::Text] instead, which
@@ -96,6 +96,7 @@ pub struct RenderTextWidget {
     props: RenderText,
     text: String,
     style: TextStyle,
+    layout_direction: LayoutDirection,
     text_changed: Cell<bool>,
 
     max_size: Cell<Option<(Option<f32>, Option<f32>)>>,
@@ -127,6 +128,7 @@ impl Widget for RenderTextWidget {
             props: RenderText::new(),
             text: String::default(),
             style: TextStyle::default(),
+            layout_direction: LayoutDirection::default(),
             text_changed: Cell::new(true),
 
             max_size: Cell::default(),
@@ -154,6 +156,12 @@ impl Widget for RenderTextWidget {
             self.text_changed.set(true);
         }
 
+        let layout_direction = current_layout_direction();
+        if layout_direction != self.layout_direction {
+            self.layout_direction = layout_direction;
+            self.text_changed.set(true);
+        }
+
         if scroll != self.scroll {
             self.scroll_changed.set(true)
         }
@@ -223,7 +231,7 @@ impl Widget for RenderTextWidget {
                     &self.text,
                     &fonts.font_selection.get_cosmic_attrs(&self.style.font),
                     cosmic_text::Shaping::Advanced,
-                    self.style.align.into(),
+                    self.style.align.to_cosmic(self.layout_direction),
                 );
             }
 
@@ -252,6 +260,7 @@ impl Widget for RenderTextWidget {
                     Some(max_width)
                         if !self.props.inline
                             && (self.style.align != TextAlignment::Start
+                                || self.layout_direction.is_rtl()
                                 || buffer.layout_runs().any(|run| run.rtl)) =>
                     {
                         max_width.ceil()
@@ -281,10 +290,66 @@ impl Widget for RenderTextWidget {
                 buffer.shape_until_scroll(&mut fonts.font_system, text_changed);
             }
 
+            // Report where the first line's baseline is so that containers can
+            // line text up by it.
+            if let Some(run) = buffer.layout_runs().next() {
+                let baseline = (run.line_y / ctx.layout.scale_factor()).round();
+                ctx.layout.set_baseline(ctx.dom, baseline);
+            }
+
             self.text_size.get()
         })
     }
 
+    fn intrinsic_size(&self, ctx: IntrinsicContext<'_>, kind: IntrinsicSize, extent: f32) -> f32 {
+        let scale_factor = ctx.layout.scale_factor();
+        let fonts = ctx.dom.get_global_or_init(Fonts::default);
+
+        fonts.with_inner(|fonts| {
+            let physical_size = match kind {
+                IntrinsicSize::MinWidth => measure_text_min_width(
+                    &mut fonts.font_system,
+                    &mut fonts.font_selection,
+                    &self.text,
+                    &self.style,
+                    scale_factor,
+                ),
+                IntrinsicSize::MaxWidth => measure_text_width(
+                    &mut fonts.font_system,
+                    &mut fonts.font_selection,
+                    &self.text,
+                    &self.style,
+                    scale_factor,
+                    None,
+                ),
+                IntrinsicSize::MinHeight | IntrinsicSize::MaxHeight => {
+                    let max_width = extent.max(self.props.min_width);
+                    let max_width = if max_width.is_normal() {
+                        Some((max_width * scale_factor).ceil())
+                    } else {
+                        None
+                    };
+
+                    measure_text_height(
+                        &mut fonts.font_system,
+                        &mut fonts.font_selection,
+                        &self.text,
+                        &self.style,
+                        scale_factor,
+                        max_width,
+                    )
+                }
+            };
+
+            let size = (physical_size.ceil() / scale_factor).ceil();
+            if kind.is_width() {
+                size.max(self.props.min_width)
+            } else {
+                size
+            }
+        })
+    }
+
     fn paint(&self, ctx: PaintContext<'_>) {
         let fonts = ctx.dom.get_global_or_init(Fonts::default);
         let layout_rect = ctx.layout.get(ctx.dom.current()).unwrap().rect;
@@ -455,6 +520,36 @@ pub fn measure_text_width(
         .unwrap_or_default()
 }
 
+/// Measures the width of the widest word in a particular string of text, which
+/// is the narrowest the text can be wrapped without overflowing.
+///
+/// Note: The returned width is in physical unit.
+#[must_use]
+pub fn measure_text_min_width(
+    font_system: &mut cosmic_text::FontSystem,
+    font_selection: &mut FontSelection,
+    text: &str,
+    style: &TextStyle,
+    scale_factor: f32,
+) -> f32 {
+    let mut buffer = cosmic_text::Buffer::new(font_system, style.to_metrics(scale_factor));
+    buffer.set_wrap(cosmic_text::Wrap::Word);
+    buffer.set_size(Some(0.0), None);
+    buffer.set_text(
+        text,
+        &font_selection.get_cosmic_attrs(&style.font),
+        cosmic_text::Shaping::Advanced,
+        None,
+    );
+    buffer.shape_until_scroll(font_system, false);
+
+    buffer
+        .layout_runs()
+        .map(|run| run.line_w)
+        .max_by(|a, b| a.partial_cmp(b).unwrap())
+        .unwrap_or_default()
+}
+
 /// Measures the height of a particular string of text, with the given max width and FontSystem.
 /// Wraps according to the max width.
 ///
@@ -1,39 +1,54 @@
 use yakui_core::geometry::{Color, Constraints, Vec2};
-use yakui_core::widget::{LayoutContext, PaintContext, Widget};
-use yakui_core::Response;
+use yakui_core::paint::Fill;
+use yakui_core::widget::{IntrinsicContext, LayoutContext, PaintContext, Widget};
+use yakui_core::{IntrinsicSize, Response};
 
 use crate::border::{Border, BorderRadius};
+use crate::shadow::BoxShadow;
 use crate::util::{widget, widget_children};
 use crate::{auto_builders, shapes};
 
 /**
 A colored box with rounded corners that can contain children.
 
+The box can be filled with a gradient as well as a single color, and can cast
+a [BoxShadow]. Setting `clip` clips the children to the rounded corners of the
+box, and `anti_alias` smooths out its edges without multisampling.
+
 Responds with [RoundRectResponse].
 */
 #[derive(Debug, Clone)]
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct RoundRect {
-    pub color: Color,
+    pub color: Fill,
     pub min_size: Vec2,
     pub border: Option<Border>,
     pub radius: BorderRadius,
+    pub shadow: Option<BoxShadow>,
+    pub clip: bool,
+    pub anti_alias: bool,
 }
 
 auto_builders!(RoundRect {
-    color: Color,
+    color: Fill,
     min_size: Vec2,
     border: Option<Border>,
     radius: BorderRadius,
+    shadow: Option<BoxShadow>,
+    clip: bool,
+    anti_alias: bool,
 });
 
 impl RoundRect {
     pub fn new<T: Into<BorderRadius>>(radius: T) -> Self {
         Self {
-            color: Color::WHITE,
+            color: Color::WHITE.into(),
             min_size: Vec2::ZERO,
             radius: radius.into(),
             border: None,
+            shadow: None,
+            clip: false,
+            anti_alias: false,
         }
     }
 
@@ -70,6 +85,10 @@ impl Widget for RoundRectWidget {
     }
 
     fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        if self.props.clip {
+            ctx.layout.enable_clipping(ctx.dom);
+        }
+
         let node = ctx.dom.get_current();
         let mut size = self.props.min_size;
 
@@ -78,20 +97,40 @@ impl Widget for RoundRectWidget {
             size = size.max(child_size);
         }
 
+        ctx.layout.propagate_baseline(ctx.dom, &node.children);
         input.constrain_min(size)
     }
 
+    fn intrinsic_size(&self, ctx: IntrinsicContext<'_>, kind: IntrinsicSize, extent: f32) -> f32 {
+        let size = self.default_intrinsic_size(ctx, kind, extent);
+        size.max(kind.get(self.props.min_size))
+    }
+
     fn paint(&self, mut ctx: PaintContext<'_>) {
         let node = ctx.dom.get_current();
         let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
 
+        if let Some(shadow) = self.props.shadow {
+            shapes::Shadow::new(layout_node.rect, self.props.radius, shadow).add(ctx.paint);
+        }
+
         shapes::RoundedRectangle::new(layout_node.rect, self.props.radius)
-            .color(self.props.color)
+            .color(self.props.color.clone())
             .border(self.props.border)
+            .anti_alias(self.props.anti_alias)
             .add(ctx.paint);
 
+        if self.props.clip {
+            let shape = self.props.radius.clip_shape(layout_node.rect);
+            ctx.paint.push_clip_shape(shape);
+        }
+
         for &child in &node.children {
             ctx.paint(child);
         }
+
+        if self.props.clip {
+            ctx.paint.pop_clip_shape();
+        }
     }
 }
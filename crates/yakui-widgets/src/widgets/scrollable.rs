@@ -1,38 +1,291 @@
 use std::cell::Cell;
+use std::rc::Rc;
 
+use yakui_core::dom::Dom;
 use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
-use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
+use yakui_core::input::{Modifiers, MouseButton};
+use yakui_core::layout::LayoutDom;
+use yakui_core::paint::PaintRect;
 use yakui_core::widget::{EventContext, LayoutContext, PaintContext, Widget};
-use yakui_core::Response;
+use yakui_core::{context, Direction, Response, WidgetId};
 
-use crate::util::widget_children;
+use crate::util;
+use crate::{auto_builders, colors};
 
+const SCROLLBAR_MIN_THUMB_LENGTH: f32 = 16.0;
+
+/// How much of the remaining distance an animated scroll covers each frame at
+/// 60 frames per second.
+const ANIMATION_FACTOR: f32 = 0.25;
+
+/// How much of the distance past the edge of the content an overscrolled
+/// scrollable springs back each frame at 60 frames per second.
+const SPRING_FACTOR: f32 = 0.2;
+
+/// How much of its velocity a fling keeps each frame at 60 frames per second.
+const FLING_DECAY: f32 = 0.95;
+
+/// The speed in logical pixels per second below which a fling stops.
+const FLING_MIN_SPEED: f32 = 10.0;
+
+/// How much of each new velocity sample is used while dragging the content.
+const VELOCITY_SMOOTHING: f32 = 0.5;
+
+/// How much dragging moves the content once it has been pulled past its edge.
+const OVERSCROLL_RESISTANCE: f32 = 0.5;
+
+/// How close an animated scroll needs to get to its target before it stops.
+const ANIMATION_SNAP_DISTANCE: f32 = 0.5;
+
+/**
+A container that lets its children be bigger than itself along one or both
+axes, scrolling them with the mouse wheel. Holding Shift while using the mouse
+wheel scrolls horizontally.
+
+Scrollbars are drawn on top of the children along the right and bottom edges.
+Their thumbs can be dragged, and clicking their tracks scrolls by a page.
+
+When a widget inside of a scrollable is selected, the scrollable scrolls to
+bring it into view. To scroll from code, pass a [ScrollHandle].
+
+Widgets inside of a scrollable can find out which part of it is visible with
+[ScrollViewport::current], which is how [VirtualList](crate::widgets::VirtualList)
+only builds the items that can be seen.
+
+Smooth scrolling animates each step of the mouse wheel instead of jumping.
+Kinetic scrolling lets the content be dragged directly, keeps it moving with
+momentum when released, and lets it be pulled up to `max_overscroll` pixels
+past its edges before springing back.
+
+Responds with [ScrollableResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+yakui::scroll_vertical(|| {
+    for i in 0..100 {
+        yakui::label(format!("Line {i}"));
+    }
+});
+```
+*/
 #[derive(Debug)]
 #[must_use = "yakui widgets do nothing if you don't `show` them"]
 pub struct Scrollable {
     pub direction: Option<ScrollDirection>,
+    /// Whether scrollbars should only be shown while the mouse is over the
+    /// scrollable region or a scrollbar is being dragged.
+    pub auto_hide_scrollbars: bool,
+    pub scrollbar_thickness: f32,
+    pub scrollbar_color: Color,
+    pub scrollbar_track_color: Color,
+    /// A handle that can be used to read and change the scroll position.
+    pub handle: Option<ScrollHandle>,
+    /// Whether mouse wheel steps should be animated instead of applied
+    /// immediately.
+    pub smooth_scrolling: bool,
+    /// Whether the content can be dragged and flung with the mouse or touch.
+    pub kinetic_scrolling: bool,
+    /// How far past the edge of the content kinetic scrolling can go before
+    /// springing back.
+    pub max_overscroll: f32,
 }
 
+auto_builders!(Scrollable {
+    direction: Option<ScrollDirection>,
+    auto_hide_scrollbars: bool,
+    scrollbar_thickness: f32,
+    scrollbar_color: Color,
+    scrollbar_track_color: Color,
+    handle: Option<ScrollHandle>,
+    smooth_scrolling: bool,
+    kinetic_scrolling: bool,
+    max_overscroll: f32,
+});
+
 impl Scrollable {
+    fn with_direction(direction: Option<ScrollDirection>) -> Self {
+        Scrollable {
+            direction,
+            auto_hide_scrollbars: false,
+            scrollbar_thickness: 8.0,
+            scrollbar_color: colors::TEXT_MUTED,
+            scrollbar_track_color: colors::BACKGROUND_1.with_alpha(0.5),
+            handle: None,
+            smooth_scrolling: false,
+            kinetic_scrolling: false,
+            max_overscroll: 64.0,
+        }
+    }
+
     pub fn none() -> Self {
-        Scrollable { direction: None }
+        Self::with_direction(None)
     }
 
     pub fn vertical() -> Self {
-        Scrollable {
-            direction: Some(ScrollDirection::Y),
-        }
+        Self::with_direction(Some(ScrollDirection::Y))
+    }
+
+    pub fn horizontal() -> Self {
+        Self::with_direction(Some(ScrollDirection::X))
+    }
+
+    pub fn both() -> Self {
+        Self::with_direction(Some(ScrollDirection::Both))
     }
 
     #[track_caller]
     pub fn show<F: FnOnce()>(self, children: F) -> Response<ScrollableResponse> {
-        widget_children::<ScrollableWidget, F>(children, self)
+        let dom = context::dom();
+        let scrolls = self.direction.is_some();
+        let res = dom.begin_widget::<ScrollableWidget>(self);
+
+        if scrolls {
+            let scope_index = dom.dynamic_scope().push_scope();
+            dom.get_mut(res.id).unwrap().dynamic_scope_index = Some(scope_index);
+
+            dom.dynamic_scope().write_item(ScrollViewport {
+                id: res.id,
+                visible: Rect::from_pos_size(res.scroll_position, res.viewport_size),
+                shift: Cell::new(Vec2::ZERO),
+            });
+        }
+
+        children();
+
+        if scrolls {
+            dom.dynamic_scope().pop_scope();
+        }
+
+        dom.end_widget::<ScrollableWidget>(res.id);
+        res
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ScrollDirection {
+    X,
     Y,
+    Both,
+}
+
+impl ScrollDirection {
+    pub fn scrolls_x(&self) -> bool {
+        matches!(self, Self::X | Self::Both)
+    }
+
+    pub fn scrolls_y(&self) -> bool {
+        matches!(self, Self::Y | Self::Both)
+    }
+}
+
+/**
+Controls the scroll position of a [Scrollable] from outside of it.
+
+Handles are cheap to clone, and every clone refers to the same scroll
+position. Keep one around between frames, such as with
+[use_state](crate::use_state), and pass it to the scrollable each frame.
+
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::{ScrollHandle, Scrollable};
+
+let handle = yakui::use_state(ScrollHandle::new);
+
+if yakui::button("Back to top").clicked {
+    handle.borrow().animate_to(yakui::Vec2::ZERO);
+}
+
+Scrollable::vertical()
+    .handle(handle.borrow().clone())
+    .show(|| {
+        for i in 0..100 {
+            yakui::label(format!("Line {i}"));
+        }
+    });
+```
+*/
+#[derive(Debug, Clone, Default)]
+pub struct ScrollHandle {
+    inner: Rc<ScrollHandleInner>,
+}
+
+#[derive(Debug, Default)]
+struct ScrollHandleInner {
+    position: Cell<Vec2>,
+    request: Cell<Option<ScrollRequest>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ScrollRequest {
+    Jump(Vec2),
+    Animate(Vec2),
+}
+
+impl ScrollHandle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The scroll position of the scrollable this handle was last shown with.
+    pub fn scroll_position(&self) -> Vec2 {
+        self.inner.position.get()
+    }
+
+    /// Immediately scroll to the given position the next time the scrollable
+    /// is shown.
+    pub fn set_scroll_position(&self, position: Vec2) {
+        self.inner.request.set(Some(ScrollRequest::Jump(position)));
+    }
+
+    /// Smoothly scroll to the given position over the next few frames.
+    pub fn animate_to(&self, position: Vec2) {
+        self.inner
+            .request
+            .set(Some(ScrollRequest::Animate(position)));
+    }
+}
+
+/**
+The part of the closest enclosing [Scrollable]'s content that is visible this
+frame. Widgets that are expensive to build in full can use it to only build
+what can be seen.
+*/
+#[derive(Debug)]
+pub struct ScrollViewport {
+    /// The scrollable that is showing this viewport.
+    pub id: WidgetId,
+
+    /// The visible region, relative to the top left corner of the
+    /// scrollable's content.
+    pub visible: Rect,
+
+    shift: Cell<Vec2>,
+}
+
+impl ScrollViewport {
+    /// Returns the viewport of the closest enclosing scrollable, if there is
+    /// one. Must be called while widgets are being built.
+    pub fn current() -> Option<Rc<Self>> {
+        util::read_scope::<Self>()
+    }
+
+    /// Returns where a widget inside of the scrollable was placed relative to
+    /// the top left corner of the scrollable's content. Only available once
+    /// both have been laid out, such as while painting.
+    pub fn content_offset(&self, dom: &Dom, layout: &LayoutDom, id: WidgetId) -> Option<Vec2> {
+        let content = *dom.get(self.id)?.children.first()?;
+        let content_pos = layout.get(content)?.rect.pos();
+        Some(layout.get(id)?.rect.pos() - content_pos)
+    }
+
+    /// Scrolls by the given amount before the scrollable positions its
+    /// content. Widgets that grow or shrink above the visible region during
+    /// layout can use this to keep what's visible in place.
+    pub fn shift(&self, delta: Vec2) {
+        self.shift.set(self.shift.get() + delta);
+    }
 }
 
 #[derive(Debug)]
@@ -40,9 +293,249 @@ pub struct ScrollableWidget {
     props: Scrollable,
     scroll_position: Cell<Vec2>,
     canvas_size: Cell<Vec2>,
+    viewport_size: Cell<Vec2>,
+    laid_out: Cell<bool>,
+    animation_target: Option<Vec2>,
+    hovering: bool,
+    dragging: Option<ThumbDrag>,
+
+    /// The last mouse position while the content is being dragged with
+    /// kinetic scrolling.
+    content_drag: Option<Vec2>,
+
+    /// How fast the content is being scrolled with kinetic scrolling, in
+    /// logical pixels per second.
+    velocity: Vec2,
+
+    /// The scroll position as of the last update, used to measure velocity.
+    last_position: Vec2,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ThumbDrag {
+    axis: Direction,
+    start_mouse: f32,
+    start_scroll: f32,
 }
 
-pub type ScrollableResponse = ();
+#[derive(Debug, Clone, Copy)]
+pub struct ScrollableResponse {
+    /// How far the children are scrolled from their origin. Can be outside
+    /// of the content while a kinetic scrollable is overscrolled.
+    pub scroll_position: Vec2,
+
+    /// The size of the children being scrolled.
+    pub content_size: Vec2,
+
+    /// The size of the visible region.
+    pub viewport_size: Vec2,
+}
+
+/// The geometry of one of a [`ScrollableWidget`]'s scrollbars, in absolute
+/// coordinates.
+#[derive(Debug, Clone, Copy)]
+struct Scrollbar {
+    track: Rect,
+    thumb: Rect,
+    /// How far the content scrolls per pixel that the thumb moves.
+    scroll_per_pixel: f32,
+}
+
+impl ScrollableWidget {
+    fn scrolls(&self, axis: Direction) -> bool {
+        match (self.props.direction, axis) {
+            (Some(direction), Direction::Right) => direction.scrolls_x(),
+            (Some(direction), Direction::Down) => direction.scrolls_y(),
+            (None, _) => false,
+        }
+    }
+
+    fn max_scroll_position(&self) -> Vec2 {
+        (self.canvas_size.get() - self.viewport_size.get()).max(Vec2::ZERO)
+    }
+
+    /// How far past the edges of the content the scroll position can go.
+    fn overscroll_limit(&self) -> f32 {
+        if self.props.kinetic_scrolling {
+            self.props.max_overscroll.max(0.0)
+        } else {
+            0.0
+        }
+    }
+
+    fn clamp_scroll_position(&self, position: Vec2, overscroll: f32) -> Vec2 {
+        let min = Vec2::splat(-overscroll);
+
+        // Until the first layout, the scrollable doesn't know how far it can
+        // scroll, so positions set from a handle are kept as-is.
+        let mut position = if self.laid_out.get() {
+            position.clamp(min, self.max_scroll_position() + overscroll)
+        } else {
+            position.max(min)
+        };
+
+        if !self.scrolls(Direction::Right) {
+            position.x = 0.0;
+        }
+
+        if !self.scrolls(Direction::Down) {
+            position.y = 0.0;
+        }
+
+        position
+    }
+
+    fn set_scroll_position(&self, position: Vec2) {
+        self.set_clamped_scroll_position(self.clamp_scroll_position(position, 0.0));
+    }
+
+    /// Sets the scroll position, allowing it to go past the edges of the
+    /// content as far as kinetic scrolling allows.
+    fn set_overscrolled_position(&self, position: Vec2) {
+        let overscroll = self.overscroll_limit();
+        self.set_clamped_scroll_position(self.clamp_scroll_position(position, overscroll));
+    }
+
+    fn set_clamped_scroll_position(&self, position: Vec2) {
+        self.scroll_position.set(position);
+
+        if let Some(handle) = &self.props.handle {
+            handle.inner.position.set(position);
+        }
+    }
+
+    fn stop_motion(&mut self) {
+        self.animation_target = None;
+        self.velocity = Vec2::ZERO;
+    }
+
+    fn step_animation(&mut self, dt: f32) {
+        let Some(target) = self.animation_target else {
+            return;
+        };
+
+        let target = self.clamp_scroll_position(target, 0.0);
+        let current = self.scroll_position.get();
+
+        if current.distance(target) <= ANIMATION_SNAP_DISTANCE {
+            self.animation_target = None;
+            self.set_scroll_position(target);
+        } else {
+            let t = frame_fraction(ANIMATION_FACTOR, dt);
+            self.set_overscrolled_position(current.lerp(target, t));
+        }
+    }
+
+    fn step_fling(&mut self, dt: f32) {
+        if self.velocity == Vec2::ZERO {
+            return;
+        }
+
+        let position = self.scroll_position.get() + self.velocity * dt;
+        self.set_overscrolled_position(position);
+
+        self.velocity *= FLING_DECAY.powf(dt * 60.0);
+        if self.velocity.length() < FLING_MIN_SPEED {
+            self.velocity = Vec2::ZERO;
+        }
+
+        // Once the content is flung past its edge, the spring takes over.
+        let position = self.scroll_position.get();
+        let bounded = self.clamp_scroll_position(position, 0.0);
+        if position.x != bounded.x {
+            self.velocity.x = 0.0;
+        }
+        if position.y != bounded.y {
+            self.velocity.y = 0.0;
+        }
+    }
+
+    fn step_spring(&mut self, dt: f32) {
+        if !self.laid_out.get() {
+            return;
+        }
+
+        let position = self.scroll_position.get();
+        let bounded = self.clamp_scroll_position(position, 0.0);
+
+        if position == bounded {
+            return;
+        }
+
+        if position.distance(bounded) <= ANIMATION_SNAP_DISTANCE {
+            self.set_scroll_position(bounded);
+        } else {
+            let t = frame_fraction(SPRING_FACTOR, dt);
+            self.set_overscrolled_position(position.lerp(bounded, t));
+        }
+    }
+
+    fn scrollbars_visible(&self) -> bool {
+        !self.props.auto_hide_scrollbars || self.hovering || self.dragging.is_some()
+    }
+
+    /// Computes the geometry of the scrollbar that scrolls along the given
+    /// axis, if that axis can scroll and has content to scroll.
+    fn scrollbar(&self, rect: Rect, axis: Direction) -> Option<Scrollbar> {
+        if !self.scrolls(axis) {
+            return None;
+        }
+
+        let viewport = axis.get_main_axis(self.viewport_size.get());
+        let content = axis.get_main_axis(self.canvas_size.get());
+        if content <= viewport || viewport <= 0.0 {
+            return None;
+        }
+
+        let thickness = self.props.scrollbar_thickness;
+
+        // When both scrollbars are shown, leave room in the corner so that
+        // they don't overlap.
+        let other = match axis {
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Down,
+        };
+        let other_shown = self.scrolls(other)
+            && other.get_main_axis(self.canvas_size.get())
+                > other.get_main_axis(self.viewport_size.get());
+        let track_length = if other_shown {
+            viewport - thickness
+        } else {
+            viewport
+        };
+
+        let cross = axis.get_cross_axis(rect.size()) - thickness;
+        let track = Rect::from_pos_size(
+            rect.pos() + axis.vec2(0.0, cross),
+            axis.vec2(track_length, thickness),
+        );
+
+        let thumb_length = (track_length * viewport / content)
+            .max(SCROLLBAR_MIN_THUMB_LENGTH)
+            .min(track_length);
+        let max_scroll = content - viewport;
+        let thumb_travel = track_length - thumb_length;
+        let progress =
+            (axis.get_main_axis(self.scroll_position.get()) / max_scroll).clamp(0.0, 1.0);
+
+        let thumb = Rect::from_pos_size(
+            track.pos() + axis.vec2(progress * thumb_travel, 0.0),
+            axis.vec2(thumb_length, thickness),
+        );
+
+        let scroll_per_pixel = if thumb_travel > 0.0 {
+            max_scroll / thumb_travel
+        } else {
+            0.0
+        };
+
+        Some(Scrollbar {
+            track,
+            thumb,
+            scroll_per_pixel,
+        })
+    }
+}
 
 impl Widget for ScrollableWidget {
     type Props<'a> = Scrollable;
@@ -53,11 +546,56 @@ impl Widget for ScrollableWidget {
             props: Scrollable::none(),
             scroll_position: Cell::new(Vec2::ZERO),
             canvas_size: Cell::new(Vec2::ZERO),
+            viewport_size: Cell::new(Vec2::ZERO),
+            laid_out: Cell::new(false),
+            animation_target: None,
+            hovering: false,
+            dragging: None,
+            content_drag: None,
+            velocity: Vec2::ZERO,
+            last_position: Vec2::ZERO,
         }
     }
 
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
         self.props = props;
+
+        let request = self
+            .props
+            .handle
+            .as_ref()
+            .and_then(|h| h.inner.request.take());
+        match request {
+            Some(ScrollRequest::Jump(position)) => {
+                self.stop_motion();
+                self.set_scroll_position(position);
+            }
+            Some(ScrollRequest::Animate(position)) => {
+                self.stop_motion();
+                self.animation_target = Some(position);
+            }
+            None => self.set_overscrolled_position(self.scroll_position.get()),
+        }
+
+        let dt = context::dom().delta_time();
+        if self.content_drag.is_some() {
+            if dt > 0.0 {
+                let sample = (self.scroll_position.get() - self.last_position) / dt;
+                self.velocity = self.velocity.lerp(sample, VELOCITY_SMOOTHING);
+            }
+        } else {
+            self.step_fling(dt);
+            self.step_spring(dt);
+        }
+
+        self.step_animation(dt);
+        self.last_position = self.scroll_position.get();
+
+        ScrollableResponse {
+            scroll_position: self.scroll_position.get(),
+            content_size: self.canvas_size.get(),
+            viewport_size: self.viewport_size.get(),
+        }
     }
 
     fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
@@ -66,13 +604,15 @@ impl Widget for ScrollableWidget {
         let node = ctx.dom.get_current();
         let mut canvas_size = Vec2::ZERO;
 
-        let child_constraints = match self.props.direction {
-            None => constraints,
-            Some(ScrollDirection::Y) => Constraints {
-                min: Vec2::new(constraints.min.x, 0.0),
-                max: Vec2::new(constraints.max.x, f32::INFINITY),
-            },
-        };
+        let mut child_constraints = constraints;
+        if self.scrolls(Direction::Right) {
+            child_constraints.min.x = 0.0;
+            child_constraints.max.x = f32::INFINITY;
+        }
+        if self.scrolls(Direction::Down) {
+            child_constraints.min.y = 0.0;
+            child_constraints.max.y = f32::INFINITY;
+        }
 
         for &child in &node.children {
             let child_size = ctx.calculate_layout(child, child_constraints);
@@ -81,21 +621,17 @@ impl Widget for ScrollableWidget {
         self.canvas_size.set(canvas_size);
 
         let size = constraints.constrain(canvas_size);
+        self.viewport_size.set(size);
+        self.laid_out.set(true);
 
-        let max_scroll_position = (canvas_size - size).max(Vec2::ZERO);
-        let mut scroll_position = self
-            .scroll_position
-            .get()
-            .min(max_scroll_position)
-            .max(Vec2::ZERO);
-
-        match self.props.direction {
-            None => scroll_position = Vec2::ZERO,
-            Some(ScrollDirection::Y) => scroll_position.x = 0.0,
-        }
-
-        self.scroll_position.set(scroll_position);
+        let viewport = node
+            .dynamic_scope_index
+            .and_then(|index| ctx.dom.dynamic_scope().get::<ScrollViewport>(index))
+            .filter(|viewport| viewport.id == ctx.dom.current());
+        let shift = viewport.map_or(Vec2::ZERO, |viewport| viewport.shift.take());
+        self.set_overscrolled_position(self.scroll_position.get() + shift);
 
+        let scroll_position = self.scroll_position.get();
         for &child in &node.children {
             ctx.layout.set_pos(child, -scroll_position);
         }
@@ -109,20 +645,202 @@ impl Widget for ScrollableWidget {
         for &child in &node.children {
             ctx.paint(child);
         }
+
+        if !self.scrollbars_visible() {
+            return;
+        }
+
+        let rect = ctx.layout.get(ctx.dom.current()).unwrap().rect;
+        for axis in [Direction::Down, Direction::Right] {
+            let Some(scrollbar) = self.scrollbar(rect, axis) else {
+                continue;
+            };
+
+            let mut track = PaintRect::new(scrollbar.track);
+            track.color = self.props.scrollbar_track_color;
+            track.add(ctx.paint);
+
+            let mut thumb = PaintRect::new(scrollbar.thumb);
+            thumb.color = self.props.scrollbar_color;
+            thumb.add(ctx.paint);
+        }
     }
 
     fn event_interest(&self) -> EventInterest {
-        EventInterest::MOUSE_INSIDE
+        EventInterest::MOUSE_ALL
     }
 
-    fn event(&mut self, _ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+    fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
         match *event {
-            WidgetEvent::MouseScroll { delta, .. } => {
+            WidgetEvent::MouseEnter => {
+                self.hovering = true;
+                EventResponse::Bubble
+            }
+
+            WidgetEvent::MouseLeave => {
+                self.hovering = false;
+                EventResponse::Bubble
+            }
+
+            WidgetEvent::MouseScroll { delta, modifiers } => {
+                if self.props.direction.is_none() {
+                    return EventResponse::Bubble;
+                }
+
+                // Shift only turns the wheel sideways when there's a sideways
+                // direction to scroll in.
+                let sideways =
+                    modifiers.contains(Modifiers::SHIFT) && self.scrolls(Direction::Right);
+                let delta = if sideways {
+                    Vec2::new(delta.y, delta.x)
+                } else {
+                    delta
+                };
+
                 let pos = self.scroll_position.get();
-                self.scroll_position.set(pos + delta);
+                if self.props.smooth_scrolling {
+                    let target = self.animation_target.unwrap_or(pos) + delta;
+                    self.velocity = Vec2::ZERO;
+                    self.animation_target = Some(self.clamp_scroll_position(target, 0.0));
+                } else {
+                    self.stop_motion();
+                    self.set_scroll_position(pos + delta);
+                }
+
                 EventResponse::Sink
             }
+
+            WidgetEvent::ScrollIntoView(target) => {
+                if self.props.direction.is_none() {
+                    return EventResponse::Bubble;
+                }
+
+                let rect = ctx.layout.get(ctx.dom.current()).unwrap().rect;
+                let (start, end) = (rect.pos(), rect.max());
+                let delta = Vec2::new(
+                    util::scroll_into_view(start.x, end.x, target.pos().x, target.max().x),
+                    util::scroll_into_view(start.y, end.y, target.pos().y, target.max().y),
+                );
+
+                let pos = self.scroll_position.get();
+                self.stop_motion();
+                self.set_scroll_position(pos + delta);
+                EventResponse::Sink
+            }
+
+            WidgetEvent::MouseButtonChanged {
+                button: MouseButton::One,
+                down,
+                inside,
+                position,
+                ..
+            } => {
+                if !down {
+                    let thumb_drag = self.dragging.take();
+                    let content_drag = self.content_drag.take();
+
+                    return match (thumb_drag, content_drag) {
+                        (None, None) => EventResponse::Bubble,
+                        _ => EventResponse::Sink,
+                    };
+                }
+
+                if !inside || self.props.direction.is_none() {
+                    return EventResponse::Bubble;
+                }
+
+                let rect = ctx.layout.get(ctx.dom.current()).unwrap().rect;
+                let scrollbars = if self.scrollbars_visible() {
+                    [Direction::Down, Direction::Right].as_slice()
+                } else {
+                    &[]
+                };
+
+                for &axis in scrollbars {
+                    let Some(scrollbar) = self.scrollbar(rect, axis) else {
+                        continue;
+                    };
+
+                    let scroll = self.scroll_position.get();
+
+                    if scrollbar.thumb.contains_point(position) {
+                        self.stop_motion();
+                        self.dragging = Some(ThumbDrag {
+                            axis,
+                            start_mouse: axis.get_main_axis(position),
+                            start_scroll: axis.get_main_axis(scroll),
+                        });
+
+                        return EventResponse::Sink;
+                    }
+
+                    if scrollbar.track.contains_point(position) {
+                        // Clicking the track scrolls by a page towards the
+                        // mouse.
+                        let page = axis.get_main_axis(self.viewport_size.get());
+                        let before_thumb = axis.get_main_axis(position)
+                            < axis.get_main_axis(scrollbar.thumb.pos());
+                        let page = if before_thumb { -page } else { page };
+
+                        self.stop_motion();
+                        self.set_scroll_position(scroll + axis.vec2(page, 0.0));
+                        return EventResponse::Sink;
+                    }
+                }
+
+                if self.props.kinetic_scrolling {
+                    self.stop_motion();
+                    self.content_drag = Some(position);
+                    return EventResponse::Sink;
+                }
+
+                EventResponse::Bubble
+            }
+
+            WidgetEvent::MouseMoved(Some(position)) => {
+                if let Some(last) = self.content_drag {
+                    self.content_drag = Some(position);
+
+                    let scroll = self.scroll_position.get();
+                    let mut target = scroll - (position - last);
+
+                    // Dragging past the edge of the content gets harder the
+                    // further it goes.
+                    let bounded = self.clamp_scroll_position(target, 0.0);
+                    let overscrolled = target.cmpne(bounded);
+                    let resisted = scroll.lerp(target, OVERSCROLL_RESISTANCE);
+                    target = Vec2::select(overscrolled, resisted, target);
+
+                    self.set_overscrolled_position(target);
+                    return EventResponse::Sink;
+                }
+
+                let Some(drag) = self.dragging else {
+                    return EventResponse::Bubble;
+                };
+
+                let rect = ctx.layout.get(ctx.dom.current()).unwrap().rect;
+                let Some(scrollbar) = self.scrollbar(rect, drag.axis) else {
+                    return EventResponse::Bubble;
+                };
+
+                let moved = drag.axis.get_main_axis(position) - drag.start_mouse;
+                let main = drag.start_scroll + moved * scrollbar.scroll_per_pixel;
+
+                let scroll = self.scroll_position.get();
+                let cross = drag.axis.get_cross_axis(scroll);
+                self.set_scroll_position(drag.axis.vec2(main, cross));
+
+                EventResponse::Sink
+            }
+
             _ => EventResponse::Bubble,
         }
     }
 }
+
+/// Converts a fraction of the remaining distance to cover each frame at 60
+/// frames per second into the fraction to cover over `dt` seconds.
+fn frame_fraction(per_frame: f32, dt: f32) -> f32 {
+    1.0 - (1.0 - per_frame).powf(dt * 60.0)
+}
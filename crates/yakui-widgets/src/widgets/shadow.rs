@@ -0,0 +1,89 @@
+use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::Response;
+
+use crate::border::BorderRadius;
+use crate::shadow::BoxShadow;
+use crate::util::widget_children;
+use crate::{auto_builders, shapes};
+
+/**
+Casts a shadow behind its children, shaped like a box with the given corner
+radius that's the size of the children.
+
+This is useful for giving floating UI like dropdowns and tooltips some depth
+without changing how they're drawn.
+
+Responds with [ShadowResponse].
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Shadow {
+    pub shadow: BoxShadow,
+    pub radius: BorderRadius,
+}
+
+auto_builders!(Shadow {
+    shadow: BoxShadow,
+    radius: BorderRadius,
+});
+
+impl Shadow {
+    pub fn new(shadow: BoxShadow) -> Self {
+        Self {
+            shadow,
+            radius: BorderRadius::ZERO,
+        }
+    }
+
+    #[track_caller]
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<ShadowResponse> {
+        widget_children::<ShadowWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct ShadowWidget {
+    props: Shadow,
+}
+
+pub type ShadowResponse = ();
+
+impl Widget for ShadowWidget {
+    type Props<'a> = Shadow;
+    type Response = ShadowResponse;
+
+    fn new() -> Self {
+        Self {
+            props: Shadow::new(BoxShadow::default()),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        let mut size = Vec2::ZERO;
+
+        for &child in &node.children {
+            let child_size = ctx.calculate_layout(child, input);
+            size = size.max(child_size);
+        }
+
+        ctx.layout.propagate_baseline(ctx.dom, &node.children);
+        input.constrain_min(size)
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        let node = ctx.dom.get_current();
+        let layout_node = ctx.layout.get(ctx.dom.current()).unwrap();
+
+        shapes::Shadow::new(layout_node.rect, self.props.radius, self.props.shadow).add(ctx.paint);
+
+        for &child in &node.children {
+            ctx.paint(child);
+        }
+    }
+}
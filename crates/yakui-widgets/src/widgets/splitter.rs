@@ -0,0 +1,287 @@
+use std::cell::Cell;
+
+use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
+use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
+use yakui_core::input::MouseButton;
+use yakui_core::paint::PaintRect;
+use yakui_core::widget::{EventContext, LayoutContext, PaintContext, Widget};
+use yakui_core::{context, Direction, Response};
+
+use crate::util::{DoubleClick, SplitSizes};
+use crate::{auto_builders, colors};
+
+use super::Stack;
+
+/**
+Divides the available space between two panes with a divider that can be
+dragged to change how much space each pane gets.
+
+Double clicking the divider resets it to `default_ratio`. Neither pane can be
+made smaller than `min_size`, unless there isn't enough space for both.
+
+Responds with [SplitterResponse].
+
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::Splitter;
+
+Splitter::horizontal().default_ratio(0.3).show(
+    || {
+        yakui::label("Files");
+    },
+    || {
+        yakui::label("Editor");
+    },
+);
+```
+*/
+#[derive(Debug)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Splitter {
+    /// The direction the two panes are laid out in.
+    pub direction: Direction,
+    /// The fraction of the space given to the first pane until the user drags
+    /// the divider.
+    pub default_ratio: f32,
+    pub min_size: f32,
+    pub divider_thickness: f32,
+    pub divider_color: Color,
+    /// The color of the divider while it's hovered or dragged.
+    pub divider_active_color: Color,
+}
+
+auto_builders!(Splitter {
+    default_ratio: f32,
+    min_size: f32,
+    divider_thickness: f32,
+    divider_color: Color,
+    divider_active_color: Color,
+});
+
+impl Splitter {
+    pub fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            default_ratio: 0.5,
+            min_size: 50.0,
+            divider_thickness: 6.0,
+            divider_color: colors::BACKGROUND_3,
+            divider_active_color: colors::TEXT_MUTED,
+        }
+    }
+
+    /// A splitter with its panes side by side.
+    pub fn horizontal() -> Self {
+        Self::new(Direction::Right)
+    }
+
+    /// A splitter with its panes on top of each other.
+    pub fn vertical() -> Self {
+        Self::new(Direction::Down)
+    }
+
+    #[track_caller]
+    pub fn show(self, first: impl FnOnce(), second: impl FnOnce()) -> Response<SplitterResponse> {
+        let dom = context::dom();
+        let response = dom.begin_widget::<SplitterWidget>(self);
+
+        Stack::new().show(first);
+        Stack::new().show(second);
+
+        dom.end_widget::<SplitterWidget>(response.id);
+        response
+    }
+}
+
+#[derive(Debug)]
+pub struct SplitterWidget {
+    props: Splitter,
+    /// The ratio the user dragged the divider to, if they have.
+    ratio: Option<f32>,
+    drag: Option<SplitterDrag>,
+    divider_hovered: bool,
+    double_click: DoubleClick,
+
+    /// How the panes divided the space as of the last layout.
+    sizes: Cell<SplitSizes>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SplitterDrag {
+    start_mouse: f32,
+    start_size: f32,
+}
+
+#[derive(Debug)]
+pub struct SplitterResponse {
+    /// The fraction of the space given to the first pane.
+    pub ratio: f32,
+}
+
+impl SplitterWidget {
+    fn ratio(&self) -> f32 {
+        self.ratio
+            .unwrap_or(self.props.default_ratio)
+            .clamp(0.0, 1.0)
+    }
+
+    fn divider_rect(&self, rect: Rect) -> Rect {
+        let direction = self.props.direction;
+        let cross = direction.get_cross_axis(rect.size());
+
+        Rect::from_pos_size(
+            rect.pos() + direction.vec2(self.sizes.get().first, 0.0),
+            direction.vec2(self.props.divider_thickness, cross),
+        )
+    }
+}
+
+impl Widget for SplitterWidget {
+    type Props<'a> = Splitter;
+    type Response = SplitterResponse;
+
+    fn new() -> Self {
+        Self {
+            props: Splitter::horizontal(),
+            ratio: None,
+            drag: None,
+            divider_hovered: false,
+            double_click: DoubleClick::default(),
+            sizes: Cell::new(SplitSizes::default()),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+        self.double_click.advance(context::dom().delta_time());
+
+        SplitterResponse {
+            ratio: self.ratio(),
+        }
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        let direction = self.props.direction;
+        let thickness = self.props.divider_thickness;
+
+        let max_main = direction.get_main_axis(input.max);
+        let main = if max_main.is_finite() {
+            max_main
+        } else {
+            direction
+                .get_main_axis(input.min)
+                .max(self.props.min_size * 2.0 + thickness)
+        };
+
+        let sizes = SplitSizes::new(main, thickness, self.ratio(), self.props.min_size);
+        self.sizes.set(sizes);
+
+        let max_cross = direction.get_cross_axis(input.max);
+        let min_cross = if max_cross.is_finite() {
+            max_cross
+        } else {
+            direction.get_cross_axis(input.min)
+        };
+
+        let mut cross = min_cross;
+        let mut next_main = 0.0;
+        for (&child, main) in node.children.iter().zip([sizes.first, sizes.second()]) {
+            let constraints = Constraints {
+                min: direction.vec2(main, min_cross),
+                max: direction.vec2(main, max_cross),
+            };
+
+            let child_size = ctx.calculate_layout(child, constraints);
+            cross = cross.max(direction.get_cross_axis(child_size));
+
+            ctx.layout.set_pos(child, direction.vec2(next_main, 0.0));
+            next_main += main + thickness;
+        }
+
+        direction.vec2(main, cross)
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        let rect = ctx.layout.get(ctx.dom.current()).unwrap().rect;
+
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            ctx.paint(child);
+        }
+
+        let mut divider = PaintRect::new(self.divider_rect(rect));
+        divider.color = if self.divider_hovered || self.drag.is_some() {
+            self.props.divider_active_color
+        } else {
+            self.props.divider_color
+        };
+        divider.add(ctx.paint);
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::MOUSE_ALL
+    }
+
+    fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        let rect = ctx.layout.get(ctx.dom.current()).unwrap().rect;
+        let direction = self.props.direction;
+
+        match *event {
+            WidgetEvent::MouseButtonChanged {
+                button: MouseButton::One,
+                down: true,
+                inside: true,
+                position,
+                ..
+            } if self.divider_rect(rect).contains_point(position) => {
+                if self.double_click.click() {
+                    self.ratio = None;
+                    self.drag = None;
+                } else {
+                    self.drag = Some(SplitterDrag {
+                        start_mouse: direction.get_main_axis(position),
+                        start_size: self.sizes.get().first,
+                    });
+                }
+
+                EventResponse::Sink
+            }
+
+            WidgetEvent::MouseButtonChanged {
+                button: MouseButton::One,
+                down: false,
+                ..
+            } => match self.drag.take() {
+                Some(_) => EventResponse::Sink,
+                None => EventResponse::Bubble,
+            },
+
+            WidgetEvent::MouseMoved(Some(position)) => {
+                self.divider_hovered = self.divider_rect(rect).contains_point(position);
+
+                let Some(drag) = self.drag else {
+                    return EventResponse::Bubble;
+                };
+
+                let delta = direction.get_main_axis(position) - drag.start_mouse;
+                if delta != 0.0 {
+                    self.double_click.cancel();
+                }
+
+                if let Some(ratio) = self.sizes.get().ratio_for(drag.start_size + delta) {
+                    self.ratio = Some(ratio);
+                }
+
+                EventResponse::Sink
+            }
+
+            WidgetEvent::MouseMoved(None) | WidgetEvent::MouseLeave => {
+                self.divider_hovered = false;
+                EventResponse::Bubble
+            }
+
+            _ => EventResponse::Bubble,
+        }
+    }
+}
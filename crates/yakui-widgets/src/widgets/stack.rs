@@ -1,13 +1,21 @@
-use yakui_core::{widget::Widget, Response};
+use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::widget::{IntrinsicContext, LayoutContext, Widget};
+use yakui_core::{IntrinsicSize, Response};
 
 use crate::util::widget_children;
 
+use super::PositionedWidget;
+
 /**
 A [Stack] widget. This widget does nothing interesting on its own, but
 when used "inside" other layouts, such as [List](crate::widgets::List),
 it will stacks its own children, rather than following the layout of its own parent.
 This internal layouting is just using yakui's default layout algorithm.
 
+Children wrapped in [Positioned](crate::widgets::Positioned) don't affect the
+size of the stack. Instead, they are sized and placed relative to its edges
+after the rest of the children have been laid out.
+
 Responds with [StackResponse].
 
 Shorthand:
@@ -59,4 +67,53 @@ impl Widget for StackWidget {
     fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {
         // nothing here
     }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+
+        let mut positioned = Vec::new();
+        let mut stacked = Vec::new();
+        let mut size = Vec2::ZERO;
+        for &child in &node.children {
+            let child_node = ctx.dom.get(child).unwrap();
+            if let Some(widget) = child_node
+                .widget
+                .as_any()
+                .downcast_ref::<PositionedWidget>()
+            {
+                positioned.push((child, widget.props().clone()));
+                continue;
+            }
+
+            let child_size = ctx.calculate_layout(child, constraints);
+            size = size.max(child_size);
+            stacked.push(child);
+        }
+
+        let size = constraints.constrain_min(size);
+
+        for (child, props) in positioned {
+            let child_size = ctx.calculate_layout(child, props.constraints(size));
+            ctx.layout.set_pos(child, props.position(size, child_size));
+        }
+
+        ctx.layout.propagate_baseline(ctx.dom, &stacked);
+        size
+    }
+
+    fn intrinsic_size(&self, ctx: IntrinsicContext<'_>, kind: IntrinsicSize, extent: f32) -> f32 {
+        let node = ctx.dom.get_current();
+
+        let mut size: f32 = 0.0;
+        for &child in &node.children {
+            let child_node = ctx.dom.get(child).unwrap();
+            if child_node.widget.as_any().is::<PositionedWidget>() {
+                continue;
+            }
+
+            size = size.max(ctx.intrinsic_size(child, kind, extent));
+        }
+
+        size
+    }
 }
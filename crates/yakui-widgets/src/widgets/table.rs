@@ -0,0 +1,773 @@
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::collections::BTreeSet;
+use std::ops::Range;
+use std::rc::Rc;
+
+use yakui_core::context;
+use yakui_core::event::{EventInterest, EventResponse, WidgetEvent};
+use yakui_core::geometry::{Color, Constraints, Rect, Vec2};
+use yakui_core::input::{Modifiers, MouseButton};
+use yakui_core::paint::{PaintMesh, PaintRect, Vertex};
+use yakui_core::widget::{EventContext, LayoutContext, PaintContext, Widget};
+use yakui_core::Response;
+
+use crate::style::TextStyle;
+use crate::util::widget_children;
+use crate::{auto_builders, colors};
+
+use super::{RenderText, ScrollViewport};
+
+/// How many rows to build before the table has been laid out for the first
+/// time and knows how tall it is.
+const FALLBACK_VISIBLE_ROWS: usize = 50;
+
+/// How far from a column's edge the mouse can be to start resizing it.
+const RESIZE_HANDLE_SIZE: f32 = 6.0;
+
+/// The size of the triangle shown next to the title of the sorted column.
+const SORT_INDICATOR_SIZE: f32 = 8.0;
+
+/**
+A table of cells with a header row. Columns can be resized by dragging the edge
+of their header and sorted by clicking their header, and rows can be selected
+with the mouse, holding Ctrl to toggle and Shift to select a range.
+
+Only rows that are visible are built each frame, so tables can contain a very
+large number of rows. To make this possible, every row has the same height.
+
+A table with a bounded height scrolls its rows with the mouse wheel under a
+fixed header. A table inside of a vertical
+[Scrollable](crate::widgets::Scrollable) can instead be as tall as all of its
+rows, in which case it builds the rows within the scrollable's visible region.
+Outside of a scrollable, a table with an unbounded height builds every row.
+
+The table does not own any data. Row indices passed to the cell callback and
+reported in the response are display positions: when
+[`TableResponse::sort`] changes, the caller should reorder its data to match.
+The selection is cleared whenever the sort changes.
+
+Responds with [TableResponse].
+
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::{Table, TableColumn};
+
+let names = ["Alice", "Bob", "Carol"];
+let columns = vec![TableColumn::new("#"), TableColumn::new("Name")];
+
+Table::new(columns, names.len()).show(|row, column| {
+    match column {
+        0 => yakui::text(14.0, row.to_string()),
+        _ => yakui::text(14.0, names[row]),
+    };
+});
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Table {
+    pub columns: Vec<TableColumn>,
+    pub row_count: usize,
+    pub row_height: f32,
+    pub header_height: f32,
+    /// Added space on the left and right of every cell.
+    pub cell_padding: f32,
+    pub selection_mode: TableSelectionMode,
+    pub header_text_style: TextStyle,
+    pub header_color: Color,
+    pub row_color: Color,
+    pub alternate_row_color: Color,
+    pub selected_row_color: Color,
+    pub divider_color: Color,
+}
+
+auto_builders!(Table {
+    row_height: f32,
+    header_height: f32,
+    cell_padding: f32,
+    selection_mode: TableSelectionMode,
+    header_text_style: TextStyle,
+    header_color: Color,
+    row_color: Color,
+    alternate_row_color: Color,
+    selected_row_color: Color,
+    divider_color: Color,
+});
+
+impl Table {
+    pub fn new(columns: Vec<TableColumn>, row_count: usize) -> Self {
+        Self {
+            columns,
+            row_count,
+            row_height: 24.0,
+            header_height: 28.0,
+            cell_padding: 6.0,
+            selection_mode: TableSelectionMode::Multiple,
+            header_text_style: TextStyle::label().font_size(14.0),
+            header_color: colors::BACKGROUND_3,
+            row_color: colors::BACKGROUND_2,
+            alternate_row_color: colors::BACKGROUND_2.adjust(1.15),
+            selected_row_color: Color::hex(0x3b5e8c),
+            divider_color: colors::BACKGROUND_1,
+        }
+    }
+
+    /// Show the table, calling `cell` with the row and column index of every
+    /// visible cell. Each call should show the contents of that cell.
+    #[track_caller]
+    pub fn show<F: FnMut(usize, usize)>(self, mut cell: F) -> Response<TableResponse> {
+        let dom = context::dom();
+        let column_count = self.columns.len();
+
+        let response = dom.begin_widget::<TableWidget>(self);
+
+        let body = dom.begin_widget::<TableBodyWidget>(response.body.clone());
+        for row in response.visible_rows.clone() {
+            for column in 0..column_count {
+                widget_children::<TableCellWidget, _>(|| cell(row, column), ());
+            }
+        }
+        dom.end_widget::<TableBodyWidget>(body.id);
+
+        dom.end_widget::<TableWidget>(response.id);
+        response
+    }
+}
+
+/// Describes a single column of a [`Table`].
+#[derive(Debug, Clone)]
+pub struct TableColumn {
+    pub title: Cow<'static, str>,
+    /// The initial width of the column. Once the table has been shown, the
+    /// width of the column is controlled by the user.
+    pub width: f32,
+    pub min_width: f32,
+    pub resizable: bool,
+    pub sortable: bool,
+}
+
+auto_builders!(TableColumn {
+    width: f32,
+    min_width: f32,
+    resizable: bool,
+    sortable: bool,
+});
+
+impl TableColumn {
+    pub fn new(title: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            title: title.into(),
+            width: 120.0,
+            min_width: 24.0,
+            resizable: true,
+            sortable: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableSelectionMode {
+    /// Rows cannot be selected.
+    None,
+
+    /// At most one row can be selected at a time.
+    Single,
+
+    /// Any number of rows can be selected using Ctrl and Shift.
+    Multiple,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableSortOrder {
+    Ascending,
+    Descending,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableSort {
+    pub column: usize,
+    pub order: TableSortOrder,
+}
+
+#[derive(Debug)]
+pub struct TableResponse {
+    /// The current width of each column.
+    pub column_widths: Vec<f32>,
+
+    /// The column the table is sorted by, if any.
+    pub sort: Option<TableSort>,
+
+    /// Whether the sort changed since the last time the table was shown.
+    pub sort_changed: bool,
+
+    /// The indices of all selected rows, in ascending order.
+    pub selection: Vec<usize>,
+
+    /// Whether the selection changed since the last time the table was shown.
+    pub selection_changed: bool,
+
+    /// The rows that were built this frame.
+    pub visible_rows: Range<usize>,
+
+    body: TableBody,
+}
+
+#[derive(Debug)]
+pub struct TableWidget {
+    props: Table,
+    column_widths: Vec<f32>,
+    sort: Option<TableSort>,
+    sort_changed: bool,
+    selection: BTreeSet<usize>,
+    selection_anchor: Option<usize>,
+    selection_changed: bool,
+    scroll_offset: f32,
+    viewport_height: Cell<Option<f32>>,
+    resizing: Option<ColumnResize>,
+
+    /// The viewport of the scrollable that this table is inside of, if any.
+    scroll_viewport: Option<Rc<ScrollViewport>>,
+
+    /// Where this table was placed within the scrollable's content when it
+    /// was last painted.
+    content_offset: Cell<Option<Vec2>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ColumnResize {
+    column: usize,
+    start_mouse: f32,
+    start_width: f32,
+}
+
+impl TableWidget {
+    fn visible_rows(&self) -> Range<usize> {
+        let row_count = self.props.row_count;
+        let row_height = self.props.row_height;
+
+        let fallback = 0..row_count.min(FALLBACK_VISIBLE_ROWS);
+        let Some(viewport_height) = self.viewport_height.get() else {
+            return fallback;
+        };
+
+        if row_height <= 0.0 {
+            return fallback;
+        }
+
+        let mut top = self.scroll_offset;
+        let mut bottom = self.scroll_offset + viewport_height;
+
+        // Inside of a scrollable, rows outside of what the scrollable shows
+        // aren't visible either.
+        if let Some(viewport) = &self.scroll_viewport {
+            let Some(offset) = self.content_offset.get() else {
+                return fallback;
+            };
+
+            let body_top = offset.y + self.props.header_height - self.scroll_offset;
+            top = top.max(viewport.visible.pos().y - body_top);
+            bottom = bottom.min(viewport.visible.max().y - body_top);
+        }
+
+        let start = ((top / row_height).floor().max(0.0) as usize).min(row_count);
+        let end = ((bottom / row_height).ceil().max(0.0) as usize).min(row_count);
+        start..end.max(start)
+    }
+
+    fn max_scroll_offset(&self) -> f32 {
+        let content_height = self.props.row_count as f32 * self.props.row_height;
+        let viewport_height = self.viewport_height.get().unwrap_or(0.0);
+        (content_height - viewport_height).max(0.0)
+    }
+
+    /// Returns the left edge of each column, plus the right edge of the last
+    /// column.
+    fn column_edges(&self) -> impl Iterator<Item = f32> + '_ {
+        std::iter::once(0.0).chain(self.column_widths.iter().scan(0.0, |x, width| {
+            *x += width;
+            Some(*x)
+        }))
+    }
+
+    fn header_pressed(&mut self, local: Vec2) {
+        let edges: Vec<f32> = self.column_edges().collect();
+
+        for (column, &right) in edges.iter().skip(1).enumerate() {
+            let resizable = self.props.columns[column].resizable;
+            if resizable && (local.x - right).abs() <= RESIZE_HANDLE_SIZE / 2.0 {
+                self.resizing = Some(ColumnResize {
+                    column,
+                    start_mouse: local.x,
+                    start_width: self.column_widths[column],
+                });
+                return;
+            }
+        }
+
+        let column = edges
+            .windows(2)
+            .position(|edge| local.x >= edge[0] && local.x < edge[1]);
+
+        if let Some(column) = column {
+            if !self.props.columns[column].sortable {
+                return;
+            }
+
+            let order = match self.sort {
+                Some(sort) if sort.column == column && sort.order == TableSortOrder::Ascending => {
+                    TableSortOrder::Descending
+                }
+                _ => TableSortOrder::Ascending,
+            };
+
+            self.sort = Some(TableSort { column, order });
+            self.sort_changed = true;
+
+            if !self.selection.is_empty() {
+                self.selection.clear();
+                self.selection_changed = true;
+            }
+            self.selection_anchor = None;
+        }
+    }
+
+    fn row_pressed(&mut self, row: usize, modifiers: Modifiers) {
+        let toggle = modifiers.intersects(Modifiers::CONTROL | Modifiers::META);
+        let range = modifiers.contains(Modifiers::SHIFT);
+
+        match self.props.selection_mode {
+            TableSelectionMode::None => return,
+            TableSelectionMode::Single => {
+                self.selection.clear();
+                self.selection.insert(row);
+                self.selection_anchor = Some(row);
+            }
+            TableSelectionMode::Multiple => match self.selection_anchor {
+                Some(anchor) if range => {
+                    if !toggle {
+                        self.selection.clear();
+                    }
+
+                    self.selection.extend(anchor.min(row)..=anchor.max(row));
+                }
+                _ => {
+                    if toggle {
+                        if !self.selection.remove(&row) {
+                            self.selection.insert(row);
+                        }
+                    } else {
+                        self.selection.clear();
+                        self.selection.insert(row);
+                    }
+
+                    self.selection_anchor = Some(row);
+                }
+            },
+        }
+
+        self.selection_changed = true;
+    }
+}
+
+impl Widget for TableWidget {
+    type Props<'a> = Table;
+    type Response = TableResponse;
+
+    fn new() -> Self {
+        Self {
+            props: Table::new(Vec::new(), 0),
+            column_widths: Vec::new(),
+            sort: None,
+            sort_changed: false,
+            selection: BTreeSet::new(),
+            selection_anchor: None,
+            selection_changed: false,
+            scroll_offset: 0.0,
+            viewport_height: Cell::new(None),
+            resizing: None,
+            scroll_viewport: None,
+            content_offset: Cell::new(None),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+        self.scroll_viewport = ScrollViewport::current();
+
+        // Columns keep the width the user gave them, but columns that are new
+        // since the last frame start out with their initial width.
+        let column_count = self.props.columns.len();
+        self.column_widths.truncate(column_count);
+        for column in &self.props.columns[self.column_widths.len()..] {
+            self.column_widths.push(column.width.max(column.min_width));
+        }
+
+        if self.sort.is_some_and(|sort| sort.column >= column_count) {
+            self.sort = None;
+            self.sort_changed = true;
+        }
+
+        let row_count = self.props.row_count;
+        if self.selection.last().is_some_and(|&last| last >= row_count) {
+            self.selection.retain(|&row| row < row_count);
+            self.selection_changed = true;
+        }
+
+        self.scroll_offset = self.scroll_offset.clamp(0.0, self.max_scroll_offset());
+        let visible_rows = self.visible_rows();
+
+        for column in &self.props.columns {
+            widget_children::<TableCellWidget, _>(
+                || {
+                    RenderText::new()
+                        .inline(true)
+                        .show(&column.title, &self.props.header_text_style);
+                },
+                (),
+            );
+        }
+
+        let body = TableBody {
+            column_widths: self.column_widths.clone(),
+            first_row: visible_rows.start,
+            selected: visible_rows
+                .clone()
+                .map(|row| self.selection.contains(&row))
+                .collect(),
+            scroll_offset: self.scroll_offset,
+            row_height: self.props.row_height,
+            cell_padding: self.props.cell_padding,
+            row_color: self.props.row_color,
+            alternate_row_color: self.props.alternate_row_color,
+            selected_row_color: self.props.selected_row_color,
+        };
+
+        Self::Response {
+            column_widths: self.column_widths.clone(),
+            sort: self.sort,
+            sort_changed: std::mem::take(&mut self.sort_changed),
+            selection: self.selection.iter().copied().collect(),
+            selection_changed: std::mem::take(&mut self.selection_changed),
+            visible_rows,
+            body,
+        }
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        ctx.layout.enable_clipping(ctx.dom);
+
+        let node = ctx.dom.get_current();
+        let header_height = self.props.header_height;
+        let padding = self.props.cell_padding;
+
+        let total_width: f32 = self.column_widths.iter().sum();
+        let content_height = self.props.row_count as f32 * self.props.row_height;
+        let size = input.constrain(Vec2::new(total_width, header_height + content_height));
+
+        let viewport_height = (size.y - header_height).max(0.0);
+        self.viewport_height.set(Some(viewport_height));
+
+        let column_count = self.props.columns.len();
+        let (header_cells, body) = node
+            .children
+            .split_at(column_count.min(node.children.len()));
+
+        let header = header_cells.iter().zip(self.column_edges());
+        for (index, (&child, x)) in header.enumerate() {
+            // Sortable columns leave room after their title for the sort
+            // indicator, which is painted by the table itself.
+            let mut max_width = self.column_widths[index] - padding * 2.0;
+            if self.props.columns[index].sortable {
+                max_width -= SORT_INDICATOR_SIZE + padding;
+            }
+
+            let constraints = Constraints::loose(Vec2::new(max_width.max(0.0), header_height));
+            let child_size = ctx.calculate_layout(child, constraints);
+            let y = ((header_height - child_size.y) / 2.0).max(0.0);
+            ctx.layout.set_pos(child, Vec2::new(x + padding, y));
+        }
+
+        for &child in body {
+            ctx.calculate_layout(
+                child,
+                Constraints::tight(Vec2::new(size.x, viewport_height)),
+            );
+            ctx.layout.set_pos(child, Vec2::new(0.0, header_height));
+        }
+
+        size
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        if let Some(viewport) = &self.scroll_viewport {
+            let offset = viewport.content_offset(ctx.dom, ctx.layout, ctx.dom.current());
+            self.content_offset.set(offset);
+        }
+
+        let node = ctx.dom.get_current();
+        let rect = ctx.layout.get(ctx.dom.current()).unwrap().rect;
+        let header_height = self.props.header_height;
+
+        let mut header = PaintRect::new(Rect::from_pos_size(
+            rect.pos(),
+            Vec2::new(rect.size().x, header_height),
+        ));
+        header.color = self.props.header_color;
+        header.add(ctx.paint);
+
+        for &child in &node.children {
+            ctx.paint(child);
+        }
+
+        let edges: Vec<f32> = self.column_edges().collect();
+        for (column, edge) in edges.windows(2).enumerate() {
+            let right = rect.pos().x + edge[1];
+
+            let mut divider = PaintRect::new(Rect::from_pos_size(
+                Vec2::new(right - 1.0, rect.pos().y),
+                Vec2::new(1.0, rect.size().y),
+            ));
+            divider.color = self.props.divider_color;
+            divider.add(ctx.paint);
+
+            let Some(sort) = self.sort.filter(|sort| sort.column == column) else {
+                continue;
+            };
+
+            let half = SORT_INDICATOR_SIZE / 2.0;
+            let center = Vec2::new(
+                right - self.props.cell_padding - half,
+                rect.pos().y + header_height / 2.0,
+            );
+
+            let points = match sort.order {
+                TableSortOrder::Ascending => [
+                    Vec2::new(center.x - half, center.y + half / 2.0),
+                    Vec2::new(center.x + half, center.y + half / 2.0),
+                    Vec2::new(center.x, center.y - half / 2.0),
+                ],
+                TableSortOrder::Descending => [
+                    Vec2::new(center.x - half, center.y - half / 2.0),
+                    Vec2::new(center.x, center.y + half / 2.0),
+                    Vec2::new(center.x + half, center.y - half / 2.0),
+                ],
+            };
+
+            let color = self.props.header_text_style.color.to_linear();
+            let vertices = points
+                .into_iter()
+                .map(|pos| Vertex::new(pos, [0.0, 0.0], color));
+            ctx.paint.add_mesh(PaintMesh::new(vertices, [0u32, 1, 2]));
+        }
+
+        let mut header_divider = PaintRect::new(Rect::from_pos_size(
+            Vec2::new(rect.pos().x, rect.pos().y + header_height - 1.0),
+            Vec2::new(rect.size().x, 1.0),
+        ));
+        header_divider.color = self.props.divider_color;
+        header_divider.add(ctx.paint);
+    }
+
+    fn event_interest(&self) -> EventInterest {
+        EventInterest::MOUSE_ALL
+    }
+
+    fn event(&mut self, ctx: EventContext<'_>, event: &WidgetEvent) -> EventResponse {
+        match *event {
+            WidgetEvent::MouseScroll { delta, .. } => {
+                // Tables as tall as all of their rows leave scrolling to
+                // whatever they're inside of.
+                let max = self.max_scroll_offset();
+                if max <= 0.0 {
+                    return EventResponse::Bubble;
+                }
+
+                self.scroll_offset = (self.scroll_offset + delta.y).clamp(0.0, max);
+                EventResponse::Sink
+            }
+
+            WidgetEvent::MouseButtonChanged {
+                button: MouseButton::One,
+                down,
+                inside,
+                position,
+                modifiers,
+            } => {
+                if !down {
+                    return match self.resizing.take() {
+                        Some(_) => EventResponse::Sink,
+                        None => EventResponse::Bubble,
+                    };
+                }
+
+                if !inside {
+                    return EventResponse::Bubble;
+                }
+
+                let rect = ctx.layout.get(ctx.dom.current()).unwrap().rect;
+                let local = position - rect.pos();
+
+                if local.y < self.props.header_height {
+                    self.header_pressed(local);
+                } else if self.props.row_height > 0.0 {
+                    let y = local.y - self.props.header_height + self.scroll_offset;
+                    let row = (y / self.props.row_height).floor() as usize;
+                    let in_columns = local.x < self.column_edges().last().unwrap_or(0.0);
+
+                    if row < self.props.row_count && in_columns {
+                        self.row_pressed(row, modifiers);
+                    }
+                }
+
+                EventResponse::Sink
+            }
+
+            WidgetEvent::MouseMoved(Some(position)) => {
+                let Some(resize) = self.resizing else {
+                    return EventResponse::Bubble;
+                };
+
+                let rect = ctx.layout.get(ctx.dom.current()).unwrap().rect;
+                let local = position - rect.pos();
+
+                let min_width = self.props.columns[resize.column].min_width;
+                let width = resize.start_width + local.x - resize.start_mouse;
+                self.column_widths[resize.column] = width.max(min_width);
+
+                EventResponse::Sink
+            }
+
+            _ => EventResponse::Bubble,
+        }
+    }
+}
+
+/// Internal props for the scrolling region of a [`Table`] that contains its
+/// rows.
+#[derive(Debug, Clone)]
+struct TableBody {
+    column_widths: Vec<f32>,
+    first_row: usize,
+    selected: Vec<bool>,
+    scroll_offset: f32,
+    row_height: f32,
+    cell_padding: f32,
+    row_color: Color,
+    alternate_row_color: Color,
+    selected_row_color: Color,
+}
+
+#[derive(Debug)]
+struct TableBodyWidget {
+    props: TableBody,
+}
+
+impl TableBodyWidget {
+    fn row_top(&self, row: usize) -> f32 {
+        row as f32 * self.props.row_height - self.props.scroll_offset
+    }
+}
+
+impl Widget for TableBodyWidget {
+    type Props<'a> = TableBody;
+    type Response = ();
+
+    fn new() -> Self {
+        Self {
+            props: TableBody {
+                column_widths: Vec::new(),
+                first_row: 0,
+                selected: Vec::new(),
+                scroll_offset: 0.0,
+                row_height: 0.0,
+                cell_padding: 0.0,
+                row_color: Color::CLEAR,
+                alternate_row_color: Color::CLEAR,
+                selected_row_color: Color::CLEAR,
+            },
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        ctx.layout.enable_clipping(ctx.dom);
+
+        let node = ctx.dom.get_current();
+        let column_count = self.props.column_widths.len().max(1);
+        let row_height = self.props.row_height;
+        let padding = self.props.cell_padding;
+
+        let column_edges: Vec<f32> = self
+            .props
+            .column_widths
+            .iter()
+            .scan(0.0, |x, width| {
+                let left = *x;
+                *x += width;
+                Some(left)
+            })
+            .collect();
+
+        for (index, &child) in node.children.iter().enumerate() {
+            let row = self.props.first_row + index / column_count;
+            let column = index % column_count;
+
+            let width = self.props.column_widths.get(column).copied().unwrap_or(0.0);
+            let max_size = Vec2::new((width - padding * 2.0).max(0.0), row_height);
+
+            let child_size = ctx.calculate_layout(child, Constraints::loose(max_size));
+            let x = column_edges.get(column).copied().unwrap_or(0.0) + padding;
+            let y = self.row_top(row) + ((row_height - child_size.y) / 2.0).max(0.0);
+            ctx.layout.set_pos(child, Vec2::new(x, y));
+        }
+
+        input.max
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        let node = ctx.dom.get_current();
+        let rect = ctx.layout.get(ctx.dom.current()).unwrap().rect;
+
+        for (index, &selected) in self.props.selected.iter().enumerate() {
+            let row = self.props.first_row + index;
+
+            let color = if selected {
+                self.props.selected_row_color
+            } else if row % 2 == 1 {
+                self.props.alternate_row_color
+            } else {
+                self.props.row_color
+            };
+
+            let mut background = PaintRect::new(Rect::from_pos_size(
+                Vec2::new(rect.pos().x, rect.pos().y + self.row_top(row)),
+                Vec2::new(rect.size().x, self.props.row_height),
+            ));
+            background.color = color;
+            background.add(ctx.paint);
+        }
+
+        for &child in &node.children {
+            ctx.paint(child);
+        }
+    }
+}
+
+/// Holds the contents of a single cell of a [`Table`].
+#[derive(Debug)]
+struct TableCellWidget;
+
+impl Widget for TableCellWidget {
+    type Props<'a> = ();
+    type Response = ();
+
+    fn new() -> Self {
+        Self
+    }
+
+    fn update(&mut self, _props: Self::Props<'_>) -> Self::Response {}
+}
@@ -9,13 +9,13 @@ use yakui_core::geometry::{Color, Constraints, FlexFit, Vec2};
 use yakui_core::input::{KeyCode, Modifiers, MouseButton};
 use yakui_core::navigation::NavDirection;
 use yakui_core::widget::{EventContext, LayoutContext, PaintContext, Widget};
-use yakui_core::{context, Response};
+use yakui_core::{context, LayoutDirection, Response};
 
 use crate::clipboard::ClipboardHolder;
 use crate::font::Fonts;
 use crate::shapes;
 use crate::style::{TextAlignment, TextStyle};
-use crate::util::widget;
+use crate::util::{current_layout_direction, widget};
 use crate::widgets::RenderTextResponse;
 use crate::{auto_builders, colors, pad};
 
@@ -112,6 +112,7 @@ enum DragState {
 pub struct TextBoxWidget {
     text: String,
     props: TextBox,
+    layout_direction: LayoutDirection,
 
     /// Whether this widget is focused and receiving input from the user.
     active: bool,
@@ -160,6 +161,7 @@ impl Widget for TextBoxWidget {
         Self {
             text: String::new(),
             props: TextBox::new(),
+            layout_direction: LayoutDirection::default(),
 
             active: false,
             activated: false,
@@ -186,7 +188,10 @@ impl Widget for TextBoxWidget {
     }
 
     fn update(&mut self, (props, text): Self::Props<'_>) -> Self::Response {
-        let text_changed_by_caller = text != self.text || props.style != self.props.style;
+        let layout_direction = current_layout_direction();
+        let text_changed_by_caller = text != self.text
+            || props.style != self.props.style
+            || layout_direction != self.layout_direction;
         if text_changed_by_caller {
             self.text_changed_by_cosmic.set(false);
         }
@@ -195,6 +200,7 @@ impl Widget for TextBoxWidget {
             .set(text_changed_by_caller || text_changed_by_cosmic);
 
         self.props = props;
+        self.layout_direction = layout_direction;
         if text != self.text {
             self.text = text.to_string();
         }
@@ -248,7 +254,7 @@ impl Widget for TextBoxWidget {
                 editor.with_buffer_mut(|buffer| {
                     // apply styles
                     for line in buffer.lines.iter_mut() {
-                        line.set_align(self.props.style.align.into());
+                        line.set_align(self.props.style.align.to_cosmic(self.layout_direction));
                     }
 
                     self.is_textbox_empty = !(buffer.lines.len() > 1
@@ -381,7 +387,7 @@ impl Widget for TextBoxWidget {
 
         if let Some(fill_color) = self.props.fill {
             let mut bg = shapes::RoundedRectangle::new(layout_rect, self.props.radius);
-            bg.color = fill_color;
+            bg.color = fill_color.into();
             bg.add(ctx.paint);
         }
 
@@ -447,8 +453,9 @@ impl Widget for TextBoxWidget {
                 } else if self.drag == DragState::Dragging {
                     if let Some(layout) = ctx.layout.get(ctx.dom.current()) {
                         let scale_factor = ctx.layout.scale_factor();
-                        let relative_pos =
-                            position - (layout.rect.pos() + self.props.padding.offset());
+                        let relative_pos = position
+                            - (layout.rect.pos()
+                                + self.props.padding.resolve(self.layout_direction).offset());
                         let text_pos = (relative_pos * scale_factor).round().as_ivec2();
 
                         fonts.with_inner(|fonts| {
@@ -479,7 +486,9 @@ impl Widget for TextBoxWidget {
             } => {
                 if let Some(layout) = ctx.layout.get(ctx.dom.current()) {
                     let scale_factor = ctx.layout.scale_factor();
-                    let relative_pos = position - (layout.rect.pos() + self.props.padding.offset());
+                    let relative_pos = position
+                        - (layout.rect.pos()
+                            + self.props.padding.resolve(self.layout_direction).offset());
                     let text_pos = (relative_pos * scale_factor).round().as_ivec2();
 
                     fonts.with_inner(|fonts| {
@@ -549,6 +558,22 @@ impl Widget for TextBoxWidget {
                     CurrentCursor,
                 }
 
+                // The arrow keys move the cursor visually, so in right-to-left
+                // paragraphs the left arrow moves forward through the text.
+                let cursor_line = editor.cursor().line;
+                let line_rtl = fonts.with_inner(|fonts| {
+                    editor.with_buffer_mut(|buffer| {
+                        buffer
+                            .line_shape(&mut fonts.font_system, cursor_line)
+                            .is_some_and(|shape| shape.rtl)
+                    })
+                });
+                let key = match key {
+                    KeyCode::ArrowLeft if line_rtl => &KeyCode::ArrowRight,
+                    KeyCode::ArrowRight if line_rtl => &KeyCode::ArrowLeft,
+                    key => key,
+                };
+
                 let mut select = None;
                 let mut action = None;
 
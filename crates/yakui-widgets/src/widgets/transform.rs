@@ -0,0 +1,134 @@
+use yakui_core::geometry::{Affine2, Constraints, Vec2};
+use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::{Pivot, Response};
+
+use crate::auto_builders;
+use crate::util::widget_children;
+
+/**
+Applies an affine transform made of a translation, rotation, scale and skew to
+its children when they are painted and hit tested. The transform does not
+affect layout: the widget takes up the same space as its untransformed
+children.
+
+The transform is applied around `origin`, which defaults to the center of the
+widget.
+
+Clipping rectangles can't be rotated, so content inside of a transformed widget
+is clipped to the bounding box of its transformed clipping rectangle.
+
+Responds with [TransformResponse].
+
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::Transform;
+use yakui::Vec2;
+
+Transform::new()
+    .rotation(0.3)
+    .scale(Vec2::splat(1.5))
+    .show(|| {
+        yakui::button("Tilted");
+    });
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Transform {
+    /// Offset in logical pixels, applied after all other transformations.
+    pub translation: Vec2,
+
+    /// Clockwise rotation in radians.
+    pub rotation: f32,
+
+    pub scale: Vec2,
+
+    /// Skew angles in radians along the X and Y axes.
+    pub skew: Vec2,
+
+    /// The point within the widget that stays in place when rotating, scaling
+    /// and skewing.
+    pub origin: Pivot,
+}
+
+auto_builders!(Transform {
+    translation: Vec2,
+    rotation: f32,
+    scale: Vec2,
+    skew: Vec2,
+    origin: Pivot,
+});
+
+impl Transform {
+    /// Creates a `Transform` that leaves its children unchanged.
+    pub fn new() -> Self {
+        Self {
+            translation: Vec2::ZERO,
+            rotation: 0.0,
+            scale: Vec2::ONE,
+            skew: Vec2::ZERO,
+            origin: Pivot::CENTER,
+        }
+    }
+
+    /// Returns the matrix described by this transform, ignoring `origin`.
+    pub fn matrix(&self) -> Affine2 {
+        let skew = Affine2::from_cols(
+            Vec2::new(1.0, self.skew.y.tan()),
+            Vec2::new(self.skew.x.tan(), 1.0),
+            Vec2::ZERO,
+        );
+
+        Affine2::from_translation(self.translation)
+            * Affine2::from_angle(self.rotation)
+            * skew
+            * Affine2::from_scale(self.scale)
+    }
+
+    #[track_caller]
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<TransformResponse> {
+        widget_children::<TransformWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct TransformWidget {
+    props: Transform,
+}
+
+pub type TransformResponse = ();
+
+impl Widget for TransformWidget {
+    type Props<'a> = Transform;
+    type Response = TransformResponse;
+
+    fn new() -> Self {
+        Self {
+            props: Transform::new(),
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, constraints: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        let mut size = Vec2::ZERO;
+        for &child in &node.children {
+            let child_size = ctx.calculate_layout(child, constraints);
+            size = size.max(child_size);
+        }
+
+        let size = constraints.constrain_min(size);
+
+        let origin = self.props.origin.as_vec2() * size;
+        let transform = Affine2::from_translation(origin)
+            * self.props.matrix()
+            * Affine2::from_translation(-origin);
+        ctx.layout.set_transform(ctx.dom, transform);
+
+        ctx.layout.propagate_baseline(ctx.dom, &node.children);
+        size
+    }
+}
@@ -83,6 +83,7 @@ impl Widget for UnconstrainedBoxWidget {
             size = size.max(child_size);
         }
 
+        ctx.layout.propagate_baseline(ctx.dom, &node.children);
         input.constrain_min(size)
     }
 }
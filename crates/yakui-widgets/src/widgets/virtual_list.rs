@@ -0,0 +1,440 @@
+use std::cell::{Cell, RefCell};
+use std::ops::Range;
+use std::rc::Rc;
+
+use yakui_core::context;
+use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::widget::{LayoutContext, PaintContext, Widget};
+use yakui_core::{Direction, Response};
+
+use crate::auto_builders;
+use crate::widgets::ScrollViewport;
+
+/// How many items to build before the list has been laid out for the first
+/// time and knows which items are visible.
+const FALLBACK_VISIBLE_ITEMS: usize = 50;
+
+/**
+A list that only builds the items that are currently visible, which makes it
+suitable for lists with a very large number of items.
+
+Items are built by calling a closure with the index of each visible item. Each
+item can either have a fixed size along the list's direction, or be measured
+as it becomes visible, in which case an estimated size is used for items that
+have not been seen yet.
+
+To scroll the list, put it inside of a [Scrollable](crate::widgets::Scrollable)
+that scrolls along the list's direction. The list takes up the size of all of
+its items, and only builds the ones within the scrollable's visible region.
+Outside of a scrollable, the list builds the items that fit within its own
+size.
+
+Responds with [VirtualListResponse].
+
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+use yakui::widgets::{Scrollable, VirtualList};
+
+Scrollable::vertical().show(|| {
+    VirtualList::fixed(10_000, 24.0).show(|index| {
+        yakui::text(14.0, format!("Item {index}"));
+    });
+});
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct VirtualList {
+    pub direction: Direction,
+    pub item_count: usize,
+    pub item_size: VirtualListItemSize,
+    /// Added space between each item.
+    pub item_spacing: f32,
+    /// How many items to build beyond each edge of the visible region.
+    pub overscan: usize,
+}
+
+auto_builders!(VirtualList {
+    direction: Direction,
+    item_spacing: f32,
+    overscan: usize,
+});
+
+/// Describes how a [`VirtualList`] determines the size of its items along its
+/// direction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VirtualListItemSize {
+    /// Every item has the same size.
+    Fixed(f32),
+
+    /// Items are measured when they are built. Items that have never been
+    /// built are assumed to have the estimated size.
+    Measured { estimate: f32 },
+}
+
+impl VirtualList {
+    pub fn new(item_count: usize, item_size: VirtualListItemSize) -> Self {
+        Self {
+            direction: Direction::Down,
+            item_count,
+            item_size,
+            item_spacing: 0.0,
+            overscan: 1,
+        }
+    }
+
+    pub fn fixed(item_count: usize, item_size: f32) -> Self {
+        Self::new(item_count, VirtualListItemSize::Fixed(item_size))
+    }
+
+    pub fn measured(item_count: usize, estimate: f32) -> Self {
+        Self::new(item_count, VirtualListItemSize::Measured { estimate })
+    }
+
+    /// Show the list, calling `item` with the index of every visible item.
+    /// Each call should show exactly one widget.
+    #[track_caller]
+    pub fn show<F: FnMut(usize)>(self, mut item: F) -> Response<VirtualListResponse> {
+        let dom = context::dom();
+        let response = dom.begin_widget::<VirtualListWidget>(self);
+
+        for index in response.visible_items.clone() {
+            item(index);
+        }
+
+        dom.end_widget::<VirtualListWidget>(response.id);
+        response
+    }
+}
+
+#[derive(Debug)]
+pub struct VirtualListResponse {
+    /// The items that were built this frame.
+    pub visible_items: Range<usize>,
+
+    /// The size of all items in the list, including estimates for items that
+    /// have not been measured yet.
+    pub content_size: f32,
+}
+
+#[derive(Debug)]
+pub struct VirtualListWidget {
+    props: VirtualList,
+
+    /// The viewport of the scrollable that this list is inside of, if any.
+    viewport: Option<Rc<ScrollViewport>>,
+
+    /// Where this list was placed within the scrollable's content when it was
+    /// last painted.
+    content_offset: Cell<Option<Vec2>>,
+
+    /// The size of the list along its direction when it was last laid out.
+    size: Cell<Option<f32>>,
+
+    /// The first item that is at least partially visible. When items before
+    /// it are measured, the scrollable is shifted to keep it in place.
+    anchor: usize,
+
+    measured: RefCell<MeasuredSizes>,
+
+    /// The items that were built this frame.
+    built: Range<usize>,
+}
+
+impl VirtualListWidget {
+    fn stride(&self) -> f32 {
+        match self.props.item_size {
+            VirtualListItemSize::Fixed(size) => size + self.props.item_spacing,
+            VirtualListItemSize::Measured { estimate } => estimate + self.props.item_spacing,
+        }
+    }
+
+    /// Returns the distance from the start of the list to the given item.
+    fn offset_of(&self, measured: &MeasuredSizes, index: usize) -> f32 {
+        let offset = index as f32 * self.stride();
+        match self.props.item_size {
+            VirtualListItemSize::Fixed(_) => offset,
+            VirtualListItemSize::Measured { .. } => offset + measured.difference_before(index),
+        }
+    }
+
+    fn content_size(&self, measured: &MeasuredSizes) -> f32 {
+        let count = self.props.item_count;
+        (self.offset_of(measured, count) - self.props.item_spacing).max(0.0)
+    }
+
+    /// Returns how many items start at an offset for which `pred` is true,
+    /// where `pred` is true for every offset up to some point.
+    fn count_starting(&self, measured: &MeasuredSizes, pred: impl Fn(f32) -> bool) -> usize {
+        let count = self.props.item_count;
+        if count == 0 || !pred(0.0) {
+            return 0;
+        }
+
+        let last = match self.props.item_size {
+            VirtualListItemSize::Fixed(_) => {
+                let (mut low, mut high) = (0, count - 1);
+                while low < high {
+                    let mid = high - (high - low) / 2;
+                    if pred(self.offset_of(measured, mid)) {
+                        low = mid;
+                    } else {
+                        high = mid - 1;
+                    }
+                }
+                low
+            }
+            VirtualListItemSize::Measured { .. } => {
+                measured.last_where(count - 1, self.stride(), pred)
+            }
+        };
+
+        last + 1
+    }
+
+    /// Returns the items that overlap the given span along the list's
+    /// direction.
+    fn items_within(&self, start: f32, end: f32) -> Range<usize> {
+        let measured = self.measured.borrow();
+        let first = self
+            .count_starting(&measured, |offset| offset <= start)
+            .saturating_sub(1);
+        let end = self.count_starting(&measured, |offset| offset < end);
+        first..end.max(first)
+    }
+
+    fn visible_items(&mut self) -> Range<usize> {
+        let direction = self.props.direction;
+        let count = self.props.item_count;
+        let overscan = self.props.overscan;
+
+        let visible = match (&self.viewport, self.content_offset.get(), self.size.get()) {
+            (Some(viewport), Some(offset), _) => {
+                let start = direction.get_main_axis(viewport.visible.pos() - offset);
+                let length = direction.get_main_axis(viewport.visible.size());
+                Some(self.items_within(start.max(0.0), start + length))
+            }
+            (None, _, Some(size)) => Some(self.items_within(0.0, size)),
+            _ => None,
+        };
+
+        let Some(visible) = visible else {
+            self.anchor = 0;
+            return 0..count.min(FALLBACK_VISIBLE_ITEMS);
+        };
+
+        self.anchor = visible.start;
+        visible.start.saturating_sub(overscan)..count.min(visible.end + overscan)
+    }
+}
+
+impl Widget for VirtualListWidget {
+    type Props<'a> = VirtualList;
+    type Response = VirtualListResponse;
+
+    fn new() -> Self {
+        Self {
+            props: VirtualList::fixed(0, 0.0),
+            viewport: None,
+            content_offset: Cell::new(None),
+            size: Cell::new(None),
+            anchor: 0,
+            measured: RefCell::new(MeasuredSizes::default()),
+            built: 0..0,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+        self.viewport = ScrollViewport::current();
+
+        let count = self.props.item_count;
+        match self.props.item_size {
+            VirtualListItemSize::Fixed(_) => self.measured.get_mut().reset(0, 0.0),
+            VirtualListItemSize::Measured { estimate } => {
+                self.measured.get_mut().reset(count, estimate)
+            }
+        }
+
+        self.built = self.visible_items();
+
+        Self::Response {
+            visible_items: self.built.clone(),
+            content_size: self.content_size(&self.measured.borrow()),
+        }
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        ctx.layout.enable_clipping(ctx.dom);
+
+        let node = ctx.dom.get_current();
+        let direction = self.props.direction;
+        let spacing = self.props.item_spacing;
+
+        let cross_max = direction.get_cross_axis(input.max);
+        let child_constraints = match self.props.item_size {
+            VirtualListItemSize::Fixed(size) => Constraints {
+                min: direction.vec2(size, 0.0),
+                max: direction.vec2(size, cross_max),
+            },
+            VirtualListItemSize::Measured { .. } => Constraints {
+                min: Vec2::ZERO,
+                max: direction.vec2(f32::INFINITY, cross_max),
+            },
+        };
+
+        let mut sizes = Vec::with_capacity(node.children.len());
+        let mut cross_size: f32 = 0.0;
+
+        for &child in &node.children {
+            let size = ctx.calculate_layout(child, child_constraints);
+            sizes.push(direction.get_main_axis(size));
+            cross_size = cross_size.max(direction.get_cross_axis(size));
+        }
+
+        let mut measured = self.measured.borrow_mut();
+        if let VirtualListItemSize::Measured { .. } = self.props.item_size {
+            let anchor_before = self.offset_of(&measured, self.anchor);
+
+            for (index, &size) in self.built.clone().zip(&sizes) {
+                measured.set(index, size);
+            }
+
+            // Items before the anchor that turned out to have a different
+            // size than expected would otherwise move everything visible.
+            let moved = self.offset_of(&measured, self.anchor) - anchor_before;
+            if let Some(viewport) = &self.viewport {
+                if moved != 0.0 && self.content_offset.get().is_some() {
+                    viewport.shift(direction.vec2(moved, 0.0));
+                }
+            }
+        }
+
+        let main_size = direction
+            .get_main_axis(input.constrain(direction.vec2(self.content_size(&measured), 0.0)));
+        let cross_size = if cross_max.is_finite() {
+            cross_max
+        } else {
+            cross_size
+        };
+        let size = input.constrain(direction.vec2(main_size, cross_size));
+        self.size.set(Some(direction.get_main_axis(size)));
+
+        let mut pos = self.offset_of(&measured, self.built.start);
+        for (&child, &child_size) in node.children.iter().zip(&sizes) {
+            ctx.layout.set_pos(child, direction.vec2(pos, 0.0));
+            pos += child_size + spacing;
+        }
+
+        size
+    }
+
+    fn paint(&self, mut ctx: PaintContext<'_>) {
+        if let Some(viewport) = &self.viewport {
+            let offset = viewport.content_offset(ctx.dom, ctx.layout, ctx.dom.current());
+            self.content_offset.set(offset);
+        }
+
+        let node = ctx.dom.get_current();
+        for &child in &node.children {
+            ctx.paint(child);
+        }
+    }
+}
+
+/// The sizes of the items in a measured [`VirtualList`]. How much each
+/// measured item differs from the estimate is kept in a Fenwick tree, so that
+/// finding the offset of an item doesn't need to add up every item before it.
+#[derive(Debug, Default)]
+struct MeasuredSizes {
+    estimate: f32,
+    sizes: Vec<Option<f32>>,
+
+    /// Sums of differences from the estimate, where entry `i` covers the
+    /// `i & i.wrapping_neg()` items ending with item `i - 1`.
+    tree: Vec<f64>,
+}
+
+impl MeasuredSizes {
+    /// Resizes to the given number of items. Changing the item count or the
+    /// estimate rebuilds the tree, which takes time proportional to the
+    /// number of items.
+    fn reset(&mut self, count: usize, estimate: f32) {
+        if self.sizes.len() == count && self.estimate == estimate {
+            return;
+        }
+
+        self.estimate = estimate;
+        self.sizes.resize(count, None);
+
+        self.tree = vec![0.0; count + 1];
+        for i in 1..=count {
+            let difference = self.difference(i - 1);
+            self.tree[i] += difference;
+
+            let parent = i + (i & i.wrapping_neg());
+            if parent <= count {
+                self.tree[parent] += self.tree[i];
+            }
+        }
+    }
+
+    fn difference(&self, index: usize) -> f64 {
+        self.sizes[index].map_or(0.0, |size| f64::from(size - self.estimate))
+    }
+
+    fn set(&mut self, index: usize, size: f32) {
+        if index >= self.sizes.len() {
+            return;
+        }
+
+        let delta = f64::from(size - self.estimate) - self.difference(index);
+        self.sizes[index] = Some(size);
+
+        if delta != 0.0 {
+            let mut i = index + 1;
+            while i < self.tree.len() {
+                self.tree[i] += delta;
+                i += i & i.wrapping_neg();
+            }
+        }
+    }
+
+    /// Returns how much the items before the given index differ from the
+    /// estimate in total.
+    fn difference_before(&self, index: usize) -> f32 {
+        let mut sum = 0.0;
+        let mut i = index.min(self.sizes.len());
+        while i > 0 {
+            sum += self.tree[i];
+            i &= i - 1;
+        }
+
+        sum as f32
+    }
+
+    /// Returns the largest index up to `max` whose offset satisfies `pred`,
+    /// given that every item takes up `stride` plus its difference from the
+    /// estimate.
+    fn last_where(&self, max: usize, stride: f32, pred: impl Fn(f32) -> bool) -> usize {
+        let mut index = 0;
+        let mut sum = 0.0;
+        let mut step = max.checked_next_power_of_two().unwrap_or(0);
+
+        while step > 0 {
+            let next = index + step;
+            if next <= max {
+                let next_sum = sum + self.tree[next];
+                let offset = next as f64 * f64::from(stride) + next_sum;
+                if pred(offset as f32) {
+                    index = next;
+                    sum = next_sum;
+                }
+            }
+
+            step /= 2;
+        }
+
+        index
+    }
+}
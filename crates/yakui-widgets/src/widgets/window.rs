@@ -5,8 +5,9 @@ use yakui_core::widget::Widget;
 use yakui_core::Response;
 
 use crate::colors;
+use crate::shadow::BoxShadow;
 use crate::util::widget;
-use crate::widgets::Pad;
+use crate::widgets::{ColoredBox, Pad};
 
 /**
 A floating window within the application.
@@ -54,7 +55,8 @@ impl Widget for WindowWidget {
     fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
         self.props = props;
 
-        crate::colored_box_container(colors::BACKGROUND_2, || {
+        let container = ColoredBox::container(colors::BACKGROUND_2).shadow(BoxShadow::floating());
+        container.show_children(|| {
             crate::column(|| {
                 // Window Title Bar
                 let constraints = Constraints::loose(self.props.initial_size);
@@ -0,0 +1,328 @@
+use yakui_core::geometry::{Constraints, Vec2};
+use yakui_core::widget::{IntrinsicContext, LayoutContext, Widget};
+use yakui_core::{
+    CrossAxisAlignment, Direction, Flow, IntrinsicSize, MainAxisAlignment, MainAxisSize, Response,
+    WidgetId,
+};
+
+use crate::auto_builders;
+use crate::util::widget_children;
+
+/**
+Lays out children along a main axis, breaking onto a new run whenever the next
+child would not fit within the incoming constraints.
+
+Alignment is applied to each run individually. Because children are laid out
+before the size of their run is known, [`CrossAxisAlignment::Stretch`] behaves
+like [`CrossAxisAlignment::Start`].
+
+Responds with [WrapResponse].
+
+Shorthand:
+```rust
+# let _handle = yakui_widgets::DocTest::start();
+yakui::wrap(|| {
+    for i in 0..10 {
+        yakui::label(format!("Tag {i}"));
+    }
+});
+```
+*/
+#[derive(Debug, Clone)]
+#[must_use = "yakui widgets do nothing if you don't `show` them"]
+pub struct Wrap {
+    pub direction: Direction,
+    /// Added space between each item in a run.
+    pub item_spacing: f32,
+    /// Added space between each run.
+    pub run_spacing: f32,
+    pub main_axis_size: MainAxisSize,
+    pub main_axis_alignment: MainAxisAlignment,
+    pub cross_axis_alignment: CrossAxisAlignment,
+}
+
+auto_builders!(Wrap {
+    item_spacing: f32,
+    run_spacing: f32,
+    main_axis_size: MainAxisSize,
+    main_axis_alignment: MainAxisAlignment,
+    cross_axis_alignment: CrossAxisAlignment,
+});
+
+impl Wrap {
+    pub fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            item_spacing: 0.0,
+            run_spacing: 0.0,
+            main_axis_size: MainAxisSize::Max,
+            main_axis_alignment: MainAxisAlignment::Start,
+            cross_axis_alignment: CrossAxisAlignment::Start,
+        }
+    }
+
+    pub fn row() -> Self {
+        Self::new(Direction::Right)
+    }
+
+    pub fn column() -> Self {
+        Self::new(Direction::Down)
+    }
+
+    #[track_caller]
+    pub fn show<F: FnOnce()>(self, children: F) -> Response<WrapResponse> {
+        widget_children::<WrapWidget, F>(children, self)
+    }
+}
+
+#[derive(Debug)]
+pub struct WrapWidget {
+    props: Wrap,
+}
+
+pub type WrapResponse = ();
+
+/// A single line of children within a [`Wrap`].
+#[derive(Debug, Default)]
+struct Run {
+    children: Vec<WidgetId>,
+    main_size: f32,
+    cross_size: f32,
+    /// The largest distance from the top of a child to its baseline, used
+    /// when aligning children by their baselines.
+    ascent: f32,
+    descent: f32,
+}
+
+impl Widget for WrapWidget {
+    type Props<'a> = Wrap;
+    type Response = WrapResponse;
+
+    fn new() -> Self {
+        Self { props: Wrap::row() }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.props = props;
+    }
+
+    fn intrinsic_size(&self, ctx: IntrinsicContext<'_>, kind: IntrinsicSize, extent: f32) -> f32 {
+        let node = ctx.dom.get_current();
+        let direction = self.props.direction;
+        let item_spacing = self.props.item_spacing;
+
+        let children: Vec<_> = node
+            .children
+            .iter()
+            .copied()
+            .filter(|&child| ctx.dom.get(child).unwrap().widget.flow() == Flow::Inline)
+            .collect();
+
+        if kind.direction() == direction {
+            if kind.is_min() {
+                // At its narrowest, every child is placed on its own run.
+                children
+                    .iter()
+                    .map(|&child| ctx.intrinsic_size(child, kind, f32::INFINITY))
+                    .fold(0.0, f32::max)
+            } else {
+                // At its widest, every child fits onto a single run.
+                let total_item_spacing = item_spacing * children.len().saturating_sub(1) as f32;
+
+                children
+                    .iter()
+                    .map(|&child| ctx.intrinsic_size(child, kind, f32::INFINITY))
+                    .sum::<f32>()
+                    + total_item_spacing
+            }
+        } else {
+            // Along the cross axis, we break children into runs the same way
+            // layout would, using each child's preferred main axis size.
+            let mut total_cross = 0.0;
+            let mut run_main = 0.0;
+            let mut run_cross: f32 = 0.0;
+            let mut run_count = 0;
+            let mut run_len = 0;
+
+            for &child in &children {
+                let main = ctx
+                    .intrinsic_size(child, IntrinsicSize::max(direction), f32::INFINITY)
+                    .min(extent);
+                let cross = ctx.intrinsic_size(child, kind, main);
+
+                if run_len > 0 && run_main + item_spacing + main > extent {
+                    total_cross += run_cross;
+                    run_count += 1;
+                    run_main = 0.0;
+                    run_cross = 0.0;
+                    run_len = 0;
+                }
+
+                if run_len > 0 {
+                    run_main += item_spacing;
+                }
+
+                run_main += main;
+                run_cross = run_cross.max(cross);
+                run_len += 1;
+            }
+
+            if run_len > 0 {
+                total_cross += run_cross;
+                run_count += 1;
+            }
+
+            total_cross + self.props.run_spacing * (run_count as f32 - 1.0).max(0.0)
+        }
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        let direction = self.props.direction;
+        let item_spacing = self.props.item_spacing;
+
+        // Children may take up to the whole main axis, but are free to grow
+        // along the cross axis since runs stack up in that direction.
+        let main_axis_max = direction.get_main_axis(input.max);
+        let child_constraints = Constraints::loose(direction.vec2(main_axis_max, f32::INFINITY));
+
+        let align_baselines = direction == Direction::Right
+            && self.props.cross_axis_alignment == CrossAxisAlignment::Baseline;
+
+        let mut runs = Vec::new();
+        let mut current = Run::default();
+
+        for &child_id in &node.children {
+            let child = ctx.dom.get(child_id).unwrap();
+            if child.widget.flow() != Flow::Inline {
+                continue;
+            }
+
+            let size = ctx.calculate_layout(child_id, child_constraints);
+            let child_main = direction.get_main_axis(size);
+            let child_cross = direction.get_cross_axis(size);
+
+            if !current.children.is_empty()
+                && current.main_size + item_spacing + child_main > main_axis_max
+            {
+                runs.push(std::mem::take(&mut current));
+            }
+
+            if !current.children.is_empty() {
+                current.main_size += item_spacing;
+            }
+
+            current.children.push(child_id);
+            current.main_size += child_main;
+            current.cross_size = current.cross_size.max(child_cross);
+
+            if let Some(baseline) = ctx.layout.baseline(child_id).filter(|_| align_baselines) {
+                current.ascent = current.ascent.max(baseline);
+                current.descent = current.descent.max(child_cross - baseline);
+                current.cross_size = current.cross_size.max(current.ascent + current.descent);
+            }
+        }
+
+        if !current.children.is_empty() {
+            runs.push(current);
+        }
+
+        let total_run_spacing = self.props.run_spacing * runs.len().saturating_sub(1) as f32;
+        let total_cross_size =
+            runs.iter().map(|run| run.cross_size).sum::<f32>() + total_run_spacing;
+        let max_run_main_size = runs.iter().map(|run| run.main_size).fold(0.0, f32::max);
+
+        let main_axis_size = match self.props.main_axis_size {
+            MainAxisSize::Min => max_run_main_size,
+            MainAxisSize::Max => {
+                if main_axis_max.is_finite() {
+                    f32::max(max_run_main_size, main_axis_max)
+                } else {
+                    max_run_main_size
+                }
+            }
+        };
+
+        let container_size = input.constrain(direction.vec2(main_axis_size, total_cross_size));
+        let main_axis_size = direction.get_main_axis(container_size);
+
+        // We can lay out all children that are not part of the layout flow at
+        // this point, now that we know the total size of the container.
+        for &child_id in &node.children {
+            let child = ctx.dom.get(child_id).unwrap();
+
+            if let Flow::Relative { anchor, offset } = child.widget.flow() {
+                ctx.calculate_layout(child_id, Constraints::none());
+
+                let anchor = container_size * anchor.as_vec2();
+                let offset = offset.resolve(container_size);
+
+                let child_layout = ctx.layout.get_mut(child_id).unwrap();
+                child_layout.rect.set_pos(anchor + offset);
+            }
+        }
+
+        // Finally, position each run's children, applying alignment within the
+        // bounds of the run.
+        let mut next_cross = 0.0;
+
+        for run in &runs {
+            let count = run.children.len() as f32;
+            let free_space = (main_axis_size - run.main_size).max(0.0);
+
+            let (leading_space, mut between_space) = match self.props.main_axis_alignment {
+                MainAxisAlignment::Start => (0.0, 0.0),
+                MainAxisAlignment::Center => (free_space / 2.0, 0.0),
+                MainAxisAlignment::End => (free_space, 0.0),
+                MainAxisAlignment::SpaceAround => {
+                    let between_space = free_space / count;
+                    (between_space * 0.5, between_space)
+                }
+                MainAxisAlignment::SpaceBetween => {
+                    if run.children.len() <= 1 {
+                        (0.0, 0.0)
+                    } else {
+                        (0.0, free_space / (count - 1.0))
+                    }
+                }
+                MainAxisAlignment::SpaceEvenly => {
+                    let between_space = free_space / (count + 1.0);
+                    (between_space, between_space)
+                }
+            };
+            between_space += item_spacing;
+
+            let mut next_main = leading_space;
+
+            for &child_id in &run.children {
+                let child_baseline = ctx.layout.baseline(child_id);
+                let child_layout = ctx.layout.get_mut(child_id).unwrap();
+                let child_size = child_layout.rect.size();
+                let child_main = direction.get_main_axis(child_size);
+                let child_cross = direction.get_cross_axis(child_size);
+
+                let cross = match self.props.cross_axis_alignment {
+                    CrossAxisAlignment::Start | CrossAxisAlignment::Stretch => 0.0,
+                    CrossAxisAlignment::Center => (run.cross_size - child_cross) / 2.0,
+                    CrossAxisAlignment::End => run.cross_size - child_cross,
+                    CrossAxisAlignment::Baseline => match child_baseline {
+                        Some(baseline) if align_baselines => run.ascent - baseline,
+                        _ => 0.0,
+                    },
+                };
+
+                child_layout
+                    .rect
+                    .set_pos(direction.vec2(next_main, next_cross + cross));
+
+                next_main += child_main + between_space;
+            }
+
+            next_cross += run.cross_size + self.props.run_spacing;
+        }
+
+        ctx.layout.propagate_baseline(ctx.dom, &node.children);
+
+        container_size
+    }
+}
@@ -0,0 +1,311 @@
+#![cfg(feature = "serde")]
+
+use serde::de::value::{Error, MapAccessDeserializer, MapDeserializer, SeqDeserializer};
+use serde::de::{self, IntoDeserializer, Visitor};
+use serde::ser::{self, Impossible, Serialize};
+use serde::{forward_to_deserialize_any, Deserialize};
+use yakui::widgets::{DockLayout, DockNode, DockSide};
+
+#[test]
+fn dock_layout_round_trip() {
+    let mut layout = DockLayout::new(DockNode::horizontal(
+        0.25,
+        DockNode::tabs(["Outliner"]),
+        DockNode::vertical(
+            0.7,
+            DockNode::tabs(["Viewport"]),
+            DockNode::tabs(["Console", "Output"]),
+        ),
+    ));
+    layout.move_tab("Output", "Outliner", DockSide::Bottom);
+
+    let saved = layout.serialize(ValueSerializer).unwrap();
+    let restored = DockLayout::deserialize(saved).unwrap();
+    assert_eq!(restored, layout);
+}
+
+/// A minimal self-describing format, since the workspace doesn't depend on
+/// any serde format crates. Structs and struct variants are stored as maps,
+/// the same way formats like JSON store them.
+#[derive(Debug, Clone)]
+enum Value {
+    U64(u64),
+    F64(f64),
+    String(String),
+    Seq(Vec<Value>),
+    Map(Vec<(String, Value)>),
+}
+
+impl<'de> IntoDeserializer<'de, Error> for Value {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Value {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self {
+            Value::U64(value) => visitor.visit_u64(value),
+            Value::F64(value) => visitor.visit_f64(value),
+            Value::String(value) => visitor.visit_string(value),
+            Value::Seq(values) => visitor.visit_seq(SeqDeserializer::new(values.into_iter())),
+            Value::Map(entries) => visitor.visit_map(MapDeserializer::new(entries.into_iter())),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self {
+            Value::String(variant) => visitor.visit_enum(variant.into_deserializer()),
+            Value::Map(entries) => visitor.visit_enum(MapAccessDeserializer::new(
+                MapDeserializer::new(entries.into_iter()),
+            )),
+            _ => Err(de::Error::custom("expected an enum")),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct ValueSerializer;
+
+struct MapSerializer {
+    variant: Option<&'static str>,
+    entries: Vec<(String, Value)>,
+}
+
+struct SeqSerializer(Vec<Value>);
+
+fn unsupported() -> Error {
+    ser::Error::custom("unsupported by this format")
+}
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = Impossible<Value, Error>;
+    type SerializeTupleStruct = Impossible<Value, Error>;
+    type SerializeTupleVariant = Impossible<Value, Error>;
+    type SerializeMap = Impossible<Value, Error>;
+    type SerializeStruct = MapSerializer;
+    type SerializeStructVariant = MapSerializer;
+
+    fn serialize_bool(self, _value: bool) -> Result<Value, Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_i8(self, _value: i8) -> Result<Value, Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_i16(self, _value: i16) -> Result<Value, Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_i32(self, _value: i32) -> Result<Value, Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_i64(self, _value: i64) -> Result<Value, Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<Value, Error> {
+        self.serialize_u64(value.into())
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<Value, Error> {
+        self.serialize_u64(value.into())
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<Value, Error> {
+        self.serialize_u64(value.into())
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<Value, Error> {
+        Ok(Value::U64(value))
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<Value, Error> {
+        self.serialize_f64(value.into())
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<Value, Error> {
+        Ok(Value::F64(value))
+    }
+
+    fn serialize_char(self, value: char) -> Result<Value, Error> {
+        Ok(Value::String(value.into()))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<Value, Error> {
+        Ok(Value::String(value.to_owned()))
+    }
+
+    fn serialize_bytes(self, _value: &[u8]) -> Result<Value, Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_none(self) -> Result<Value, Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _value: &T) -> Result<Value, Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_unit(self) -> Result<Value, Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Value, Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Value, Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer, Error> {
+        Ok(SeqSerializer(Vec::with_capacity(len.unwrap_or(0))))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<MapSerializer, Error> {
+        Ok(MapSerializer {
+            variant: None,
+            entries: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<MapSerializer, Error> {
+        Ok(MapSerializer {
+            variant: Some(variant),
+            entries: Vec::with_capacity(len),
+        })
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.0.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Seq(self.0))
+    }
+}
+
+impl ser::SerializeStruct for MapSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.entries
+            .push((key.to_owned(), value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        let map = Value::Map(self.entries);
+
+        // Variants are stored as a map from their name to their fields.
+        Ok(match self.variant {
+            Some(variant) => Value::Map(vec![(variant.to_owned(), map)]),
+            None => map,
+        })
+    }
+}
+
+impl ser::SerializeStructVariant for MapSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        ser::SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        ser::SerializeStruct::end(self)
+    }
+}
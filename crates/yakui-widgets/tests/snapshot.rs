@@ -1,11 +1,28 @@
-use yakui::{Constraints, CrossAxisAlignment, Dim2, MainAxisAlignment, MainAxisSize, Vec2};
+use yakui::{Constraints, CrossAxisAlignment, Dim, Dim2, MainAxisAlignment, MainAxisSize, Vec2};
+use yakui_core::event::Event;
 use yakui_core::geometry::Color;
-use yakui_core::{Alignment, Pivot};
+use yakui_core::input::{Modifiers, MouseButton};
+use yakui_core::paint::{
+    ClipShape, Fill, PaintCall, PaintDom, PaintLayerCall, PaintLimits, PaintMesh, PaintRect,
+    Pipeline, Uniforms, Vertex, YakuiPaintCall,
+};
+use yakui_core::widget::{LayoutContext, Widget};
+use yakui_core::{Alignment, IntrinsicSize, LayoutDirection, Pivot};
+use yakui_core::{TextureId, WidgetId, Yakui};
 use yakui_test::{run, Test};
-use yakui_widgets::widgets::{Button, List, Pad, UnconstrainedBox};
+use yakui_widgets::shadow::BoxShadow;
+use yakui_widgets::shapes::{FillRule, FillStyle, LineCap, Path, StrokeStyle};
+use yakui_widgets::widgets::{
+    AspectRatio, Button, Cached, Circle, CountGrid, DockArea, DockAreaResponse, DockLayout,
+    DockNode, DockSide, FractionallySized, List, Opacity, Pad, Panel, PanelResponse, Positioned,
+    RoundRect, ScrollHandle, Scrollable, ScrollableResponse, Splitter, SplitterResponse, Table,
+    TableColumn, TableResponse, TableSort, TableSortOrder, Transform, UnconstrainedBox,
+    VirtualList, VirtualListResponse, Wrap,
+};
 use yakui_widgets::{
-    align, button, center, checkbox, colored_box, colored_box_container, column, constrained,
-    expanded, pad, reflow, row, text,
+    align, aspect_ratio, button, canvas, center, checkbox, colored_box, colored_box_container,
+    column, constrained, expanded, fractionally_sized, layout_direction, pad, reflow, row, stack,
+    text, wrap,
 };
 
 #[test]
@@ -234,6 +251,147 @@ fn row_cross_stretch() {
     });
 }
 
+#[test]
+fn row_cross_baseline() {
+    let mut yak = test_yakui();
+    yak.start();
+    let mut ids = Vec::new();
+    align(Alignment::TOP_LEFT, || {
+        let mut row = List::row();
+        row.cross_axis_alignment = CrossAxisAlignment::Baseline;
+        row.show(|| {
+            ids.push(text(32.0, "42").id);
+            ids.push(text(12.0, "kg").id);
+            pad(Pad::all(10.0), || {
+                ids.push(text(20.0, "total").id);
+            });
+            colored_box(Color::RED, [10.0, 10.0]);
+        });
+    });
+    yak.finish();
+
+    let baseline = absolute_baseline(&yak, ids[0]);
+    assert!(baseline > 0.0);
+    assert_eq!(absolute_baseline(&yak, ids[1]), baseline);
+    assert_eq!(absolute_baseline(&yak, ids[2]), baseline);
+    assert_view_snapshot("row_cross_baseline", &yak);
+}
+
+#[test]
+fn count_grid_cross_baseline() {
+    let mut yak = test_yakui();
+    yak.start();
+    let mut ids = Vec::new();
+    align(Alignment::TOP_LEFT, || {
+        let mut grid = CountGrid::row(2);
+        grid.cross_axis_alignment = CrossAxisAlignment::Baseline;
+        grid.main_axis_size = MainAxisSize::Min;
+        grid.show(|| {
+            ids.push(text(32.0, "12").id);
+            ids.push(text(12.0, "a").id);
+            ids.push(text(12.0, "kg").id);
+            ids.push(text(32.0, "b").id);
+        });
+    });
+    yak.finish();
+
+    // Children are given in column-major order, so each row holds every
+    // other child.
+    assert_eq!(
+        absolute_baseline(&yak, ids[0]),
+        absolute_baseline(&yak, ids[2])
+    );
+    assert_eq!(
+        absolute_baseline(&yak, ids[1]),
+        absolute_baseline(&yak, ids[3])
+    );
+    assert_view_snapshot("count_grid_cross_baseline", &yak);
+}
+
+#[test]
+fn row_rtl() {
+    run!({
+        layout_direction(LayoutDirection::RightToLeft, || {
+            List::row().item_spacing(10.0).show(|| {
+                rect_50x50();
+                pad(Pad::horizontal(0.0).left(20.0), || {
+                    rect_50x50();
+                });
+                rect_50x50();
+            });
+        });
+    });
+}
+
+#[test]
+fn column_rtl() {
+    run!({
+        layout_direction(LayoutDirection::RightToLeft, || {
+            column(|| {
+                rect_50x50();
+                constrained(Constraints::tight(Vec2::new(200.0, 50.0)), || {
+                    align(Alignment::TOP_LEFT, || {
+                        rect_50x50();
+                    });
+                });
+            });
+        });
+    });
+}
+
+#[test]
+fn stack_positioned() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            stack(|| {
+                rect(400, 200);
+
+                // Stretched between the left and right edges.
+                Positioned::new()
+                    .left(Dim::pixels(10.0))
+                    .right(Dim::percent(0.25))
+                    .top(Dim::pixels(20.0))
+                    .height(Dim::pixels(30.0))
+                    .show(|| rect(0, 0));
+
+                // Pinned to the bottom right corner.
+                Positioned::new()
+                    .right(Dim::percent(0.1))
+                    .bottom(Dim::pixels(5.0))
+                    .show(rect_50x50);
+
+                // Fills the whole stack.
+                Positioned::fill().show(|| rect(0, 0));
+            });
+        });
+    });
+}
+
+#[test]
+fn stack_positioned_intrinsic() {
+    let mut yak = test_yakui();
+
+    let id = frame(&mut yak, [1000.0, 1000.0], || {
+        let mut id = None;
+        intrinsic(IntrinsicSize::MaxWidth, || {
+            id = Some(
+                stack(|| {
+                    rect(100, 50);
+
+                    // Positioned children don't affect the size of the stack,
+                    // even when it's sized to its intrinsic width.
+                    Positioned::new().show(|| rect(300, 20));
+                })
+                .id,
+            );
+        });
+        id.unwrap()
+    });
+
+    let rect = yak.layout_dom().get(id).unwrap().rect;
+    assert_eq!(rect.size(), Vec2::new(100.0, 50.0));
+}
+
 /// When given infinite constraints, widgets like List need to pick the minimum
 /// size that fits their content, not infinity.
 #[test]
@@ -368,6 +526,1857 @@ fn row_reflow() {
     });
 }
 
+#[test]
+fn wrap_basic() {
+    run!({
+        wrap(|| {
+            for _ in 0..25 {
+                rect(150, 50);
+            }
+        });
+    });
+}
+
+#[test]
+fn wrap_spacing() {
+    run!({
+        Wrap::row().item_spacing(10.0).run_spacing(20.0).show(|| {
+            for _ in 0..25 {
+                rect(150, 50);
+            }
+        });
+    });
+}
+
+#[test]
+fn wrap_run_alignment() {
+    run!({
+        Wrap::row()
+            .main_axis_alignment(MainAxisAlignment::Center)
+            .cross_axis_alignment(CrossAxisAlignment::End)
+            .show(|| {
+                for i in 0..10 {
+                    rect(300, 25 + 25 * (i % 3));
+                }
+            });
+    });
+}
+
+#[test]
+fn wrap_column_min() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            Wrap::column().main_axis_size(MainAxisSize::Min).show(|| {
+                for _ in 0..25 {
+                    rect_50x50();
+                }
+            });
+        });
+    });
+}
+
+#[test]
+fn intrinsic_row() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            intrinsic(IntrinsicSize::MaxWidth, || {
+                List::row().item_spacing(10.0).show(|| {
+                    pad(Pad::all(10.0), rect_50x50);
+                    rect(30, 20);
+                    text(20.0, "Hello");
+                });
+            });
+        });
+    });
+}
+
+#[test]
+fn intrinsic_column() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            intrinsic(IntrinsicSize::MaxWidth, || {
+                List::column().item_spacing(10.0).show(|| {
+                    rect(30, 20);
+                    pad(Pad::horizontal(25.0), rect_50x50);
+                    rect(80, 10);
+                });
+            });
+        });
+    });
+}
+
+#[test]
+fn intrinsic_text_min_width() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            intrinsic(IntrinsicSize::MinWidth, || {
+                text(60.0, "X X X X X");
+            });
+        });
+    });
+}
+
+#[test]
+fn intrinsic_wrap() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            constrained(Constraints::loose(Vec2::new(120.0, f32::INFINITY)), || {
+                intrinsic(IntrinsicSize::MaxWidth, || {
+                    Wrap::row().run_spacing(10.0).show(|| {
+                        for _ in 0..5 {
+                            rect_50x50();
+                        }
+                    });
+                });
+            });
+        });
+    });
+}
+
+/// Constraints that can't be satisfied, like a minimum above the maximum, are
+/// resolved in favor of the maximum instead of panicking.
+#[test]
+fn intrinsic_invalid_constraints() {
+    let mut yak = test_yakui();
+
+    yak.start();
+    let mut child = None;
+    align(Alignment::TOP_LEFT, || {
+        intrinsic(IntrinsicSize::MaxWidth, || {
+            let constraints = Constraints {
+                min: Vec2::new(100.0, 0.0),
+                max: Vec2::new(50.0, f32::INFINITY),
+            };
+            child = Some(constrained(constraints, rect_50x50).id);
+        });
+    });
+    yak.finish();
+
+    let layout = yak.layout_dom().get(child.unwrap()).unwrap();
+    assert_eq!(layout.rect.size(), Vec2::new(50.0, 50.0));
+}
+
+#[test]
+fn aspect_ratio_wide() {
+    run!({
+        center(|| {
+            aspect_ratio(16.0 / 9.0, || {
+                colored_box_container(Color::RED, || {});
+            });
+        });
+    });
+}
+
+#[test]
+fn aspect_ratio_tall() {
+    run!({
+        center(|| {
+            aspect_ratio(0.5, || {
+                colored_box_container(Color::RED, || {});
+            });
+        });
+    });
+}
+
+#[test]
+fn aspect_ratio_in_row() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            constrained(Constraints::loose(Vec2::new(1000.0, 100.0)), || {
+                row(|| {
+                    rect_50x50();
+                    AspectRatio::new(2.0).show(|| {
+                        rect(20, 20);
+                    });
+                });
+            });
+        });
+    });
+}
+
+#[test]
+fn fractionally_sized_basic() {
+    run!({
+        center(|| {
+            fractionally_sized(Dim2::new(Dim::percent(0.4), Dim::percent(0.5)), || {
+                colored_box_container(Color::RED, || {});
+            });
+        });
+    });
+}
+
+#[test]
+fn fractionally_sized_width_only() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            FractionallySized::horizontal(Dim::percent(0.25)).show(|| {
+                colored_box(Color::RED, [10.0, 50.0]);
+            });
+        });
+    });
+}
+
+#[test]
+fn fractionally_sized_pixels_and_percent() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            let size = Dim2::new(
+                Dim {
+                    pixels: -100.0,
+                    percent: 0.5,
+                },
+                Dim::pixels(80.0),
+            );
+
+            fractionally_sized(size, || {
+                colored_box_container(Color::RED, || {});
+            });
+        });
+    });
+}
+
+#[test]
+fn table_basic() {
+    run!({
+        align(Alignment::TOP_LEFT, || {
+            show_table(4);
+        });
+    });
+}
+
+#[test]
+fn table_virtualized() {
+    let mut yak = test_yakui();
+
+    // The first frame doesn't know how tall the table is yet.
+    frame(&mut yak, [1000.0, 100.0], || show_table(1000));
+    let response = frame(&mut yak, [1000.0, 100.0], || show_table(1000));
+    assert_eq!(response.visible_rows, 0..3);
+
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(50.0, 50.0))));
+    yak.handle_event(Event::MouseScroll {
+        delta: Vec2::new(0.0, 250.0),
+    });
+
+    let response = frame(&mut yak, [1000.0, 100.0], || show_table(1000));
+    assert_eq!(response.visible_rows, 10..14);
+
+    assert_view_snapshot("table_virtualized", &yak);
+}
+
+/// A table inside of a scrollable is as tall as all of its rows, lets the
+/// scrollable handle the mouse wheel, and only builds the rows it shows.
+#[test]
+fn table_in_scrollable() {
+    let mut yak = test_yakui();
+    yak.set_paint_limit(PaintLimits {
+        max_texture_size_2d: 4096,
+        ..PaintLimits::default()
+    });
+
+    let show = || {
+        let mut table = None;
+        let scroll = Scrollable::vertical().show(|| table = Some(show_table(1000)));
+        (scroll.into_inner(), table.unwrap())
+    };
+
+    // The first frame doesn't know where the table is yet.
+    let (_, table) = frame(&mut yak, [1000.0, 100.0], show);
+    assert_eq!(table.visible_rows, 0..50);
+    yak.paint();
+
+    // Rows start below the 28px header and are 24px tall.
+    let (scroll, table) = frame(&mut yak, [1000.0, 100.0], show);
+    assert_eq!(scroll.content_size.y, 28.0 + 24_000.0);
+    assert_eq!(table.visible_rows, 0..3);
+    yak.paint();
+
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(50.0, 50.0))));
+    yak.handle_event(Event::MouseScroll {
+        delta: Vec2::new(0.0, 250.0),
+    });
+
+    let (scroll, table) = frame(&mut yak, [1000.0, 100.0], show);
+    assert_eq!(scroll.scroll_position.y, 250.0);
+    assert_eq!(table.visible_rows, 9..14);
+}
+
+#[test]
+fn table_selection() {
+    let mut yak = test_yakui();
+    frame(&mut yak, [1000.0, 100.0], || show_table(10));
+
+    let click = |yak: &mut Yakui, pos: Vec2, modifiers: Modifiers| {
+        yak.handle_event(Event::ModifiersChanged(modifiers));
+        click(yak, pos);
+        frame(yak, [1000.0, 100.0], || show_table(10))
+    };
+
+    // Rows start below the 28px header and are 24px tall.
+    let row = |index: f32| Vec2::new(20.0, 28.0 + 24.0 * index + 12.0);
+
+    let response = click(&mut yak, row(0.0), Modifiers::empty());
+    assert_eq!(response.selection, [0]);
+    assert!(response.selection_changed);
+
+    let response = click(&mut yak, row(2.0), Modifiers::CONTROL);
+    assert_eq!(response.selection, [0, 2]);
+
+    let response = click(&mut yak, row(1.0), Modifiers::SHIFT);
+    assert_eq!(response.selection, [1, 2]);
+
+    let response = click(&mut yak, row(0.0), Modifiers::CONTROL | Modifiers::SHIFT);
+    assert_eq!(response.selection, [0, 1, 2]);
+
+    let response = click(&mut yak, row(1.0), Modifiers::CONTROL);
+    assert_eq!(response.selection, [0, 2]);
+
+    let response = frame(&mut yak, [1000.0, 100.0], || show_table(10));
+    assert!(!response.selection_changed);
+
+    // Clicking a header sorts by it and clears the selection.
+    let header = Vec2::new(150.0, 14.0);
+    let response = click(&mut yak, header, Modifiers::empty());
+    let ascending = TableSort {
+        column: 1,
+        order: TableSortOrder::Ascending,
+    };
+    assert_eq!(response.sort, Some(ascending));
+    assert!(response.sort_changed);
+    assert!(response.selection.is_empty());
+
+    let response = click(&mut yak, header, Modifiers::empty());
+    assert_eq!(response.sort.unwrap().order, TableSortOrder::Descending);
+}
+
+#[test]
+fn table_resize_column() {
+    let mut yak = test_yakui();
+    frame(&mut yak, [1000.0, 100.0], || show_table(10));
+
+    // Drag the right edge of the first column.
+    drag(&mut yak, Vec2::new(100.0, 10.0), Vec2::new(160.0, 10.0));
+
+    let response = frame(&mut yak, [1000.0, 100.0], || show_table(10));
+    assert_eq!(response.column_widths, [160.0, 100.0, 100.0]);
+    assert_eq!(response.sort, None);
+
+    // Columns can't be made smaller than their minimum width.
+    press(&mut yak, Vec2::new(160.0, 10.0));
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(0.0, 10.0))));
+    let response = frame(&mut yak, [1000.0, 100.0], || show_table(10));
+    assert_eq!(response.column_widths, [24.0, 100.0, 100.0]);
+}
+
+fn show_table(row_count: usize) -> TableResponse {
+    let columns = vec![
+        TableColumn::new("Name").width(100.0),
+        TableColumn::new("Kind").width(100.0),
+        TableColumn::new("Size").width(100.0).resizable(false),
+    ];
+
+    Table::new(columns, row_count)
+        .show(|_row, column| rect(20 + 10 * column as i32, 10))
+        .into_inner()
+}
+
+#[test]
+fn virtual_list_fixed() {
+    let mut yak = test_yakui();
+    let list = || VirtualList::fixed(10_000, 20.0);
+
+    // The first frame doesn't know where the list is yet.
+    let (_, response) = frame(&mut yak, [200.0, 100.0], || show_virtual_list(list()));
+    assert_eq!(response.visible_items, 0..50);
+    yak.paint();
+
+    let (_, response) = frame(&mut yak, [200.0, 100.0], || show_virtual_list(list()));
+    assert_eq!(response.visible_items, 0..6);
+    assert_eq!(response.content_size, 200_000.0);
+
+    assert_view_snapshot("virtual_list_fixed", &yak);
+}
+
+#[test]
+fn virtual_list_scroll() {
+    let mut yak = test_yakui();
+    let list = || VirtualList::fixed(10_000, 20.0).item_spacing(5.0);
+    frame(&mut yak, [200.0, 100.0], || show_virtual_list(list()));
+    yak.paint();
+
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(50.0, 50.0))));
+    yak.handle_event(Event::MouseScroll {
+        delta: Vec2::new(0.0, 260.0),
+    });
+
+    let (scroll, response) = frame(&mut yak, [200.0, 100.0], || show_virtual_list(list()));
+    assert_eq!(scroll.scroll_position.y, 260.0);
+    assert_eq!(response.visible_items, 9..16);
+    assert_view_snapshot("virtual_list_scroll", &yak);
+    yak.paint();
+
+    // Scrolling is clamped to the end of the list.
+    yak.handle_event(Event::MouseScroll {
+        delta: Vec2::new(0.0, 1_000_000.0),
+    });
+
+    let (scroll, response) = frame(&mut yak, [200.0, 100.0], || show_virtual_list(list()));
+    assert_eq!(scroll.scroll_position.y, 250_000.0 - 5.0 - 100.0);
+    assert_eq!(response.visible_items, 9994..10_000);
+}
+
+#[test]
+fn virtual_list_measured() {
+    let mut yak = test_yakui();
+    let list = || VirtualList::measured(1000, 20.0);
+    frame(&mut yak, [200.0, 100.0], || show_virtual_list(list()));
+    yak.paint();
+
+    // The first 50 items have now been measured as either 10 or 30 pixels
+    // tall, which averages out to the estimate.
+    let (_, response) = frame(&mut yak, [200.0, 100.0], || show_virtual_list(list()));
+    assert_eq!(response.content_size, 20_000.0);
+    assert_eq!(response.visible_items, 0..7);
+    yak.paint();
+
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(50.0, 50.0))));
+    yak.handle_event(Event::MouseScroll {
+        delta: Vec2::new(0.0, 45.0),
+    });
+
+    let (scroll, response) = frame(&mut yak, [200.0, 100.0], || show_virtual_list(list()));
+    assert_eq!(scroll.scroll_position.y, 45.0);
+    assert_eq!(response.visible_items, 1..9);
+    assert_view_snapshot("virtual_list_measured", &yak);
+}
+
+/// Measuring items above the visible ones shifts the scrollable so that the
+/// visible items stay where they were.
+#[test]
+fn virtual_list_measured_anchor() {
+    let mut yak = test_yakui();
+    let handle = ScrollHandle::new();
+    let show = || {
+        let mut items = Vec::new();
+        Scrollable::vertical().handle(handle.clone()).show(|| {
+            VirtualList::measured(1000, 20.0).show(|index| {
+                let height = if index % 2 == 0 { 10.0 } else { 30.0 };
+                items.push((index, colored_box(Color::WHITE, [50.0, height]).id));
+            });
+        });
+        items
+    };
+
+    frame(&mut yak, [200.0, 100.0], show);
+    yak.paint();
+
+    // Item 100 is expected to start at 2000, but item 99 right above it is
+    // taller than the estimate.
+    handle.set_scroll_position(Vec2::new(0.0, 2000.0));
+    let items = frame(&mut yak, [200.0, 100.0], show);
+    assert_eq!(items.first().unwrap().0, 99);
+    assert_eq!(handle.scroll_position().y, 2010.0);
+
+    let &(_, id) = items.iter().find(|(index, _)| *index == 100).unwrap();
+    assert_eq!(yak.layout_dom().get(id).unwrap().rect.pos().y, 0.0);
+}
+
+#[test]
+fn scrollable_both() {
+    let mut yak = test_yakui();
+    frame(&mut yak, [200.0, 100.0], || {
+        show_scrollable(Scrollable::both())
+    });
+    let response = frame(&mut yak, [200.0, 100.0], || {
+        show_scrollable(Scrollable::both())
+    });
+    assert_eq!(response.content_size, Vec2::new(1000.0, 500.0));
+    assert_eq!(response.viewport_size, Vec2::new(200.0, 100.0));
+
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(50.0, 50.0))));
+    yak.handle_event(Event::MouseScroll {
+        delta: Vec2::new(30.0, 40.0),
+    });
+
+    let response = frame(&mut yak, [200.0, 100.0], || {
+        show_scrollable(Scrollable::both())
+    });
+    assert_eq!(response.scroll_position, Vec2::new(30.0, 40.0));
+    assert_view_snapshot("scrollable_both", &yak);
+
+    // Holding shift turns vertical wheel movement into horizontal scrolling.
+    yak.handle_event(Event::ModifiersChanged(Modifiers::SHIFT));
+    yak.handle_event(Event::MouseScroll {
+        delta: Vec2::new(0.0, 50.0),
+    });
+
+    let response = frame(&mut yak, [200.0, 100.0], || {
+        show_scrollable(Scrollable::both())
+    });
+    assert_eq!(response.scroll_position, Vec2::new(80.0, 40.0));
+
+    // A horizontal scrollable ignores vertical movement.
+    let response = frame(&mut yak, [200.0, 100.0], || {
+        show_scrollable(Scrollable::horizontal())
+    });
+    assert_eq!(response.scroll_position, Vec2::new(80.0, 0.0));
+
+    // A vertical scrollable keeps scrolling vertically while Shift is held.
+    frame(&mut yak, [200.0, 100.0], || {
+        show_scrollable(Scrollable::vertical())
+    });
+    yak.handle_event(Event::MouseScroll {
+        delta: Vec2::new(0.0, 50.0),
+    });
+
+    let response = frame(&mut yak, [200.0, 100.0], || {
+        show_scrollable(Scrollable::vertical())
+    });
+    assert_eq!(response.scroll_position, Vec2::new(0.0, 50.0));
+}
+
+#[test]
+fn scrollable_scrollbars() {
+    let mut yak = test_yakui();
+    frame(&mut yak, [200.0, 100.0], || {
+        show_scrollable(Scrollable::both())
+    });
+    frame(&mut yak, [200.0, 100.0], || {
+        show_scrollable(Scrollable::both())
+    });
+
+    // The vertical track is 92px long because the horizontal scrollbar takes
+    // up the corner, so the 18.4px thumb can travel 73.6px over 400px of
+    // content.
+    drag(
+        &mut yak,
+        Vec2::new(196.0, 5.0),
+        Vec2::new(150.0, 5.0 + 36.8),
+    );
+
+    let response = frame(&mut yak, [200.0, 100.0], || {
+        show_scrollable(Scrollable::both())
+    });
+    assert_eq!(response.scroll_position.x, 0.0);
+    assert!((response.scroll_position.y - 200.0).abs() < 0.01);
+
+    // Clicking the horizontal track after the thumb scrolls by a page.
+    click(&mut yak, Vec2::new(150.0, 96.0));
+
+    let response = frame(&mut yak, [200.0, 100.0], || {
+        show_scrollable(Scrollable::both())
+    });
+    assert_eq!(response.scroll_position.x, 200.0);
+    assert_view_snapshot("scrollable_scrollbars", &yak);
+
+    // Auto-hidden scrollbars can't be clicked while the mouse is outside.
+    let auto_hide = || Scrollable::both().auto_hide_scrollbars(true);
+    frame(&mut yak, [200.0, 100.0], || show_scrollable(auto_hide()));
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(500.0, 500.0))));
+    frame(&mut yak, [200.0, 100.0], || show_scrollable(auto_hide()));
+    click(&mut yak, Vec2::new(10.0, 96.0));
+
+    let response = frame(&mut yak, [200.0, 100.0], || show_scrollable(auto_hide()));
+    assert_eq!(response.scroll_position.x, 0.0);
+}
+
+#[test]
+fn scrollable_handle() {
+    let mut yak = test_yakui();
+    let handle = ScrollHandle::new();
+    let scrollable = || Scrollable::both().handle(handle.clone());
+
+    // Positions set before the first layout are kept until the scrollable
+    // knows how far it can scroll.
+    handle.set_scroll_position(Vec2::new(100.0, 50.0));
+    let response = frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    assert_eq!(response.scroll_position, Vec2::new(100.0, 50.0));
+    assert_eq!(handle.scroll_position(), Vec2::new(100.0, 50.0));
+
+    handle.set_scroll_position(Vec2::new(5000.0, 5000.0));
+    let response = frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    assert_eq!(response.scroll_position, Vec2::new(800.0, 400.0));
+
+    handle.animate_to(Vec2::ZERO);
+    let response = frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    assert_eq!(response.scroll_position, Vec2::new(600.0, 300.0));
+
+    for _ in 0..50 {
+        frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    }
+    assert_eq!(handle.scroll_position(), Vec2::ZERO);
+}
+
+#[test]
+fn scrollable_scroll_into_view() {
+    let mut yak = test_yakui();
+
+    // A 100px tall scrollable holding a 200px spacer followed by another
+    // 100px tall scrollable holding ten 50px boxes.
+    let show = || {
+        let mut inner = None;
+        let mut boxes = Vec::new();
+        let outer = Scrollable::vertical().show(|| {
+            column(|| {
+                rect(100, 200);
+                constrained(Constraints::loose(Vec2::new(100.0, 100.0)), || {
+                    let res = Scrollable::vertical().show(|| {
+                        column(|| {
+                            for _ in 0..10 {
+                                boxes.push(colored_box(Color::RED, [50.0, 50.0]).id);
+                            }
+                        });
+                    });
+                    inner = Some(res.into_inner());
+                });
+            });
+        });
+        (outer.into_inner(), inner.unwrap(), boxes)
+    };
+
+    frame(&mut yak, [100.0, 100.0], show);
+    let (_, _, boxes) = frame(&mut yak, [100.0, 100.0], show);
+
+    yak.request_focus(Some(boxes[7]));
+    let (outer, inner, _) = frame(&mut yak, [100.0, 100.0], show);
+    assert_eq!(inner.scroll_position.y, 300.0);
+    assert_eq!(outer.scroll_position.y, 200.0);
+    assert_view_snapshot("scrollable_scroll_into_view", &yak);
+
+    // Selecting a box above the visible ones scrolls back up to it.
+    yak.request_focus(Some(boxes[2]));
+    let (outer, inner, _) = frame(&mut yak, [100.0, 100.0], show);
+    assert_eq!(inner.scroll_position.y, 100.0);
+    assert_eq!(outer.scroll_position.y, 200.0);
+}
+
+#[test]
+fn scrollable_smooth() {
+    let mut yak = test_yakui();
+    let scrollable = || Scrollable::vertical().smooth_scrolling(true);
+    frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(50.0, 50.0))));
+    yak.handle_event(Event::MouseScroll {
+        delta: Vec2::new(0.0, 100.0),
+    });
+
+    // Each frame covers a quarter of the remaining distance at 60 FPS.
+    let response = frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    assert_eq!(response.scroll_position.y, 25.0);
+
+    // Steps made while animating add to the target instead of the position.
+    yak.handle_event(Event::MouseScroll {
+        delta: Vec2::new(0.0, 100.0),
+    });
+
+    for _ in 0..50 {
+        frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    }
+    let response = frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    assert_eq!(response.scroll_position.y, 200.0);
+}
+
+#[test]
+fn scrollable_kinetic() {
+    let mut yak = test_yakui();
+    let scrollable = || Scrollable::vertical().kinetic_scrolling(true);
+    frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+
+    // Dragging the content scrolls it immediately.
+    press(&mut yak, Vec2::new(50.0, 80.0));
+    frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(50.0, 40.0))));
+    let response = frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    assert_eq!(response.scroll_position.y, 40.0);
+
+    // Letting go keeps it moving until it slows to a stop.
+    release(&mut yak);
+    let response = frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    assert!(response.scroll_position.y > 40.0);
+
+    let mut last = response.scroll_position.y;
+    for _ in 0..200 {
+        let response = frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+        assert!(response.scroll_position.y >= last);
+        last = response.scroll_position.y;
+    }
+
+    let response = frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    assert_eq!(response.scroll_position.y, last);
+    assert!(last > 100.0 && last <= 400.0);
+}
+
+#[test]
+fn scrollable_overscroll() {
+    let mut yak = test_yakui();
+    let scrollable = || {
+        Scrollable::vertical()
+            .kinetic_scrolling(true)
+            .max_overscroll(30.0)
+    };
+    frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+
+    // Pulling past the top of the content only moves it half as far, up to
+    // the overscroll limit.
+    press(&mut yak, Vec2::new(50.0, 10.0));
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(50.0, 50.0))));
+    let response = frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    assert_eq!(response.scroll_position.y, -20.0);
+
+    yak.handle_event(Event::CursorMoved(Some(Vec2::new(50.0, 90.0))));
+    let response = frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    assert_eq!(response.scroll_position.y, -30.0);
+
+    // Letting go springs back to the edge.
+    release(&mut yak);
+    let response = frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    assert!(response.scroll_position.y > -30.0 && response.scroll_position.y < 0.0);
+
+    for _ in 0..50 {
+        frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    }
+    let response = frame(&mut yak, [200.0, 100.0], || show_scrollable(scrollable()));
+    assert_eq!(response.scroll_position.y, 0.0);
+}
+
+/// Shows a scrollable holding a 1000x500 box.
+fn show_scrollable(scrollable: Scrollable) -> ScrollableResponse {
+    scrollable.show(|| rect(1000, 500)).into_inner()
+}
+
+/// Shows a list of 50px wide boxes in a vertical scrollable. Measured lists
+/// alternate between boxes that are 10px and 30px tall.
+fn show_virtual_list(list: VirtualList) -> (ScrollableResponse, VirtualListResponse) {
+    let measured = matches!(
+        list.item_size,
+        yakui_widgets::widgets::VirtualListItemSize::Measured { .. }
+    );
+
+    let mut response = None;
+    let scroll = Scrollable::vertical().show(|| {
+        let res = list.show(|index| {
+            let height = match (measured, index % 2) {
+                (false, _) => 20,
+                (true, 0) => 10,
+                (true, _) => 30,
+            };
+
+            rect(50, height);
+        });
+        response = Some(res.into_inner());
+    });
+    (scroll.into_inner(), response.unwrap())
+}
+
+/// Returns the absolute position of the baseline a widget reported.
+fn absolute_baseline(yak: &Yakui, id: WidgetId) -> f32 {
+    let node = yak.layout_dom().get(id).unwrap();
+    node.rect.pos().y + node.baseline.unwrap()
+}
+
+#[test]
+fn transform_paint() {
+    let mut yak = test_yakui();
+    yak.start();
+    align(Alignment::TOP_LEFT, || {
+        Transform::new()
+            .translation(Vec2::new(100.0, 0.0))
+            .scale(Vec2::splat(2.0))
+            .origin(Pivot::TOP_LEFT)
+            .show(|| {
+                colored_box(Color::RED, [50.0, 20.0]);
+            });
+    });
+    yak.finish();
+
+    let paint = yak.paint();
+    let clip = paint.layers[0].calls[0].clip();
+    let call = first_internal_call(paint);
+
+    let positions: Vec<Vec2> = call
+        .vertices
+        .iter()
+        .map(|vertex| vertex.position * 1000.0)
+        .collect();
+    let min = positions.iter().copied().fold(Vec2::INFINITY, Vec2::min);
+    let max = positions
+        .iter()
+        .copied()
+        .fold(Vec2::NEG_INFINITY, Vec2::max);
+
+    assert!(min.abs_diff_eq(Vec2::new(100.0, 0.0), 0.01), "{min}");
+    assert!(max.abs_diff_eq(Vec2::new(200.0, 40.0), 0.01), "{max}");
+
+    // The viewport clip moves with the content, but stays within the viewport.
+    assert_eq!(clip.pos(), Vec2::new(100.0, 0.0));
+    assert_eq!(clip.size(), Vec2::new(900.0, 1000.0));
+}
+
+#[test]
+fn list_overflow() {
+    let mut yak = test_yakui();
+    yak.set_show_overflow(true);
+
+    yak.start();
+    let mut list = None;
+    align(Alignment::TOP_LEFT, || {
+        list = Some(
+            row(|| {
+                rect(400, 50);
+                rect(400, 50);
+                rect(400, 50);
+            })
+            .id,
+        );
+    });
+    yak.finish();
+
+    let warnings = yak.layout_dom().warnings(list.unwrap()).unwrap();
+    assert_eq!(warnings.constraint_violation, None);
+    assert_eq!(warnings.overflow.left, 0.0);
+    assert_eq!(warnings.overflow.right, 200.0);
+
+    // Stripes are painted in a band along the right edge of the row.
+    let paint = yak.paint();
+    let band = paint.layers[0]
+        .calls
+        .iter()
+        .find(|call| call.clip().pos() == Vec2::new(992.0, 0.0));
+    let clip = band
+        .expect("expected overflow stripes to be painted")
+        .clip();
+    assert_eq!(clip.size(), Vec2::new(8.0, 50.0));
+}
+
+#[test]
+fn round_rect_clip_mask() {
+    let mut yak = test_yakui();
+    yak.set_scale_factor(2.0);
+
+    yak.start();
+    align(Alignment::TOP_LEFT, || {
+        column(|| {
+            RoundRect::new(10.0).clip(true).show_children(|| {
+                Circle::new().clip(true).show_children(|| {
+                    rect(100, 100);
+                });
+            });
+            rect(50, 50);
+        });
+    });
+    yak.finish();
+
+    let paint = yak.paint();
+    let masks: Vec<_> = paint.layers[0]
+        .calls
+        .iter()
+        .map(|call| call.mask().map(|mask| mask.shapes().to_vec()))
+        .collect();
+
+    // Clip shapes are in physical pixels, and nested shapes are combined.
+    let round_rect = ClipShape::rounded_rect(
+        yakui::Rect::from_pos_size(Vec2::ZERO, Vec2::splat(200.0)),
+        20.0,
+    );
+    let circle = ClipShape::circle(Vec2::splat(100.0), 100.0);
+
+    assert_eq!(
+        masks,
+        [
+            None,
+            Some(vec![round_rect.clone()]),
+            Some(vec![round_rect, circle]),
+            None,
+        ]
+    );
+}
+
+#[test]
+fn linear_gradient_fill() {
+    let mut yak = test_yakui();
+
+    yak.start();
+    align(Alignment::TOP_LEFT, || {
+        let fill = Fill::linear(
+            Vec2::ZERO,
+            Vec2::Y,
+            [(1.0, Color::BLUE), (0.0, Color::RED), (0.5, Color::GREEN)],
+        );
+        colored_box(fill, [100.0, 100.0]);
+    });
+    yak.finish();
+
+    let paint = yak.paint();
+    let call = first_internal_call(paint);
+
+    // The box is split along each stop, so every stop's color shows up
+    // exactly where it was placed, regardless of the order stops were given.
+    for vertex in &call.vertices {
+        let y = vertex.position.y * 1000.0;
+        let expected = if y <= 50.0 {
+            Color::RED
+                .to_linear()
+                .lerp(Color::GREEN.to_linear(), y / 50.0)
+        } else {
+            Color::GREEN
+                .to_linear()
+                .lerp(Color::BLUE.to_linear(), (y - 50.0) / 50.0)
+        };
+
+        assert!(vertex.color.abs_diff_eq(expected, 0.001), "{y}");
+    }
+
+    let has_stop_at = |y: f32| {
+        call.vertices
+            .iter()
+            .any(|vertex| (vertex.position.y * 1000.0 - y).abs() < 0.01)
+    };
+    assert!(has_stop_at(0.0) && has_stop_at(50.0) && has_stop_at(100.0));
+}
+
+#[test]
+fn radial_gradient_fill() {
+    let mut yak = test_yakui();
+
+    yak.start();
+    align(Alignment::TOP_LEFT, || {
+        let fill = Fill::radial(
+            Vec2::splat(0.5),
+            0.5,
+            [(0.0, Color::WHITE), (1.0, Color::BLACK)],
+        );
+        RoundRect::new(10.0)
+            .color(fill)
+            .min_size(Vec2::splat(100.0))
+            .show();
+    });
+    yak.finish();
+
+    let paint = yak.paint();
+    let call = first_internal_call(paint);
+
+    // Radial gradients are approximated by splitting the shape into pieces
+    // that are small enough for vertex colors to look smooth.
+    assert!(call.vertices.len() > 1000);
+
+    for vertex in &call.vertices {
+        let distance = (vertex.position * 1000.0).distance(Vec2::splat(50.0)) / 50.0;
+        let expected = Color::WHITE
+            .to_linear()
+            .lerp(Color::BLACK.to_linear(), distance.min(1.0));
+        assert!(vertex.color.abs_diff_eq(expected, 0.001));
+    }
+}
+
+#[test]
+fn large_meshes_are_not_wrapped() {
+    let mut yak = test_yakui();
+
+    yak.start();
+    canvas(|ctx| {
+        // Two meshes that together have more vertices than 16-bit indices can
+        // reach are merged into the same call.
+        for mesh in 0..2 {
+            let vertices = (0..40_000).map(|i| {
+                let pos = Vec2::new(i as f32 / 100.0, mesh as f32);
+                Vertex::new(pos, Vec2::ZERO, yakui::geometry::Vec4::ONE)
+            });
+            let indices: Vec<u32> = (0..40_000 - 2).flat_map(|i| [i, i + 1, i + 2]).collect();
+            ctx.paint.add_mesh(PaintMesh::new(vertices, indices));
+        }
+
+        PaintRect::new(yakui::Rect::from_pos_size(Vec2::ZERO, Vec2::ONE)).add(ctx.paint);
+    });
+    yak.finish();
+
+    let paint = yak.paint();
+    assert_eq!(paint.layers[0].calls.len(), 1);
+
+    let call = first_internal_call(paint);
+
+    assert_eq!(call.vertices.len(), 80_004);
+    assert_eq!(call.indices[3 * (40_000 - 2)], 40_000);
+    assert_eq!(call.indices.last(), Some(&80_002));
+}
+
+#[test]
+fn custom_pipeline_uniforms() {
+    let mut yak = test_yakui();
+    let pipeline = Pipeline::Custom(0);
+
+    yak.start();
+    canvas(move |ctx| {
+        let progress = [0.5, 0.5, 1.0];
+
+        for (i, progress) in progress.into_iter().enumerate() {
+            let pos = Vec2::new(i as f32 * 100.0, 0.0);
+            let mut rect = PaintRect::new(yakui::Rect::from_pos_size(pos, Vec2::splat(50.0)));
+            rect.pipeline = pipeline;
+            rect.uniforms = Uniforms::from(yakui::geometry::Vec4::splat(progress));
+            rect.add(ctx.paint);
+        }
+    });
+    yak.finish();
+
+    // Meshes with the same uniforms are batched together, but the shader needs
+    // to see different uniforms in separate calls.
+    let paint = yak.paint();
+    let calls: Vec<_> = paint.layers[0]
+        .calls
+        .iter()
+        .map(internal_call)
+        .map(|call| (call.pipeline, call.uniforms.0[0].x, call.indices.len()))
+        .collect();
+    assert_eq!(calls, [(pipeline, 0.5, 12), (pipeline, 1.0, 6)]);
+}
+
+#[test]
+fn anti_aliased_circle() {
+    let mut yak = test_yakui();
+    yak.set_scale_factor(2.0);
+
+    yak.start();
+    align(Alignment::TOP_LEFT, || {
+        pad(Pad::all(100.0), || {
+            Circle::new().min_radius(100.0).anti_alias(true).show();
+        });
+    });
+    yak.finish();
+
+    let paint = yak.paint();
+    let call = first_internal_call(paint);
+
+    // The edge fades out over one physical pixel, which is half of a logical
+    // pixel, centered on the edge of the circle.
+    let center = Vec2::splat(150.0);
+    for vertex in &call.vertices {
+        let distance = (vertex.position * 1000.0 / 2.0).distance(center);
+        let expected = if vertex.color.w == 0.0 { 50.25 } else { 49.75 };
+        assert!((distance - expected).abs() < 0.01, "{distance}");
+    }
+    assert!(call.vertices.iter().any(|vertex| vertex.color.w == 0.0));
+    assert!(call.vertices.iter().any(|vertex| vertex.color.w == 1.0));
+}
+
+#[test]
+fn round_rect_shadow() {
+    let mut yak = test_yakui();
+
+    yak.start();
+    align(Alignment::TOP_LEFT, || {
+        pad(Pad::all(100.0), || {
+            RoundRect::new(10.0)
+                .min_size(Vec2::splat(100.0))
+                .shadow(BoxShadow::new(
+                    Vec2::new(0.0, 10.0),
+                    20.0,
+                    5.0,
+                    Color::rgba(0, 0, 0, 255),
+                ))
+                .show();
+        });
+    });
+    yak.finish();
+
+    let paint = yak.paint();
+    let call = first_internal_call(paint);
+
+    // The shadow is painted behind the box in the same mesh. Its vertices are
+    // the ones that reach outside of the box.
+    let shadow: Vec<_> = call
+        .vertices
+        .iter()
+        .map(|vertex| (vertex.position * 1000.0, vertex.color.w))
+        .filter(|&(pos, _)| {
+            pos.cmple(Vec2::splat(95.0)).any() || pos.cmpge(Vec2::splat(205.0)).any()
+        })
+        .collect();
+
+    // The shadow is offset, spread and then blurred past its edge.
+    let min = shadow
+        .iter()
+        .map(|&(pos, _)| pos)
+        .fold(Vec2::INFINITY, Vec2::min);
+    let max = shadow
+        .iter()
+        .map(|&(pos, _)| pos)
+        .fold(Vec2::NEG_INFINITY, Vec2::max);
+    assert!(min.abs_diff_eq(Vec2::new(75.0, 85.0), 0.01), "{min}");
+    assert!(max.abs_diff_eq(Vec2::new(225.0, 235.0), 0.01), "{max}");
+
+    // It fades out completely at the edge of the blur, and is half covered at
+    // the edge of the spread shape.
+    for &(pos, alpha) in &shadow {
+        if pos.x <= 75.01 || pos.y >= 234.99 {
+            assert_eq!(alpha, 0.0);
+        }
+        if (pos.y - 215.0).abs() < 0.01 && (100.0..200.0).contains(&pos.x) {
+            assert!((alpha - 0.5).abs() < 0.01, "{alpha}");
+        }
+    }
+}
+
+/// Whether a point is covered by an opaque triangle of a paint call.
+fn covers(call: &yakui_core::paint::YakuiPaintCall, point: Vec2) -> bool {
+    call.indices.chunks_exact(3).any(|triangle| {
+        let [a, b, c] = [0, 1, 2].map(|i| call.vertices[triangle[i] as usize]);
+        if [a, b, c].iter().any(|vertex| vertex.color.w < 1.0) {
+            return false;
+        }
+
+        let [a, b, c] = [a, b, c].map(|vertex| vertex.position * 1000.0);
+        let sides = [(a, b), (b, c), (c, a)].map(|(p, q)| (q - p).perp_dot(point - p));
+        sides.iter().all(|&side| side >= 0.0) || sides.iter().all(|&side| side <= 0.0)
+    })
+}
+
+#[test]
+fn path_fill_rule() {
+    let mut star = Path::new();
+    for i in 0..5 {
+        let angle = i as f32 * std::f32::consts::TAU * 2.0 / 5.0;
+        let point = Vec2::splat(100.0) + 80.0 * Vec2::from_angle(angle);
+        if i == 0 {
+            star.move_to(point);
+        } else {
+            star.line_to(point);
+        }
+    }
+    star.close();
+
+    for (rule, center_filled) in [(FillRule::NonZero, true), (FillRule::EvenOdd, false)] {
+        let mut yak = test_yakui();
+
+        yak.start();
+        let path = star.clone();
+        canvas(move |ctx| {
+            path.fill(ctx.paint, &FillStyle::new(Color::WHITE).rule(rule));
+        });
+        yak.finish();
+
+        let paint = yak.paint();
+        let call = first_internal_call(paint);
+
+        assert_eq!(covers(call, Vec2::splat(100.0)), center_filled, "{rule:?}");
+        assert!(covers(call, Vec2::new(170.0, 100.0)), "{rule:?}");
+        assert!(!covers(call, Vec2::new(100.0, 10.0)), "{rule:?}");
+
+        // Edges fade out over one physical pixel, centered on the true edge,
+        // which is stretched at the 36° tips of the star.
+        let expected = 180.0 + 0.5 / 18f32.to_radians().sin();
+        let max_x = call
+            .vertices
+            .iter()
+            .map(|vertex| vertex.position.x * 1000.0)
+            .fold(f32::MIN, f32::max);
+        assert!((max_x - expected).abs() < 0.01, "{max_x}");
+    }
+}
+
+#[test]
+fn path_stroke_caps_and_dashes() {
+    let stroke_bounds = |style: StrokeStyle| {
+        let mut yak = test_yakui();
+
+        yak.start();
+        canvas(move |ctx| {
+            let mut path = Path::new();
+            path.move_to(Vec2::new(100.0, 100.0))
+                .line_to(Vec2::new(200.0, 100.0));
+            path.stroke(ctx.paint, &style);
+        });
+        yak.finish();
+
+        let paint = yak.paint();
+        let call = first_internal_call(paint);
+
+        let positions = call.vertices.iter().map(|vertex| vertex.position * 1000.0);
+        let min = positions.clone().fold(Vec2::INFINITY, Vec2::min);
+        let max = positions.fold(Vec2::NEG_INFINITY, Vec2::max);
+        (min, max, call.vertices.len())
+    };
+
+    let (min, max, _) = stroke_bounds(StrokeStyle::new(10.0, Color::WHITE).anti_alias(false));
+    assert!(min.abs_diff_eq(Vec2::new(100.0, 95.0), 0.01), "{min}");
+    assert!(max.abs_diff_eq(Vec2::new(200.0, 105.0), 0.01), "{max}");
+
+    let (min, max, _) = stroke_bounds(StrokeStyle::new(10.0, Color::WHITE));
+    assert!(min.abs_diff_eq(Vec2::new(99.5, 94.5), 0.01), "{min}");
+    assert!(max.abs_diff_eq(Vec2::new(200.5, 105.5), 0.01), "{max}");
+
+    let square = StrokeStyle::new(10.0, Color::WHITE)
+        .cap(LineCap::Square)
+        .anti_alias(false);
+    let (min, max, _) = stroke_bounds(square);
+    assert!(min.abs_diff_eq(Vec2::new(95.0, 95.0), 0.01), "{min}");
+    assert!(max.abs_diff_eq(Vec2::new(205.0, 105.0), 0.01), "{max}");
+
+    // A dash pattern of 10 on and 15 off fits four dashes into the line, and
+    // each dash is drawn as its own strip of two sections.
+    let dashed = StrokeStyle::new(10.0, Color::WHITE)
+        .dashes(vec![10.0, 15.0])
+        .anti_alias(false);
+    let (min, max, vertices) = stroke_bounds(dashed);
+    assert_eq!(vertices, 4 * 2 * 2);
+    assert!(min.abs_diff_eq(Vec2::new(100.0, 95.0), 0.01), "{min}");
+    assert!(max.abs_diff_eq(Vec2::new(185.0, 105.0), 0.01), "{max}");
+}
+
+#[test]
+fn paint_stats() {
+    let mut yak = test_yakui();
+    yak.set_widget_stats(true);
+
+    yak.start();
+    column(|| {
+        colored_box(Color::RED, [50.0, 50.0]);
+        colored_box(Color::BLUE, [50.0, 50.0]);
+
+        RoundRect::new(10.0)
+            .min_size(Vec2::splat(100.0))
+            .clip(true)
+            .show_children(|| {
+                colored_box(Color::GREEN, [50.0, 50.0]);
+            });
+    });
+    yak.finish();
+
+    let stats = yak.paint().stats();
+
+    // The boxes are batched into one call, the clipping rectangle is clipped to
+    // its own rect and its child is also clipped to its rounded corners.
+    let total = stats.total;
+    assert_eq!(stats.layers, [total]);
+    assert_eq!(total.calls, 3);
+    assert_eq!(total.user_calls, 0);
+    assert_eq!(total.texture_changes, 1);
+    assert_eq!(total.pipeline_changes, 1);
+    assert_eq!(total.clip_changes, 3);
+
+    let widgets: Vec<_> = stats
+        .widgets
+        .iter()
+        .map(|widget| {
+            let name = widget.type_name.rsplit("::").next().unwrap();
+            (name, widget.instances, widget.vertices)
+        })
+        .collect();
+
+    let round_rect_vertices = total.vertices - 12;
+    assert_eq!(
+        widgets,
+        [
+            ("RoundRectWidget", 1, round_rect_vertices),
+            ("ColoredBoxWidget", 3, 12),
+            ("RootWidget", 1, 0),
+            ("ListWidget", 1, 0),
+        ]
+    );
+
+    // Render targets are counted on their own and in the total.
+    yak.set_paint_limit(PaintLimits {
+        render_targets: true,
+        ..PaintLimits::default()
+    });
+
+    yak.start();
+    Opacity::new(0.5).offscreen(true).show(|| {
+        colored_box(Color::RED, [50.0, 50.0]);
+        colored_box(Color::BLUE, [50.0, 50.0]);
+    });
+    yak.finish();
+
+    let stats = yak.paint().stats();
+    let [target] = stats.render_targets[..] else {
+        panic!("expected one render target");
+    };
+    assert_eq!(target.calls, 1);
+    assert_eq!(target.vertices, 8);
+    assert_eq!(stats.layers[0].calls, 1);
+    assert_eq!(stats.layers[0].vertices, 4);
+    assert_eq!(stats.total.calls, 2);
+    assert_eq!(stats.total.vertices, 12);
+    assert_eq!(stats.total.texture_changes, 2);
+}
+
+#[test]
+fn transform_hit_test() {
+    let mut yak = test_yakui();
+
+    let show = || {
+        let mut clicked = false;
+        Transform::new()
+            .rotation(std::f32::consts::FRAC_PI_2)
+            .origin(Pivot::TOP_LEFT)
+            .translation(Vec2::new(200.0, 0.0))
+            .show(|| {
+                constrained(Constraints::tight(Vec2::new(100.0, 20.0)), || {
+                    clicked = button("Hi").clicked;
+                });
+            });
+        clicked
+    };
+
+    frame(&mut yak, [1000.0, 1000.0], show);
+
+    // The untransformed location of the button is empty.
+    click(&mut yak, Vec2::new(90.0, 10.0));
+    assert!(!frame(&mut yak, [1000.0, 1000.0], show));
+
+    // Rotated a quarter turn around its top left corner, the button now
+    // covers x from 180 to 200 and y from 0 to 100.
+    click(&mut yak, Vec2::new(190.0, 90.0));
+    assert!(frame(&mut yak, [1000.0, 1000.0], show));
+}
+
+#[test]
+fn panel_resize() {
+    let mut yak = test_yakui();
+    let panel = || Panel::left().collapsible(true);
+
+    let response = frame(&mut yak, [1000.0, 1000.0], || show_panel(panel()));
+    assert_eq!(response.size, 200.0);
+
+    // Drag the handle on the right edge of the panel.
+    drag(&mut yak, Vec2::new(197.0, 10.0), Vec2::new(297.0, 10.0));
+    let response = frame(&mut yak, [1000.0, 1000.0], || show_panel(panel()));
+    assert_eq!(response.size, 300.0);
+    assert!(!response.collapsed);
+
+    // Panels can't be made smaller than their minimum size...
+    drag(&mut yak, Vec2::new(297.0, 10.0), Vec2::new(27.0, 10.0));
+    let response = frame(&mut yak, [1000.0, 1000.0], || show_panel(panel()));
+    assert_eq!(response.size, 50.0);
+    assert!(!response.collapsed);
+
+    // ...unless they are dragged far enough to collapse.
+    drag(&mut yak, Vec2::new(47.0, 10.0), Vec2::new(10.0, 10.0));
+    let response = frame(&mut yak, [1000.0, 1000.0], || show_panel(panel()));
+    assert!(response.collapsed);
+    assert_view_snapshot("panel_resize_collapsed", &yak);
+
+    // Double clicking the handle resets the panel.
+    for _ in 0..2 {
+        drag(&mut yak, Vec2::new(3.0, 10.0), Vec2::new(3.0, 10.0));
+    }
+    let response = frame(&mut yak, [1000.0, 1000.0], || show_panel(panel()));
+    assert_eq!(response.size, 200.0);
+    assert!(!response.collapsed);
+}
+
+#[test]
+fn panel_drag_open_collapsed() {
+    let mut yak = test_yakui();
+    let panel = || Panel::left().collapsible(true);
+
+    frame(&mut yak, [1000.0, 1000.0], || show_panel(panel()));
+    drag(&mut yak, Vec2::new(197.0, 10.0), Vec2::new(10.0, 10.0));
+    let response = frame(&mut yak, [1000.0, 1000.0], || show_panel(panel()));
+    assert!(response.collapsed);
+
+    // Collapsed panels keep their 6px wide handle, which drags them open from
+    // a size of zero.
+    drag(&mut yak, Vec2::new(3.0, 10.0), Vec2::new(153.0, 10.0));
+    let response = frame(&mut yak, [1000.0, 1000.0], || show_panel(panel()));
+    assert!(!response.collapsed);
+    assert_eq!(response.size, 150.0);
+}
+
+fn show_panel(panel: Panel) -> PanelResponse {
+    let mut response = None;
+    row(|| {
+        response = Some(panel.show(|| rect(0, 0)).into_inner());
+    });
+    response.unwrap()
+}
+
+#[test]
+fn splitter_drag() {
+    let mut yak = test_yakui();
+
+    let response = frame(&mut yak, [406.0, 100.0], show_splitter);
+    assert_eq!(response.ratio, 0.5);
+
+    // The divider sits between the two 200px wide panes.
+    drag(&mut yak, Vec2::new(203.0, 10.0), Vec2::new(103.0, 10.0));
+    let response = frame(&mut yak, [406.0, 100.0], show_splitter);
+    assert_eq!(response.ratio, 0.25);
+    assert_view_snapshot("splitter_drag", &yak);
+}
+
+fn show_splitter() -> SplitterResponse {
+    Splitter::horizontal()
+        .show(|| rect(0, 0), || rect(0, 0))
+        .into_inner()
+}
+
+#[test]
+fn dock_layout_move_tab() {
+    let mut layout = DockLayout::new(DockNode::horizontal(
+        0.5,
+        DockNode::tabs(["A"]),
+        DockNode::tabs(["B", "C"]),
+    ));
+
+    layout.move_tab("C", "A", DockSide::Bottom);
+    assert_eq!(
+        layout,
+        DockLayout::new(DockNode::horizontal(
+            0.5,
+            DockNode::vertical(0.5, DockNode::tabs(["A"]), DockNode::tabs(["C"])),
+            DockNode::tabs(["B"]),
+        ))
+    );
+
+    // Moving the last tab out of a stack collapses its split.
+    layout.move_tab("B", "C", DockSide::Center);
+    assert_eq!(
+        layout,
+        DockLayout::new(DockNode::vertical(
+            0.5,
+            DockNode::tabs(["A"]),
+            DockNode::Tabs {
+                tabs: vec!["C".to_owned(), "B".to_owned()],
+                active: 1,
+            },
+        ))
+    );
+
+    // A tab can't be split off from a stack it's the only tab of.
+    layout.move_tab("A", "A", DockSide::Left);
+    assert!(layout.contains("A"));
+    assert!(!layout.remove_tab("D"));
+}
+
+#[test]
+fn dock_area_drag() {
+    let mut yak = test_yakui();
+    let mut layout = DockLayout::new(DockNode::horizontal(
+        0.5,
+        DockNode::tabs(["A"]),
+        DockNode::tabs(["B", "C"]),
+    ));
+
+    let response = frame(&mut yak, [400.0, 300.0], || show_dock(&mut layout));
+    assert!(!response.changed);
+
+    // The divider sits between the two 198px wide stacks.
+    drag(&mut yak, Vec2::new(200.0, 150.0), Vec2::new(299.0, 150.0));
+    let response = frame(&mut yak, [400.0, 300.0], || show_dock(&mut layout));
+    assert!(response.changed);
+    assert_eq!(
+        layout,
+        DockLayout::new(DockNode::horizontal(
+            0.75,
+            DockNode::tabs(["A"]),
+            DockNode::tabs(["B", "C"]),
+        ))
+    );
+
+    // Drag tab B from the second stack to the bottom edge of the first.
+    drag(&mut yak, Vec2::new(305.0, 10.0), Vec2::new(100.0, 290.0));
+    let response = frame(&mut yak, [400.0, 300.0], || show_dock(&mut layout));
+    assert!(response.changed);
+    assert_eq!(
+        layout,
+        DockLayout::new(DockNode::horizontal(
+            0.75,
+            DockNode::vertical(0.5, DockNode::tabs(["A"]), DockNode::tabs(["B"])),
+            DockNode::tabs(["C"]),
+        ))
+    );
+
+    frame(&mut yak, [400.0, 300.0], || show_dock(&mut layout));
+    assert_view_snapshot("dock_area_drag", &yak);
+}
+
+#[test]
+fn dock_area_nan_ratio() {
+    let mut yak = test_yakui();
+    let mut layout = DockLayout::new(DockNode::horizontal(
+        f32::NAN,
+        DockNode::tabs(["A"]),
+        DockNode::tabs(["B"]),
+    ));
+
+    // The divider is laid out in the middle, as if the ratio was 0.5.
+    frame(&mut yak, [400.0, 300.0], || show_dock(&mut layout));
+    drag(&mut yak, Vec2::new(200.0, 150.0), Vec2::new(299.0, 150.0));
+    frame(&mut yak, [400.0, 300.0], || show_dock(&mut layout));
+    assert_eq!(
+        layout,
+        DockLayout::new(DockNode::horizontal(
+            0.75,
+            DockNode::tabs(["A"]),
+            DockNode::tabs(["B"]),
+        ))
+    );
+}
+
+fn show_dock(layout: &mut DockLayout) -> DockAreaResponse {
+    DockArea::new().show(layout, |_| rect(0, 0)).into_inner()
+}
+
+#[test]
+fn opacity_fades_descendants() {
+    let mut yak = test_yakui();
+
+    yak.start();
+    column(|| {
+        Opacity::new(0.5).show(|| {
+            Opacity::new(0.5).show(|| {
+                colored_box(Color::RED, [50.0, 50.0]);
+            });
+        });
+
+        Opacity::new(0.0).show(|| {
+            colored_box(Color::BLUE, [50.0, 50.0]);
+        });
+    });
+    yak.finish();
+
+    // Nested opacities multiply, and fully transparent children aren't
+    // painted at all.
+    let paint = yak.paint();
+    assert!(paint.render_targets().is_empty());
+
+    let call = first_internal_call(paint);
+    assert_eq!(call.vertices.len(), 4);
+    assert!(call.vertices.iter().all(|vertex| vertex.color.w == 0.25));
+}
+
+#[test]
+fn opacity_offscreen() {
+    let mut yak = test_yakui();
+    yak.set_paint_limit(PaintLimits {
+        render_targets: true,
+        ..PaintLimits::default()
+    });
+
+    let mut id = None;
+    frame(&mut yak, [1000.0, 1000.0], || {
+        Pad::all(10.0).show(|| {
+            let response = Opacity::new(0.5).offscreen(true).show(|| {
+                colored_box(Color::RED, [50.0, 50.0]);
+            });
+            id = Some(response.id);
+        });
+    });
+    let id = id.unwrap();
+
+    // The children are painted into a render target covering only the widget
+    // at full opacity, which is then drawn over the surface with the opacity
+    // of the widget.
+    let paint = yak.paint();
+    let [target] = paint.render_targets() else {
+        panic!("expected one render target");
+    };
+    assert_eq!(target.id, id);
+    assert_eq!(
+        target.rect,
+        yakui::Rect::from_pos_size(Vec2::splat(10.0), Vec2::splat(50.0))
+    );
+
+    // Calls in the target are positioned and clipped relative to it.
+    let Some(calls) = &target.calls else {
+        panic!("expected the render target to be painted");
+    };
+    assert_eq!(
+        calls[0].clip(),
+        yakui::Rect::from_pos_size(Vec2::ZERO, Vec2::splat(50.0))
+    );
+    let call = internal_call(&calls[0]);
+    assert!(call.vertices.iter().all(|vertex| vertex.color.w == 1.0));
+    assert!(call
+        .vertices
+        .iter()
+        .all(|vertex| [Vec2::ZERO, Vec2::X, Vec2::Y, Vec2::ONE].contains(&vertex.position)));
+
+    // The whole texture is drawn over the widget.
+    let call = first_internal_call(paint);
+    assert_eq!(call.texture, Some(TextureId::RenderTarget(id)));
+    assert!(call.vertices.iter().all(|vertex| vertex.color.w == 0.5));
+    let (min, max) = call.vertices.iter().fold(
+        (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+        |(min, max), vertex| (min.min(vertex.position), max.max(vertex.position)),
+    );
+    assert_eq!((min, max), (Vec2::splat(0.01), Vec2::splat(0.06)));
+    assert!(call
+        .vertices
+        .iter()
+        .all(|vertex| [Vec2::ZERO, Vec2::X, Vec2::Y, Vec2::ONE].contains(&vertex.texcoord)));
+}
+
+#[test]
+fn cached_reuses_render_target() {
+    fn frame(yak: &mut Yakui, key: u32, size: f32) -> Option<usize> {
+        yak.start();
+        let mut id = None;
+        align(Alignment::TOP_LEFT, || {
+            let response = Cached::new(key).show(|| {
+                colored_box(Color::RED, [size, size]);
+            });
+            id = Some(response.id);
+        });
+        let id = id.unwrap();
+        yak.finish();
+
+        let paint = yak.paint();
+        let [target] = paint.render_targets() else {
+            panic!("expected one render target");
+        };
+        assert_eq!(target.id, id);
+
+        let call = first_internal_call(paint);
+        assert_eq!(call.texture, Some(TextureId::RenderTarget(id)));
+
+        target.calls.as_ref().map(Vec::len)
+    }
+
+    let mut yak = test_yakui();
+    yak.set_paint_limit(PaintLimits {
+        render_targets: true,
+        ..PaintLimits::default()
+    });
+
+    // The children are only painted again when the key or their layout
+    // changes, and the texture is drawn either way.
+    assert_eq!(frame(&mut yak, 0, 50.0), Some(1));
+    assert_eq!(frame(&mut yak, 0, 50.0), None);
+    assert_eq!(frame(&mut yak, 1, 50.0), Some(1));
+    assert_eq!(frame(&mut yak, 1, 50.0), None);
+    assert_eq!(frame(&mut yak, 1, 60.0), Some(1));
+
+    yak.set_surface_size(Vec2::splat(500.0));
+    assert_eq!(frame(&mut yak, 1, 60.0), Some(1));
+}
+
+#[test]
+fn cached_repaints_when_key_changes() {
+    struct Shown {
+        id: WidgetId,
+        hovering: bool,
+        target: yakui::Rect,
+        calls: Option<usize>,
+    }
+
+    // Whether the button was hovered last frame is part of the key, which is
+    // how state that doesn't change the layout gets the children painted again.
+    fn show(yak: &mut Yakui, color: usize, hovering: bool) -> Shown {
+        let (id, hovering) = frame(yak, [1000.0, 1000.0], || {
+            let mut button_hovering = false;
+            let response = Cached::new((color, hovering)).show(|| {
+                column(|| {
+                    colored_box([Color::RED, Color::BLUE][color], [50.0, 50.0]);
+                    button_hovering = button("Hover me").hovering;
+                });
+            });
+            (response.id, button_hovering)
+        });
+
+        let paint = yak.paint();
+        let [target] = paint.render_targets() else {
+            panic!("expected one render target");
+        };
+
+        Shown {
+            id,
+            hovering,
+            target: target.rect,
+            calls: target.calls.as_ref().map(Vec::len),
+        }
+    }
+
+    let mut yak = test_yakui();
+    yak.set_paint_limit(PaintLimits {
+        max_texture_size_2d: 4096,
+        render_targets: true,
+        ..PaintLimits::default()
+    });
+
+    let shown = show(&mut yak, 0, false);
+    assert!(shown.calls.is_some());
+    assert_eq!(show(&mut yak, 0, false).calls, None);
+
+    // The target only covers the widget instead of the whole surface.
+    let rect = yak.layout_dom().get(shown.id).unwrap().rect;
+    assert_eq!(shown.target.pos(), rect.pos().floor());
+    assert_eq!(shown.target.size(), rect.size().ceil());
+
+    // New props without a new layout only paint the children again when they
+    // change the key.
+    assert!(show(&mut yak, 1, false).calls.is_some());
+    assert_eq!(show(&mut yak, 1, false).calls, None);
+
+    yak.handle_event(Event::CursorMoved(Some(rect.pos() + Vec2::new(5.0, 55.0))));
+    let shown = show(&mut yak, 1, false);
+    assert!(shown.hovering);
+    assert_eq!(shown.calls, None);
+
+    assert!(show(&mut yak, 1, true).calls.is_some());
+    assert_eq!(show(&mut yak, 1, true).calls, None);
+}
+
+#[test]
+fn cached_without_render_targets() {
+    let mut yak = test_yakui();
+
+    for _ in 0..2 {
+        yak.start();
+        Cached::new(0).show(|| {
+            colored_box(Color::RED, [50.0, 50.0]);
+        });
+        yak.finish();
+
+        // Renderers without render targets get the children painted directly
+        // every frame.
+        let paint = yak.paint();
+        assert!(paint.render_targets().is_empty());
+
+        let call = first_internal_call(paint);
+        assert_eq!(call.texture, None);
+        assert_eq!(call.vertices.len(), 4);
+    }
+}
+
+fn test_yakui() -> Yakui {
+    let mut yak = Yakui::new();
+    yak.set_surface_size(Vec2::new(1000.0, 1000.0));
+    yak.set_unscaled_viewport(yakui::Rect::from_pos_size(Vec2::ZERO, Vec2::splat(1000.0)));
+    yak
+}
+
+/// Builds a frame that shows `children` in the top left corner of
+/// the viewport, limited to the given size. Returns what `children` returned.
+fn frame<T>(yak: &mut Yakui, max_size: impl Into<Vec2>, children: impl FnOnce() -> T) -> T {
+    let max_size = max_size.into();
+
+    yak.start();
+    let mut output = None;
+    align(Alignment::TOP_LEFT, || {
+        constrained(Constraints::loose(max_size), || {
+            output = Some(children());
+        });
+    });
+    yak.finish();
+
+    output.unwrap()
+}
+
+/// Moves the mouse to `pos` and presses the primary mouse button there.
+fn press(yak: &mut Yakui, pos: Vec2) {
+    yak.handle_event(Event::CursorMoved(Some(pos)));
+    yak.handle_event(Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down: true,
+    });
+}
+
+/// Releases the primary mouse button.
+fn release(yak: &mut Yakui) {
+    yak.handle_event(Event::MouseButtonChanged {
+        button: MouseButton::One,
+        down: false,
+    });
+}
+
+/// Presses and releases the primary mouse button at `pos`.
+fn click(yak: &mut Yakui, pos: Vec2) {
+    press(yak, pos);
+    release(yak);
+}
+
+/// Presses the primary mouse button at `from`, moves the mouse to `to`, then
+/// releases the button.
+fn drag(yak: &mut Yakui, from: Vec2, to: Vec2) {
+    press(yak, from);
+    yak.handle_event(Event::CursorMoved(Some(to)));
+    release(yak);
+}
+
+/// Returns the yakui paint call in a layer call, panicking if it's a
+/// user-managed call instead.
+#[track_caller]
+fn internal_call(call: &PaintLayerCall) -> &YakuiPaintCall {
+    let PaintCall::Internal(call) = call.call() else {
+        panic!("expected a yakui paint call");
+    };
+
+    call
+}
+
+/// Returns the first paint call of the first layer, which must be a yakui
+/// paint call.
+#[track_caller]
+fn first_internal_call(paint: &PaintDom) -> &YakuiPaintCall {
+    internal_call(&paint.layers[0].calls[0])
+}
+
+#[track_caller]
+fn assert_view_snapshot(name: &str, yak: &Yakui) {
+    let mut settings = yakui_test::insta::Settings::clone_current();
+    settings.set_prepend_module_to_snapshot(false);
+    let _guard = settings.bind_to_scope();
+
+    let view = yakui_test::view(yak.dom(), yak.layout_dom());
+    yakui_test::insta::assert_snapshot!(name, view);
+}
+
+/// Sizes itself to its child's intrinsic size, then lays out the child with
+/// that width so that the child's actual size can be compared against it.
+#[derive(Debug)]
+struct IntrinsicWidget {
+    width: IntrinsicSize,
+}
+
+impl Widget for IntrinsicWidget {
+    type Props<'a> = IntrinsicSize;
+    type Response = ();
+
+    fn new() -> Self {
+        Self {
+            width: IntrinsicSize::MaxWidth,
+        }
+    }
+
+    fn update(&mut self, props: Self::Props<'_>) -> Self::Response {
+        self.width = props;
+    }
+
+    fn layout(&self, mut ctx: LayoutContext<'_>, input: Constraints) -> Vec2 {
+        let node = ctx.dom.get_current();
+        let mut size = Vec2::ZERO;
+
+        for &child in &node.children {
+            let width = ctx
+                .intrinsic_size(child, self.width, f32::INFINITY)
+                .min(input.max.x);
+            let height = ctx.intrinsic_size(child, IntrinsicSize::MinHeight, width);
+
+            let constraints = Constraints {
+                min: Vec2::new(width, 0.0),
+                max: Vec2::new(width, f32::INFINITY),
+            };
+            ctx.calculate_layout(child, constraints);
+
+            size = size.max(Vec2::new(width, height));
+        }
+
+        input.constrain(size)
+    }
+}
+
+fn intrinsic<F: FnOnce()>(width: IntrinsicSize, children: F) {
+    let dom = yakui_core::context::dom();
+    let response = dom.begin_widget::<IntrinsicWidget>(width);
+    children();
+    dom.end_widget::<IntrinsicWidget>(response.id);
+}
+
 fn rect<V: IntoF32>(w: V, h: V) {
     colored_box(Color::WHITE, [w.to_f32(), h.to_f32()]);
 }
@@ -0,0 +1,34 @@
+use yakui::widgets::{DockArea, DockLayout, DockNode, Pad};
+use yakui::{button, center, label, pad, use_state};
+
+pub fn run() {
+    let layout = use_state(|| {
+        DockLayout::new(DockNode::horizontal(
+            0.25,
+            DockNode::tabs(["Outliner", "Assets"]),
+            DockNode::vertical(
+                0.7,
+                DockNode::tabs(["Viewport"]),
+                DockNode::tabs(["Console", "Output"]),
+            ),
+        ))
+    });
+
+    let mut layout = layout.borrow_mut();
+    DockArea::new().show(&mut layout, |tab| match tab {
+        "Viewport" => {
+            center(|| {
+                label("Drag tabs between stacks, or to the edge of a stack to split it.");
+            });
+        }
+        _ => {
+            pad(Pad::all(8.0), || {
+                button(tab.to_owned());
+            });
+        }
+    });
+}
+
+fn main() {
+    bootstrap::start(run as fn());
+}
@@ -58,9 +58,9 @@ fn styled_button(text: impl Into<Cow<'static, str>>) {
     let mut button = Button::styled(text);
     button.padding = theme.button_padding;
 
-    button.style.fill = theme.fill_color;
-    button.down_style.fill = theme.fill_color;
-    button.hover_style.fill = theme.fill_color;
+    button.style.fill = theme.fill_color.into();
+    button.down_style.fill = theme.fill_color.into();
+    button.hover_style.fill = theme.fill_color.into();
 
     button.style.text.color = theme.text_color;
     button.down_style.text.color = theme.text_color;
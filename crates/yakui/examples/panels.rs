@@ -1,9 +1,9 @@
-use yakui::widgets::{List, Panel};
+use yakui::widgets::{List, Panel, Splitter};
 use yakui::{button, center, column, expanded, label, row, textbox, use_state, CrossAxisAlignment};
 
 pub fn run() {
     column(|| {
-        let panel = Panel::top_bottom();
+        let panel = Panel::top().default_size(40.0).resizable(false);
         panel.show(|| {
             center(|| {
                 label("Yakui Game Editor Demo");
@@ -11,41 +11,58 @@ pub fn run() {
         });
 
         expanded(|| {
-            let panel = Panel::side();
-            panel.show(|| {
-                List::column()
-                    .cross_axis_alignment(CrossAxisAlignment::Start)
-                    .show(|| {
-                        row(|| {
-                            expanded(|| {
-                                label("Label Label");
+            row(|| {
+                let panel = Panel::left().default_size(300.0).collapsible(true);
+                panel.show(|| {
+                    List::column()
+                        .cross_axis_alignment(CrossAxisAlignment::Start)
+                        .show(|| {
+                            row(|| {
+                                expanded(|| {
+                                    label("Label Label");
+                                });
+                                button("Button!");
                             });
-                            button("Button!");
-                        });
 
-                        row(|| {
-                            label("More labels!");
-                            button("Buttons!!!");
-                        });
+                            row(|| {
+                                label("More labels!");
+                                button("Buttons!!!");
+                            });
 
-                        row(|| {
-                            label("Input");
-                            expanded(|| {
-                                let name = use_state(|| String::from("Hello"));
+                            row(|| {
+                                label("Input");
+                                expanded(|| {
+                                    let name = use_state(|| String::from("Hello"));
 
-                                let res = textbox(name.borrow().as_str());
-                                if let Some(new_text) = res.into_inner().text {
-                                    name.set(new_text);
-                                }
+                                    let res = textbox(name.borrow().as_str());
+                                    if let Some(new_text) = res.into_inner().text {
+                                        name.set(new_text);
+                                    }
+                                });
                             });
-                        });
 
-                        row(|| {
-                            expanded(|| {
-                                button("Wide Button!");
+                            row(|| {
+                                expanded(|| {
+                                    button("Wide Button!");
+                                });
                             });
                         });
-                    });
+                });
+
+                expanded(|| {
+                    Splitter::vertical().default_ratio(0.7).show(
+                        || {
+                            center(|| {
+                                label("Viewport");
+                            });
+                        },
+                        || {
+                            center(|| {
+                                label("Console");
+                            });
+                        },
+                    );
+                });
             });
         });
     });
@@ -1,19 +1,13 @@
-//! This example shows how you might draw a radial menu for a game using yakui
-//! and lyon_tessellation.
+//! This example shows how you might draw a radial menu for a game using yakui's
+//! vector paths.
 //!
-//! It also serves as an example showing how easy it is to use both libraries
-//! together to draw interesting geometry!
+//! It also serves as an example showing how easy it is to draw interesting
+//! geometry with `Path`!
 
 use std::f32::consts::TAU;
 
-use lyon_tessellation::geometry_builder::simple_builder;
-use lyon_tessellation::math::{point, Point};
-use lyon_tessellation::path::Path;
-use lyon_tessellation::{
-    FillOptions, FillTessellator, LineJoin, StrokeOptions, StrokeTessellator, VertexBuffers,
-};
+use yakui::shapes::{FillStyle, LineJoin, Path, StrokeStyle};
 use yakui::{Color, Vec2};
-use yakui_core::paint::{PaintMesh, Vertex};
 use yakui_core::widget::PaintContext;
 use yakui_widgets::widgets::ColoredBox;
 use yakui_widgets::{canvas, center};
@@ -45,71 +39,18 @@ pub fn run() {
 fn draw_segment(ctx: &mut PaintContext<'_>, center: Vec2, start_angle: f32, end_angle: f32) {
     let inner_radius = 100.0;
     let outer_radius = 150.0;
-    let angle_samples = 16;
-
-    let angle_range = (end_angle - start_angle).abs();
-
-    let mut builder = Path::builder();
-    builder.begin(point(start_angle.cos(), start_angle.sin()) * inner_radius);
-    builder.line_to(point(start_angle.cos(), start_angle.sin()) * outer_radius);
-
-    for i in 0..=angle_samples {
-        let fraction = (i as f32) / (angle_samples as f32);
-        let angle = start_angle + angle_range * fraction;
-        builder.line_to(point(angle.cos(), angle.sin()) * outer_radius);
-    }
-
-    builder.line_to(point(end_angle.cos(), end_angle.sin()) * inner_radius);
-
-    for i in (0..=angle_samples).rev() {
-        let fraction = (i as f32) / (angle_samples as f32);
-        let angle = start_angle + angle_range * fraction;
-        builder.line_to(point(angle.cos(), angle.sin()) * inner_radius);
-    }
-
-    builder.end(true);
-
-    let path = builder.build();
-
-    {
-        let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
-        let mut vertex_builder = simple_builder(&mut buffers);
-        let mut tessellator = FillTessellator::new();
-
-        tessellator
-            .tessellate_path(&path, &FillOptions::default(), &mut vertex_builder)
-            .unwrap();
-
-        let vertices = buffers.vertices.iter().map(|point| {
-            let pos = center + Vec2::from(point.to_array());
-            Vertex::new(pos, pos, Color::hex(0x333333).to_linear())
-        });
-
-        let mesh = PaintMesh::new(vertices, buffers.indices);
-        ctx.paint.add_mesh(mesh);
-    }
-
-    {
-        let mut buffers: VertexBuffers<Point, u16> = VertexBuffers::new();
-        let mut vertex_builder = simple_builder(&mut buffers);
-        let mut tessellator = StrokeTessellator::new();
-
-        let options = StrokeOptions::default()
-            .with_line_width(2.0)
-            .with_line_join(LineJoin::Round);
-
-        tessellator
-            .tessellate(&path, &options, &mut vertex_builder)
-            .unwrap();
-
-        let vertices = buffers.vertices.iter().map(|point| {
-            let pos = center + Vec2::from(point.to_array());
-            Vertex::new(pos, pos, Color::WHITE.to_linear())
-        });
-
-        let mesh = PaintMesh::new(vertices, buffers.indices);
-        ctx.paint.add_mesh(mesh);
-    }
+    let sweep = end_angle - start_angle;
+
+    let mut path = Path::new();
+    path.arc(center, outer_radius, start_angle, sweep)
+        .arc(center, inner_radius, end_angle, -sweep)
+        .close();
+
+    path.fill(ctx.paint, &FillStyle::new(Color::hex(0x333333)));
+    path.stroke(
+        ctx.paint,
+        &StrokeStyle::new(2.0, Color::WHITE).join(LineJoin::Round),
+    );
 }
 
 fn main() {
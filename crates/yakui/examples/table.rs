@@ -0,0 +1,57 @@
+use yakui::widgets::{Table, TableColumn, TableSortOrder};
+use yakui::{pad, text, use_state, widgets::Pad};
+
+struct Entity {
+    id: usize,
+    name: String,
+    health: u32,
+}
+
+pub fn run() {
+    let entities = use_state(|| {
+        (0..10_000)
+            .map(|id| Entity {
+                id,
+                name: format!("Entity {id}"),
+                health: (id * 7919 % 100) as u32,
+            })
+            .collect::<Vec<_>>()
+    });
+
+    pad(Pad::all(8.0), || {
+        let columns = vec![
+            TableColumn::new("ID").width(80.0),
+            TableColumn::new("Name").width(200.0),
+            TableColumn::new("Health"),
+        ];
+
+        let row_count = entities.borrow().len();
+        let response = Table::new(columns, row_count).show(|row, column| {
+            let entities = entities.borrow();
+            let entity = &entities[row];
+
+            match column {
+                0 => text(14.0, entity.id.to_string()),
+                1 => text(14.0, entity.name.clone()),
+                _ => text(14.0, entity.health.to_string()),
+            };
+        });
+
+        if let (true, Some(sort)) = (response.sort_changed, response.sort) {
+            let mut entities = entities.borrow_mut();
+            match sort.column {
+                0 => entities.sort_by_key(|entity| entity.id),
+                1 => entities.sort_by(|a, b| a.name.cmp(&b.name)),
+                _ => entities.sort_by_key(|entity| entity.health),
+            }
+
+            if sort.order == TableSortOrder::Descending {
+                entities.reverse();
+            }
+        }
+    });
+}
+
+fn main() {
+    bootstrap::start(run as fn());
+}
@@ -0,0 +1,18 @@
+use yakui::widgets::{Scrollable, VirtualList};
+use yakui::{pad, text, widgets::Pad};
+
+pub fn run() {
+    pad(Pad::all(8.0), || {
+        Scrollable::vertical().show(|| {
+            VirtualList::measured(100_000, 24.0).show(|index| {
+                // Every tenth item is taller to show off measured item sizes.
+                let size = if index % 10 == 0 { 24.0 } else { 14.0 };
+                text(size, format!("Item {index}"));
+            });
+        });
+    });
+}
+
+fn main() {
+    bootstrap::start(run as fn());
+}
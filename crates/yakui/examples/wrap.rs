@@ -0,0 +1,16 @@
+use yakui::widgets::Wrap;
+use yakui::{button, pad, widgets::Pad};
+
+pub fn run() {
+    pad(Pad::all(8.0), || {
+        Wrap::row().item_spacing(8.0).run_spacing(8.0).show(|| {
+            for i in 0..40 {
+                button(format!("Tag {i}"));
+            }
+        });
+    });
+}
+
+fn main() {
+    bootstrap::start(run as fn());
+}
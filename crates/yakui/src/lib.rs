@@ -10,6 +10,7 @@ pub use yakui_widgets::border::*;
 pub use yakui_widgets::colors;
 pub use yakui_widgets::cosmic_text;
 pub use yakui_widgets::font;
+pub use yakui_widgets::shadow::*;
 pub use yakui_widgets::shapes;
 pub use yakui_widgets::shorthand::*;
 pub use yakui_widgets::style;